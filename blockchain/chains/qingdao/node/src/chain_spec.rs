@@ -34,34 +34,36 @@ use setheum_runtime::{
 	FinancialCouncilMembershipConfig,
 	TechnicalCommitteeMembershipConfig,
 	OperatorMembershipSetheumConfig,
+	PricesConfig, PegDefinition,
 	SerpTreasuryConfig,
 	CdpTreasuryConfig,
 	CdpEngineConfig,
 
 	//
 	DexConfig, EnabledTradingPairs,
-	TokensConfig, OrmlNFTConfig,
+	TokensConfig, OrmlNFTConfig, AirDropConfig, AirdropPalletId,
 	NativeTokenExistentialDeposit, MaxNativeTokenExistentialDeposit,
 	//
 	SEE, SERP, DNAR, HELP, SETR, SETUSD,
 };
 use sp_consensus_babe::AuthorityId as BabeId;
 use sp_finality_grandpa::AuthorityId as GrandpaId;
-use sp_runtime::traits::{IdentifyAccount};
+use sp_runtime::traits::{AccountIdConversion, IdentifyAccount};
 use sp_runtime::{traits::Zero, FixedPointNumber, FixedU128};
 use sc_service::{ChainType, Properties};
 use sc_telemetry::TelemetryEndpoints;
 
-use sp_std::{collections::btree_map::BTreeMap, str::FromStr};
+use sp_std::{collections::btree_map::BTreeMap, convert::TryFrom, str::FromStr};
+use std::path::Path;
 use sc_chain_spec::ChainSpecExtension;
 use serde_json::map::Map;
 
 use serde::{Deserialize, Serialize};
 
 use hex_literal::hex;
-use sp_core::{crypto::UncheckedInto, bytes::from_hex};
+use sp_core::{crypto::{Ss58Codec, UncheckedInto}, bytes::from_hex};
 
-use setheum_primitives::{AccountPublic, Balance, Nonce, currency::TokenInfo, TradingPair};
+use setheum_primitives::{AccountPublic, Balance, CurrencyId, Nonce, currency::TokenInfo, TradingPair};
 use setheum_runtime::BABE_GENESIS_EPOCH_CONFIG;
 
 // The URL for the telemetry server.
@@ -204,7 +206,7 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 	))
 }
 
-pub fn public_testnet_config() -> Result<ChainSpec, String> {
+pub fn public_testnet_config(airdrop_list: Vec<(AccountId, CurrencyId, Balance)>) -> Result<ChainSpec, String> {
 	let wasm_binary = setheum_runtime::WASM_BINARY.ok_or_else(|| "WASM binary not available".to_string())?;
 	Ok(ChainSpec::from_genesis(
 		// Name
@@ -287,6 +289,8 @@ pub fn public_testnet_config() -> Result<ChainSpec, String> {
 			hex!["409bc00c7f4d8cf046c1eb363022eec1103e70ae180cba92056452315837c71a"].into(),
 			// Team and DEX Liquidity Offering Fund: VQgPxsHbvGdXC7HhUvYvPifu1SyAuRnUhbMw4hAaTm9fwvkkz
 			hex!["22b565e2303579c0d50884a3524c32ed12c8b91a8621dd72270b8fd17d20d009"].into(),
+			// Genesis airdrop list
+			airdrop_list.clone(),
 		),
 		// Bootnodes - TODO: Update!
 		vec![],
@@ -310,7 +314,7 @@ pub fn live_testnet_config() -> Result<ChainSpec, String> {
 	ChainSpec::from_json_bytes(&include_bytes!("../../resources/chain_spec_testnet_raw.json")[..])
 }
 
-pub fn mainnet_config() -> Result<ChainSpec, String> {
+pub fn mainnet_config(airdrop_list: Vec<(AccountId, CurrencyId, Balance)>) -> Result<ChainSpec, String> {
 	let wasm_binary = setheum_runtime::WASM_BINARY.ok_or_else(|| "WASM binary not available".to_string())?;
 	Ok(ChainSpec::from_genesis(
 		// Name
@@ -401,6 +405,8 @@ pub fn mainnet_config() -> Result<ChainSpec, String> {
 			hex!["22b565e2303579c0d50884a3524c32ed12c8b91a8621dd72270b8fd17d20d009"].into(),
 			// Advisors and Partners Fund: VQgfLtTS8oZCreyX3FzHuaAbUovtbcuSFLnUFS3tkRvwWGkbD
 			hex!["2e70349d7140ec49b7cf1ae03b6ae3405103dab86c5a463ceef77ffb4a769868"].into(),
+			// Genesis airdrop list
+			airdrop_list.clone(),
 		),
 		// Bootnodes - TODO: Update!
 		vec![],
@@ -490,6 +496,12 @@ fn dev_genesis(
 			],
 			phantom: Default::default(),
 		},
+		prices: PricesConfig {
+			pegs: vec![
+				(SETUSD, PegDefinition::Fixed(FixedU128::saturating_from_rational(1, 1))), // $1
+				(SETR, PegDefinition::Fixed(FixedU128::saturating_from_rational(1, 4))), // $0.25
+			],
+		},
 		session: SessionConfig {
 			keys: initial_authorities
 				.iter()
@@ -604,7 +616,8 @@ fn dev_genesis(
 		// 	initial_enabled_trading_pairs: EnabledTradingPairs::get(),
 		// 	initial_added_liquidity_pools: vec![],
 		// },
-		orml_nft: OrmlNFTConfig { tokens: vec![] }
+		orml_nft: OrmlNFTConfig { tokens: vec![] },
+		air_drop: Default::default(),
 	}
 }
 
@@ -615,7 +628,9 @@ fn testnet_genesis(
 	endowed_accounts: Vec<(AccountId, Balance)>,
 	foundation: AccountId,
 	team: AccountId,
+	airdrop_list: Vec<(AccountId, CurrencyId, Balance)>,
 ) -> GenesisConfig {
+	let airdrop_treasury: AccountId = AirdropPalletId::get().into_account();
 
 	// Allocations Endowment
 	let  serp_foundation_alloc: u128 = 51_600_000 * 1_000_000_000_000_000_000;
@@ -726,6 +741,12 @@ fn testnet_genesis(
 			],
 			phantom: Default::default(),
 		},
+		prices: PricesConfig {
+			pegs: vec![
+				(SETUSD, PegDefinition::Fixed(FixedU128::saturating_from_rational(1, 1))), // $1
+				(SETR, PegDefinition::Fixed(FixedU128::saturating_from_rational(1, 4))), // $0.25
+			],
+		},
 		session: SessionConfig {
 			keys: initial_authorities
 				.iter()
@@ -758,15 +779,20 @@ fn testnet_genesis(
 		treasury: Default::default(), // Main Treasury (Setheum Treasury)
 		tokens: TokensConfig {
 			balances: vec![
-				(foundation.clone(), SERP, serp_foundation_alloc + serp_airdrops_alloc),
+				(foundation.clone(), SERP, serp_foundation_alloc),
+				(airdrop_treasury.clone(), SERP, serp_airdrops_alloc),
 				(team.clone(), SERP, serp_team_alloc),
-				(foundation.clone(), DNAR, dnar_foundation_alloc + dnar_airdrop_alloc),
+				(foundation.clone(), DNAR, dnar_foundation_alloc),
+				(airdrop_treasury.clone(), DNAR, dnar_airdrop_alloc),
 				(team.clone(), DNAR, dnar_team_alloc),
-				(foundation.clone(), HELP, help_foundation_alloc + help_airdrop_alloc),
+				(foundation.clone(), HELP, help_foundation_alloc),
+				(airdrop_treasury.clone(), HELP, help_airdrop_alloc),
 				(team.clone(), HELP, help_team_alloc),
-				(foundation.clone(), SETR, setr_foundation_alloc + setr_airdrop_alloc),
+				(foundation.clone(), SETR, setr_foundation_alloc),
+				(airdrop_treasury.clone(), SETR, setr_airdrop_alloc),
 				(team.clone(), SETR, setr_team_alloc),
-				(foundation.clone(), SETUSD, setusd_foundation_alloc + setusd_airdrop_alloc),
+				(foundation.clone(), SETUSD, setusd_foundation_alloc),
+				(airdrop_treasury.clone(), SETUSD, setusd_airdrop_alloc),
 				(team.clone(), SETUSD, setusd_team_alloc),
 			]
 		},
@@ -852,6 +878,7 @@ fn testnet_genesis(
 		// 	initial_added_liquidity_pools: vec![],
 		// },
 		orml_nft: OrmlNFTConfig { tokens: vec![] },
+		air_drop: AirDropConfig { airdrop_list },
 	}
 }
 
@@ -864,7 +891,10 @@ fn mainnet_genesis(
 	spf: AccountId,
 	team: AccountId,
 	advisors_n_partners: AccountId,
+	airdrop_list: Vec<(AccountId, CurrencyId, Balance)>,
 ) -> GenesisConfig {
+	let airdrop_treasury: AccountId = AirdropPalletId::get().into_account();
+
 	// Allocations Endowment
 	let  serp_foundation_alloc: u128 = 51_600_000 * 1_000_000_000_000_000_000;
 	let  serp_spf_alloc: u128 = 25_800_000 * 1_000_000_000_000_000_000;
@@ -995,6 +1025,12 @@ fn mainnet_genesis(
 			],
 			phantom: Default::default(),
 		},
+		prices: PricesConfig {
+			pegs: vec![
+				(SETUSD, PegDefinition::Fixed(FixedU128::saturating_from_rational(1, 1))), // $1
+				(SETR, PegDefinition::Fixed(FixedU128::saturating_from_rational(1, 4))), // $0.25
+			],
+		},
 		session: SessionConfig {
 			keys: initial_authorities
 				.iter()
@@ -1027,27 +1063,32 @@ fn mainnet_genesis(
 		treasury: Default::default(), // Setheum Treasury
 		tokens: TokensConfig {
 			balances: vec![
-				(foundation.clone(), SERP, serp_foundation_alloc + serp_airdrops_alloc),
+				(foundation.clone(), SERP, serp_foundation_alloc),
+				(airdrop_treasury.clone(), SERP, serp_airdrops_alloc),
 				(spf.clone(), SERP, serp_spf_alloc),
 				(team.clone(), SERP, serp_team_alloc),
 				(advisors_n_partners.clone(), SERP, serp_advisors_n_partners_alloc),
 
-				(foundation.clone(), DNAR, dnar_foundation_alloc + dnar_airdrop_alloc),
+				(foundation.clone(), DNAR, dnar_foundation_alloc),
+				(airdrop_treasury.clone(), DNAR, dnar_airdrop_alloc),
 				(spf.clone(), DNAR, dnar_spf_alloc),
 				(team.clone(), DNAR, dnar_team_alloc),
 				(advisors_n_partners.clone(), DNAR, dnar_advisors_n_partners_alloc),
 
-				(foundation.clone(), HELP, help_foundation_alloc + help_airdrop_alloc),
+				(foundation.clone(), HELP, help_foundation_alloc),
+				(airdrop_treasury.clone(), HELP, help_airdrop_alloc),
 				(spf.clone(), HELP, help_spf_alloc),
 				(team.clone(), HELP, help_team_alloc),
 				(advisors_n_partners.clone(), HELP, help_advisors_n_partners_alloc),
 
-				(foundation.clone(), SETR, setr_foundation_alloc + setr_airdrop_alloc),
+				(foundation.clone(), SETR, setr_foundation_alloc),
+				(airdrop_treasury.clone(), SETR, setr_airdrop_alloc),
 				(spf.clone(), SETR, setr_spf_alloc),
 				(team.clone(), SETR, setr_team_alloc),
 				(advisors_n_partners.clone(), SETR, setr_advisors_n_partners_alloc),
 
-				(foundation.clone(), SETUSD, setusd_foundation_alloc + setusd_airdrop_alloc),
+				(foundation.clone(), SETUSD, setusd_foundation_alloc),
+				(airdrop_treasury.clone(), SETUSD, setusd_airdrop_alloc),
 				(spf.clone(), SETUSD, setusd_spf_alloc),
 				(team.clone(), SETUSD, setusd_team_alloc),
 				(advisors_n_partners.clone(), SETUSD, setusd_advisors_n_partners_alloc),
@@ -1146,9 +1187,79 @@ fn mainnet_genesis(
 		// 	initial_added_liquidity_pools: vec![],
 		// },
 		orml_nft: OrmlNFTConfig { tokens: vec![] },
+		air_drop: AirDropConfig { airdrop_list },
+	}
+}
+
+/// The header row of a CSV airdrop file, skipped if it is the first row.
+const AIRDROP_CSV_HEADER: [&str; 3] = ["account", "currency", "amount"];
+
+/// Load a genesis airdrop list of `(account, currency, amount)` entries from a
+/// CSV or JSON file, picked by the file extension.
+pub fn airdrop_list_from_file(path: &Path) -> Result<Vec<(AccountId, CurrencyId, Balance)>, String> {
+	let content = std::fs::read_to_string(path)
+		.map_err(|e| format!("Failed to read airdrop file {}: {}", path.display(), e))?;
+
+	if path.extension().map_or(false, |ext| ext == "json") {
+		airdrop_list_from_json(&content)
+	} else {
+		airdrop_list_from_csv(&content)
 	}
+	.map_err(|e| format!("Failed to parse airdrop file {}: {}", path.display(), e))
+}
+
+/// Parse the account and the currency symbol of an airdrop entry.
+fn parse_airdrop_entry(account: &str, currency: &str) -> Result<(AccountId, CurrencyId), String> {
+	let account =
+		AccountId::from_ss58check(account.trim()).map_err(|e| format!("Invalid airdrop account {}: {:?}", account, e))?;
+	let currency = CurrencyId::try_from(currency.trim().as_bytes().to_vec())
+		.map_err(|_| format!("Invalid airdrop currency {}", currency))?;
+	Ok((account, currency))
 }
 
+/// Parse a JSON array of `{ "account", "currency", "amount" }` objects.
+fn airdrop_list_from_json(content: &str) -> Result<Vec<(AccountId, CurrencyId, Balance)>, String> {
+	#[derive(Deserialize)]
+	struct AirdropEntry {
+		account: String,
+		currency: String,
+		amount: Balance,
+	}
+
+	let entries: Vec<AirdropEntry> = serde_json::from_str(content).map_err(|e| e.to_string())?;
+	entries
+		.into_iter()
+		.map(|entry| {
+			let (account, currency) = parse_airdrop_entry(&entry.account, &entry.currency)?;
+			Ok((account, currency, entry.amount))
+		})
+		.collect()
+}
+
+/// Parse `account,currency,amount` rows, with an optional
+/// `account,currency,amount` header as the first row.
+fn airdrop_list_from_csv(content: &str) -> Result<Vec<(AccountId, CurrencyId, Balance)>, String> {
+	let mut rows = content
+		.lines()
+		.map(|line| line.split(',').map(str::trim).collect::<Vec<_>>())
+		.filter(|fields| fields.iter().any(|field| !field.is_empty()))
+		.peekable();
+	if rows.peek().map_or(false, |fields| fields[..] == AIRDROP_CSV_HEADER[..]) {
+		rows.next();
+	}
+
+	rows.map(|fields| {
+		if fields.len() != 3 {
+			return Err(format!("Invalid airdrop row: {}", fields.join(",")));
+		}
+		let (account, currency) = parse_airdrop_entry(fields[0], fields[1])?;
+		let amount = fields[2]
+			.parse::<Balance>()
+			.map_err(|e| format!("Invalid airdrop amount {}: {}", fields[2], e))?;
+		Ok((account, currency, amount))
+	})
+	.collect()
+}
 
 /// Currencies Properties
 pub fn setheum_properties() -> Properties {
@@ -1194,3 +1305,75 @@ pub fn evm_genesis() -> BTreeMap<H160, module_evm::GenesisAccount<Balance, Nonce
 	}
 	accounts
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn alice() -> AccountId {
+		get_account_id_from_seed::<sr25519::Public>("Alice")
+	}
+
+	fn bob() -> AccountId {
+		get_account_id_from_seed::<sr25519::Public>("Bob")
+	}
+
+	#[test]
+	fn airdrop_list_from_csv_works() {
+		let rows = format!("{},SETR,100\n\n{}, SETUSD , 20\n", alice().to_ss58check(), bob().to_ss58check());
+		let expected = vec![(alice(), SETR, 100), (bob(), SETUSD, 20)];
+		assert_eq!(airdrop_list_from_csv(&rows), Ok(expected.clone()));
+
+		// with a header
+		let with_header = format!("account,currency,amount\n{}", rows);
+		assert_eq!(airdrop_list_from_csv(&with_header), Ok(expected));
+
+		// the header is only skipped as the first row
+		let misplaced_header = format!("{}account,currency,amount\n", rows);
+		assert!(airdrop_list_from_csv(&misplaced_header).is_err());
+	}
+
+	#[test]
+	fn airdrop_list_from_csv_rejects_malformed_rows() {
+		let alice = alice().to_ss58check();
+
+		assert!(airdrop_list_from_csv(&format!("{},SETR", alice)).is_err());
+		assert!(airdrop_list_from_csv(&format!("{},SETR,100,1", alice)).is_err());
+		assert!(airdrop_list_from_csv("not-an-account,SETR,100").is_err());
+		assert!(airdrop_list_from_csv(&format!("{},NOPE,100", alice)).is_err());
+		assert!(airdrop_list_from_csv(&format!("{},SETR,-1", alice)).is_err());
+		assert!(airdrop_list_from_csv(&format!("{},SETR,1.5", alice)).is_err());
+		assert!(airdrop_list_from_csv(&format!("{},SETR,", alice)).is_err());
+	}
+
+	#[test]
+	fn airdrop_list_from_json_works() {
+		let json = format!(
+			r#"[{{"account":"{}","currency":"SETR","amount":100}},{{"account":"{}","currency":"SETUSD","amount":20}}]"#,
+			alice().to_ss58check(),
+			bob().to_ss58check()
+		);
+		assert_eq!(
+			airdrop_list_from_json(&json),
+			Ok(vec![(alice(), SETR, 100), (bob(), SETUSD, 20)])
+		);
+		assert_eq!(airdrop_list_from_json("[]"), Ok(vec![]));
+	}
+
+	#[test]
+	fn airdrop_list_from_json_rejects_malformed_entries() {
+		let alice = alice().to_ss58check();
+
+		assert!(airdrop_list_from_json("{}").is_err());
+		assert!(airdrop_list_from_json(&format!(r#"[{{"account":"{}","currency":"SETR"}}]"#, alice)).is_err());
+		assert!(
+			airdrop_list_from_json(&format!(r#"[{{"account":"{}","currency":"SETR","amount":"100"}}]"#, alice))
+				.is_err()
+		);
+		assert!(airdrop_list_from_json(r#"[{"account":"not-an-account","currency":"SETR","amount":100}]"#).is_err());
+		assert!(
+			airdrop_list_from_json(&format!(r#"[{{"account":"{}","currency":"NOPE","amount":100}}]"#, alice))
+				.is_err()
+		);
+	}
+}
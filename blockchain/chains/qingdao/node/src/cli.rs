@@ -18,6 +18,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use std::path::PathBuf;
 use structopt::StructOpt;
 use sc_cli::RunCmd;
 
@@ -54,7 +55,7 @@ pub enum Subcommand {
 	Sign(sc_cli::SignCmd),
 
 	/// Build a chain specification.
-	BuildSpec(sc_cli::BuildSpecCmd),
+	BuildSpec(BuildSpecCmd),
 
 	/// Validate blocks.
 	CheckBlock(sc_cli::CheckBlockCmd),
@@ -78,3 +79,31 @@ pub enum Subcommand {
 	#[structopt(name = "benchmark", about = "Benchmark runtime pallets.")]
 	Benchmark(frame_benchmarking_cli::BenchmarkCmd),
 }
+
+/// The `build-spec` command, extended with a genesis airdrop list.
+#[derive(Debug, StructOpt)]
+pub struct BuildSpecCmd {
+	#[structopt(flatten)]
+	pub base: sc_cli::BuildSpecCmd,
+
+	/// CSV or JSON file of `(account, currency, amount)` entries airdropped
+	/// from the airdrop treasury at genesis.
+	///
+	/// CSV rows are `account,currency,amount`, JSON is an array of
+	/// `{ "account": .., "currency": .., "amount": .. }` objects. Accounts are
+	/// SS58 addresses, currencies are token symbols and amounts are in the
+	/// smallest unit of the currency. Only the `testnet-new` and `mainnet-new`
+	/// chains endow the airdrop treasury, so other chains reject the file.
+	#[structopt(long, parse(from_os_str))]
+	pub airdrop_file: Option<PathBuf>,
+}
+
+impl sc_cli::CliConfiguration for BuildSpecCmd {
+	fn shared_params(&self) -> &sc_cli::SharedParams {
+		self.base.shared_params()
+	}
+
+	fn node_key_params(&self) -> Option<&sc_cli::NodeKeyParams> {
+		self.base.node_key_params()
+	}
+}
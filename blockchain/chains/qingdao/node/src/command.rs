@@ -51,11 +51,25 @@ impl SubstrateCli for Cli {
 	}
 
 	fn load_spec(&self, id: &str) -> Result<Box<dyn sc_service::ChainSpec>, String> {
+		let airdrop_list = match &self.subcommand {
+			Some(Subcommand::BuildSpec(cmd)) => match &cmd.airdrop_file {
+				Some(path) => chain_spec::airdrop_list_from_file(path)?,
+				None => vec![],
+			},
+			_ => vec![],
+		};
+
+		// Only the specs built from genesis endow the airdrop treasury, so a
+		// list passed to any other chain would be silently dropped.
+		if !airdrop_list.is_empty() && !matches!(id, "testnet-new" | "mainnet-new") {
+			return Err(format!("--airdrop-file is only supported with testnet-new and mainnet-new, not {}", id));
+		}
+
 		Ok(match id {
 			"dev" => Box::new(chain_spec::development_config()?),
 			"local" => Box::new(chain_spec::local_testnet_config()?),
-			"testnet-new" => Box::new(chain_spec::public_testnet_config()?),
-			"mainnet-new" => Box::new(chain_spec::mainnet_config()?),
+			"testnet-new" => Box::new(chain_spec::public_testnet_config(airdrop_list)?),
+			"mainnet-new" => Box::new(chain_spec::mainnet_config(airdrop_list)?),
 			"testnet" => Box::new(chain_spec::live_testnet_config()?),
 			"mainnet" => Box::new(chain_spec::live_mainnet_config()?),
 			path => Box::new(chain_spec::ChainSpec::from_json_file(
@@ -80,7 +94,7 @@ pub fn run() -> sc_cli::Result<()> {
 		Some(Subcommand::Vanity(cmd)) => cmd.run(),
 		Some(Subcommand::BuildSpec(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
-			runner.sync_run(|config| cmd.run(config.chain_spec, config.network))
+			runner.sync_run(|config| cmd.base.run(config.chain_spec, config.network))
 		},
 		Some(Subcommand::CheckBlock(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
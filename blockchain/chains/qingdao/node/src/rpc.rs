@@ -44,6 +44,8 @@ use sc_finality_grandpa::{
 	SharedVoterState
 };
 pub use evm_rpc::{EVMApi, EVMApiServer, EVMRuntimeRPCApi};
+pub use module_transaction_payment_rpc::{FeeQuoteApi, FeeQuoteRpc, FeeQuoteRuntimeApi};
+pub use module_prices_rpc::{Prices, PricesApi, PricesRuntimeApi};
 pub use sc_rpc::SubscriptionTaskExecutor;
 
 /// Extra dependencies for BABE.
@@ -95,6 +97,8 @@ pub fn create_full<C, P, SC, B>(
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: FeeQuoteRuntimeApi<Block, AccountId, Balance>,
+	C::Api: PricesRuntimeApi<Block, BlockNumber>,
 	C::Api: orml_oracle_rpc::OracleRuntimeApi<Block, DataProviderId, CurrencyId, TimeStampedPrice>,
 	C::Api: EVMRuntimeRPCApi<Block, Balance>,
 	C::Api: sp_consensus_babe::BabeApi<Block>,
@@ -142,6 +146,8 @@ pub fn create_full<C, P, SC, B>(
 	io.extend_with(
 		TransactionPaymentApi::to_delegate(TransactionPayment::new(client.clone()))
 	);
+	io.extend_with(FeeQuoteApi::to_delegate(FeeQuoteRpc::new(client.clone())));
+	io.extend_with(PricesApi::to_delegate(Prices::new(client.clone())));
 
 	// Extend this RPC with a custom API by using the following syntax.
 	// `YourRpcStruct` should have a reference to a client, which is needed
@@ -213,6 +213,11 @@ impl Output {
 		ethabi::encode(&[out])
 	}
 
+	pub fn encode_u128_list(&self, b: &[u128]) -> Vec<u8> {
+		let out: Vec<Token> = b.iter().map(|c| Token::Uint(U256::from(*c))).collect();
+		ethabi::encode(&out)
+	}
+
 	pub fn encode_bytes(&self, b: &[u8]) -> Vec<u8> {
 		let out = Token::Bytes(b.to_vec());
 		ethabi::encode(&[out])
@@ -222,6 +227,14 @@ impl Output {
 		let out = Token::Address(H160::from_slice(b.as_bytes()));
 		ethabi::encode(&[out])
 	}
+
+	pub fn encode_address_u128_tuple(&self, b: &H160, c: u128) -> Vec<u8> {
+		let out = Token::Tuple(vec![
+			Token::Address(H160::from_slice(b.as_bytes())),
+			Token::Uint(U256::from(c)),
+		]);
+		ethabi::encode(&[out])
+	}
 }
 
 #[cfg(test)]
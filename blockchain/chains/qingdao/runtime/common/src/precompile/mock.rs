@@ -24,7 +24,7 @@ use crate::{AllPrecompiles, Ratio, BlockWeights, SystemContractsFilter, Weight};
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	assert_ok, ord_parameter_types, parameter_types,
-	traits::{GenesisBuild, InstanceFilter, OnFinalize, OnInitialize, SortedMembers},
+	traits::{GenesisBuild, InstanceFilter, Nothing, OnFinalize, OnInitialize, SortedMembers},
 	weights::IdentityFee,
 	PalletId, RuntimeDebug,
 };
@@ -40,7 +40,7 @@ pub use primitives::{
 use sp_core::{bytes::from_hex, Bytes, crypto::AccountId32, H160, H256};
 use sp_runtime::{
 	traits::{BlakeTwo256, Convert, IdentityLookup, One as OneT},
-	DispatchResult, FixedPointNumber, FixedU128, Perbill,
+	DispatchResult, FixedPointNumber, FixedU128, Perbill, Permill,
 };
 use sp_std::{collections::btree_map::BTreeMap, convert::TryFrom, str::FromStr};
 
@@ -323,6 +323,13 @@ parameter_types! {
 	];
 }
 
+parameter_types! {
+	pub const MaxTransferBatch: u32 = 10;
+	pub const DustHistoryDepth: u32 = 84;
+	pub const Erc20TransferWeight: Weight = 1_000_000;
+	pub const MaxMemoLength: u32 = 32;
+}
+
 impl module_currencies::Config for Test {
 	type Event = Event;
 	type MultiCurrency = Tokens;
@@ -335,6 +342,12 @@ impl module_currencies::Config for Test {
 	type EVMBridge = EVMBridge;
 	type SweepOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
 	type OnDust = ();
+	type UpdateOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
+	type CurrentEra = ();
+	type DustHistoryDepth = DustHistoryDepth;
+	type MaxTransferBatch = MaxTransferBatch;
+	type Erc20TransferWeight = Erc20TransferWeight;
+	type MaxMemoLength = MaxMemoLength;
 }
 
 impl module_evm_bridge::Config for Test {
@@ -344,6 +357,7 @@ impl module_evm_bridge::Config for Test {
 impl module_evm_manager::Config for Test {
 	type Currency = Balances;
 	type EVMBridge = EVMBridge;
+	type ForeignAssetRegistry = ();
 }
 
 parameter_types! {
@@ -352,6 +366,7 @@ parameter_types! {
 	pub const DataDepositPerByte: Balance = 10;
 	pub const NftPalletId: PalletId = PalletId(*b"set/sNFT");
 	pub MaxAttributesBytes: u32 = 2048;
+	pub MaxRoyaltyRate: Permill = Permill::from_percent(50);
 }
 impl module_nft::Config for Test {
 	type Event = Event;
@@ -361,6 +376,9 @@ impl module_nft::Config for Test {
 	type DataDepositPerByte = DataDepositPerByte;
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = MaxAttributesBytes;
+	type MultiCurrency = Currencies;
+	type MaxRoyaltyRate = MaxRoyaltyRate;
+	type CustodyAccounts = Nothing;
 	type WeightInfo = ();
 }
 
@@ -509,6 +527,9 @@ pub type OraclePrecompile = crate::OraclePrecompile<
 	MockAddressMapping,
 	EvmCurrencyIdMapping,
 	module_prices::PriorityLockedPriceProvider<Test>,
+	module_prices::LockedPriceProvider<Test>,
+	Oracle,
+	Timestamp,
 >;
 pub type ScheduleCallPrecompile = crate::ScheduleCallPrecompile<
 	AccountId,
@@ -561,6 +582,7 @@ impl module_evm::Config for Test {
 		ScheduleCallPrecompile,
 		DexPrecompile,
 	>;
+	type PausedCalls = ();
 	type ChainId = ChainId;
 	type GasToWeight = GasToWeight;
 	type ChargeTransactionPayment = ChargeTransactionPayment;
@@ -572,14 +594,16 @@ impl module_evm::Config for Test {
 	type FreeDeploymentOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = ();
+	type Task = module_evm::EvmTask<Test>;
+	type IdleScheduler = ();
 	type WeightInfo = ();
 }
 
 parameter_types! {
-	pub SetUSDFixedPrice: Price = Price::saturating_from_rational(1, 1); // $1
-	pub SetterFixedPrice: Price = Price::saturating_from_rational(1, 10); // $0.1(10 cents)
-	pub const GetSetUSDId: CurrencyId = SETUSD;
-	pub const SetterCurrencyId: CurrencyId = SETR;
+	pub const MaxBasketSize: u32 = 5;
+	pub const PriceHistoryLength: u32 = 10;
+	pub const MaxHistoryCurrencies: u32 = 5;
+	pub const MaxCircuitBreakers: u32 = 5;
 }
 
 ord_parameter_types! {
@@ -589,11 +613,13 @@ ord_parameter_types! {
 impl module_prices::Config for Test {
 	type Event = Event;
 	type Source = Oracle;
-	type GetSetUSDId = GetSetUSDId;
-	type SetterCurrencyId = SetterCurrencyId;
-	type SetUSDFixedPrice = SetUSDFixedPrice;
-	type SetterFixedPrice = SetterFixedPrice;
+	type Time = Timestamp;
+	type MaxBasketSize = MaxBasketSize;
+	type PriceHistoryLength = PriceHistoryLength;
+	type MaxHistoryCurrencies = MaxHistoryCurrencies;
+	type MaxCircuitBreakers = MaxCircuitBreakers;
 	type LockOrigin = EnsureSignedBy<One, AccountId>;
+	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type DEX = DexModule;
 	type Currency = Currencies;
 	type CurrencyIdMapping = EvmCurrencyIdMapping;
@@ -682,9 +708,9 @@ frame_support::construct_runtime!(
 		Currencies: module_currencies::{Pallet, Call, Event<T>},
 		EVMBridge: module_evm_bridge::{Pallet},
 		EVMManager: module_evm_manager::{Pallet, Storage},
-		NFTModule: module_nft::{Pallet, Call, Event<T>},
+		NFTModule: module_nft::{Pallet, Call, Storage, Event<T>},
 		TransactionPayment: module_transaction_payment::{Pallet, Call, Storage},
-		Prices: module_prices::{Pallet, Storage, Call, Event<T>},
+		Prices: module_prices::{Pallet, Storage, Call, Config, Event<T>},
 		Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>},
 		Utility: pallet_utility::{Pallet, Call, Event},
 		Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
@@ -730,6 +756,16 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	}
 	.assimilate_storage(&mut storage)
 	.unwrap();
+	GenesisBuild::<Test>::assimilate_storage(
+		&module_prices::GenesisConfig {
+			pegs: vec![
+				(SETUSD, module_prices::PegDefinition::Fixed(Price::saturating_from_rational(1, 1))), // $1
+				(SETR, module_prices::PegDefinition::Fixed(Price::saturating_from_rational(1, 10))), // $0.1(10 cents)
+			],
+		},
+		&mut storage,
+	)
+	.unwrap();
 
 	let mut ext = sp_io::TestExternalities::new(storage);
 	ext.execute_with(|| {
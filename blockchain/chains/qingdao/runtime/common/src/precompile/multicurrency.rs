@@ -21,7 +21,10 @@
 use crate::precompile::PrecompileOutput;
 use frame_support::log;
 use module_evm::{Context, ExitError, ExitSucceed, Precompile};
-use module_support::{AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT};
+use module_support::{
+	AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT, MultiCurrencyAllowance,
+	MultiCurrencyMemo,
+};
 use sp_runtime::RuntimeDebug;
 use sp_std::{fmt::Debug, marker::PhantomData, prelude::*, result};
 
@@ -40,6 +43,10 @@ use primitives::{Balance, CurrencyId};
 /// - Query total issuance.
 /// - Query balance. Rest `input` bytes: `account_id`.
 /// - Transfer. Rest `input` bytes: `from`, `to`, `amount`.
+/// - Query allowance. Rest `input` bytes: `owner`, `spender`.
+/// - Approve. Rest `input` bytes: `owner`, `spender`, `amount`.
+/// - Transfer from. Rest `input` bytes: `spender`, `from`, `to`, `amount`.
+/// - Transfer with memo. Rest `input` bytes: `from`, `to`, `amount`, `memo`.
 pub struct MultiCurrencyPrecompile<AccountId, AddressMapping, CurrencyIdMapping, MultiCurrency>(
 	PhantomData<(AccountId, AddressMapping, CurrencyIdMapping, MultiCurrency)>,
 );
@@ -54,6 +61,10 @@ pub enum Action {
 	QueryTotalIssuance = "totalSupply()",
 	QueryBalance = "balanceOf(address)",
 	Transfer = "transfer(address,address,uint256)",
+	QueryAllowance = "allowance(address,address)",
+	Approve = "approve(address,address,uint256)",
+	TransferFrom = "transferFrom(address,address,address,uint256)",
+	TransferWithMemo = "transferWithMemo(address,address,uint256,bytes)",
 }
 
 impl<AccountId, AddressMapping, CurrencyIdMapping, MultiCurrency> Precompile
@@ -62,7 +73,9 @@ where
 	AccountId: Debug + Clone,
 	AddressMapping: AddressMappingT<AccountId>,
 	CurrencyIdMapping: CurrencyIdMappingT,
-	MultiCurrency: MultiCurrencyT<AccountId, Balance = Balance, CurrencyId = CurrencyId>,
+	MultiCurrency: MultiCurrencyT<AccountId, Balance = Balance, CurrencyId = CurrencyId>
+		+ MultiCurrencyAllowance<AccountId, CurrencyId, Balance>
+		+ MultiCurrencyMemo<AccountId, CurrencyId, Balance>,
 {
 	fn execute(
 		input: &[u8],
@@ -148,6 +161,89 @@ where
 					ExitError::Other(err_msg.into())
 				})?;
 
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
+			Action::QueryAllowance => {
+				let owner = input.account_id_at(1)?;
+				let spender = input.account_id_at(2)?;
+				let allowance = MultiCurrency::allowance(currency_id, &owner, &spender);
+				log::debug!(
+					target: "evm",
+					"multicurrency: owner: {:?}, spender: {:?}, allowance: {:?}",
+					owner, spender, allowance
+				);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: Output::default().encode_u128(allowance),
+					logs: Default::default(),
+				})
+			}
+			Action::Approve => {
+				let owner = input.account_id_at(1)?;
+				let spender = input.account_id_at(2)?;
+				let amount = input.balance_at(3)?;
+				log::debug!(target: "evm", "multicurrency: approve owner: {:?}, spender: {:?}, amount: {:?}", owner, spender, amount);
+
+				MultiCurrency::approve(currency_id, &owner, &spender, amount).map_err(|e| {
+					let err_msg: &str = e.into();
+					ExitError::Other(err_msg.into())
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
+			Action::TransferFrom => {
+				let spender = input.account_id_at(1)?;
+				let from = input.account_id_at(2)?;
+				let to = input.account_id_at(3)?;
+				let amount = input.balance_at(4)?;
+				log::debug!(
+					target: "evm",
+					"multicurrency: transfer from: {:?}, to: {:?}, amount: {:?}, spender: {:?}",
+					from, to, amount, spender
+				);
+
+				MultiCurrency::transfer_from(currency_id, &spender, &from, &to, amount).map_err(|e| {
+					let err_msg: &str = e.into();
+					ExitError::Other(err_msg.into())
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
+			Action::TransferWithMemo => {
+				let from = input.account_id_at(1)?;
+				let to = input.account_id_at(2)?;
+				let amount = input.balance_at(3)?;
+				// solidity abi enocde bytes will add an length at input[5]
+				let memo_len = input.u32_at(5)?;
+				let memo = input.bytes_at(6, memo_len as usize)?;
+				log::debug!(
+					target: "evm",
+					"multicurrency: transfer from: {:?}, to: {:?}, amount: {:?}, memo: {:?}",
+					from, to, amount, memo
+				);
+
+				MultiCurrency::transfer_with_memo(currency_id, &from, &to, amount, memo).map_err(|e| {
+					let err_msg: &str = e.into();
+					ExitError::Other(err_msg.into())
+				})?;
+
 				Ok(PrecompileOutput {
 					exit_status: ExitSucceed::Returned,
 					cost: 0,
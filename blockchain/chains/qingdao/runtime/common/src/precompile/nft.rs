@@ -21,7 +21,7 @@
 use crate::precompile::PrecompileOutput;
 use frame_support::log;
 use module_evm::{Context, ExitError, ExitSucceed, Precompile};
-use module_support::{AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT};
+use module_support::{AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT, NFTRoyalty};
 use sp_core::H160;
 use sp_runtime::RuntimeDebug;
 use sp_std::{borrow::Cow, fmt::Debug, marker::PhantomData, prelude::*, result};
@@ -30,7 +30,7 @@ use orml_traits::NFT as NFTT;
 
 use super::input::{Input, InputT, Output};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
-use primitives::NFTBalance;
+use primitives::{Balance, NFTBalance};
 
 /// The `NFT` impl precompile.
 ///
@@ -40,6 +40,8 @@ use primitives::NFTBalance;
 /// - Query balance. Rest `input` bytes: `account_id`.
 /// - Query owner. Rest `input` bytes: `class_id`, `token_id`.
 /// - Transfer. Rest `input`bytes: `from`, `to`, `class_id`, `token_id`.
+/// - Query royalty info. Rest `input` bytes: `class_id`, `token_id`,
+///   `sale_price`.
 pub struct NFTPrecompile<AccountId, AddressMapping, CurrencyIdMapping, NFT>(
 	PhantomData<(AccountId, AddressMapping, CurrencyIdMapping, NFT)>,
 );
@@ -51,6 +53,7 @@ pub enum Action {
 	QueryBalance = "balanceOf(address)",
 	QueryOwner = "ownerOf(uint256,uint256)",
 	Transfer = "transfer(address,address,uint256,uint256)",
+	QueryRoyaltyInfo = "royaltyInfo(uint256,uint256,uint256)",
 }
 
 impl<AccountId, AddressMapping, CurrencyIdMapping, NFT> Precompile
@@ -59,7 +62,7 @@ where
 	AccountId: Clone + Debug,
 	AddressMapping: AddressMappingT<AccountId>,
 	CurrencyIdMapping: CurrencyIdMappingT,
-	NFT: NFTT<AccountId, Balance = NFTBalance, ClassId = u32, TokenId = u64>,
+	NFT: NFTT<AccountId, Balance = NFTBalance, ClassId = u32, TokenId = u64> + NFTRoyalty<AccountId, u32, Balance>,
 {
 	fn execute(
 		input: &[u8],
@@ -123,6 +126,31 @@ where
 					logs: Default::default(),
 				})
 			}
+			Action::QueryRoyaltyInfo => {
+				let class_id = input.u32_at(1)?;
+				let token_id = input.u64_at(2)?;
+				let sale_price = input.balance_at(3)?;
+
+				log::debug!(target: "evm", "nft: query_royalty_info class_id: {:?}, token_id: {:?}, sale_price: {:?}", class_id, token_id, sale_price);
+
+				let (receiver, royalty): (H160, Balance) =
+					if let Some((recipient, royalty)) = NFT::royalty_info(class_id, sale_price) {
+						(
+							AddressMapping::get_evm_address(&recipient)
+								.unwrap_or_else(|| AddressMapping::get_default_evm_address(&recipient)),
+							royalty,
+						)
+					} else {
+						(Default::default(), Default::default())
+					};
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: Output::default().encode_address_u128_tuple(&receiver, royalty),
+					logs: Default::default(),
+				})
+			}
 		}
 	}
 }
@@ -19,16 +19,22 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::precompile::PrecompileOutput;
-use frame_support::{log, sp_runtime::FixedPointNumber};
+use frame_support::{
+	log,
+	sp_runtime::{traits::Zero, FixedPointNumber},
+	traits::Time as TimeT,
+};
 use module_evm::{Context, ExitError, ExitSucceed, Precompile};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
-use primitives::CurrencyId;
+use orml_oracle::TimestampedValue;
+use orml_traits::DataProviderExtended;
+use primitives::{CurrencyId, Moment};
 use sp_runtime::RuntimeDebug;
 use sp_std::{fmt::Debug, marker::PhantomData, prelude::*, result};
 
 use super::input::{Input, InputT, Output};
 use module_support::{
-	AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT, PriceProvider as PriceProviderT,
+	AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT, Price, PriceProvider as PriceProviderT,
 };
 
 /// The `Oracle` impl precompile.
@@ -38,8 +44,32 @@ use module_support::{
 ///
 /// Actions:
 /// - Get price. Rest `input` bytes: `currency_id`.
-pub struct OraclePrecompile<AccountId, AddressMapping, CurrencyIdMapping, PriceProvider>(
-	PhantomData<(AccountId, AddressMapping, CurrencyIdMapping, PriceProvider)>,
+/// - Get price with timestamp. Rest `input` bytes: `currency_id`.
+/// - Get relative price. Rest `input` bytes: `base_currency_id`,
+///   `quote_currency_id`.
+/// - Get locked price. Rest `input` bytes: `currency_id`.
+/// - Latest round data of Chainlink `AggregatorV3Interface`. Rest `input`
+///   bytes: `currency_id`.
+/// - Round data of Chainlink `AggregatorV3Interface`. Rest `input` bytes:
+///   `currency_id`, `round_id`.
+pub struct OraclePrecompile<
+	AccountId,
+	AddressMapping,
+	CurrencyIdMapping,
+	PriceProvider,
+	LockedPriceProvider,
+	DataProvider,
+	Time,
+>(
+	PhantomData<(
+		AccountId,
+		AddressMapping,
+		CurrencyIdMapping,
+		PriceProvider,
+		LockedPriceProvider,
+		DataProvider,
+		Time,
+	)>,
 );
 
 #[primitives_proc_macro::generate_function_selector]
@@ -47,15 +77,30 @@ pub struct OraclePrecompile<AccountId, AddressMapping, CurrencyIdMapping, PriceP
 #[repr(u32)]
 pub enum Action {
 	GetPrice = "getPrice(address)",
+	GetPriceWithTimestamp = "getPriceWithTimestamp(address)",
+	GetRelativePrice = "getRelativePrice(address,address)",
+	GetLockedPrice = "getLockedPrice(address)",
+	LatestRoundData = "latestRoundData(address)",
+	GetRoundData = "getRoundData(address,uint80)",
 }
 
-impl<AccountId, AddressMapping, CurrencyIdMapping, PriceProvider> Precompile
-	for OraclePrecompile<AccountId, AddressMapping, CurrencyIdMapping, PriceProvider>
-where
+impl<AccountId, AddressMapping, CurrencyIdMapping, PriceProvider, LockedPriceProvider, DataProvider, Time> Precompile
+	for OraclePrecompile<
+		AccountId,
+		AddressMapping,
+		CurrencyIdMapping,
+		PriceProvider,
+		LockedPriceProvider,
+		DataProvider,
+		Time,
+	> where
 	AccountId: Debug + Clone,
 	AddressMapping: AddressMappingT<AccountId>,
 	CurrencyIdMapping: CurrencyIdMappingT,
 	PriceProvider: PriceProviderT<CurrencyId>,
+	LockedPriceProvider: PriceProviderT<CurrencyId>,
+	DataProvider: DataProviderExtended<CurrencyId, TimestampedValue<Price, Moment>>,
+	Time: TimeT<Moment = Moment>,
 {
 	fn execute(
 		input: &[u8],
@@ -66,43 +111,140 @@ where
 
 		let action = input.action()?;
 
-		match action {
+		let output = match action {
 			Action::GetPrice => {
 				let currency_id = input.currency_id_at(1)?;
-				let mut price = PriceProvider::get_price(currency_id).unwrap_or_default();
-
-				let maybe_decimals = CurrencyIdMapping::decimals(currency_id);
-				let decimals = match maybe_decimals {
-					Some(decimals) => decimals,
-					None => {
-						// If the option is none, let price = 0 to return 0.
-						// Solidity should handle the situation of price 0.
-						price = Default::default();
-						Default::default()
-					}
-				};
-
-				let maybe_adjustment_multiplier = 10u128.checked_pow((18 - decimals).into());
-				let adjustment_multiplier = match maybe_adjustment_multiplier {
-					Some(adjustment_multiplier) => adjustment_multiplier,
-					None => {
-						// If the option is none, let price = 0 to return 0.
-						// Solidity should handle the situation of price 0.
-						price = Default::default();
-						Default::default()
-					}
-				};
-
-				let output = price.into_inner().wrapping_div(adjustment_multiplier);
-
-				log::debug!(target: "evm", "oracle: getPrice currency_id: {:?}, price: {:?}, adjustment_multiplier: {:?}, output: {:?}", currency_id, price, adjustment_multiplier, output);
-				Ok(PrecompileOutput {
-					exit_status: ExitSucceed::Returned,
-					cost: 0,
-					output: Output::default().encode_u128(output),
-					logs: Default::default(),
-				})
+				let price = PriceProvider::get_price(currency_id);
+				let output = Self::output_price(currency_id, price);
+
+				log::debug!(target: "evm", "oracle: getPrice currency_id: {:?}, price: {:?}, output: {:?}", currency_id, price, output);
+				Output::default().encode_u128(output)
+			}
+			Action::GetPriceWithTimestamp => {
+				let currency_id = input.currency_id_at(1)?;
+				let (price, timestamp) = Self::oracle_price(currency_id).unwrap_or((None, Default::default()));
+				let output = Self::output_price(currency_id, price);
+
+				log::debug!(target: "evm", "oracle: getPriceWithTimestamp currency_id: {:?}, price: {:?}, timestamp: {:?}, output: {:?}", currency_id, price, timestamp, output);
+				Output::default().encode_u128_tuple(output, Self::seconds(timestamp))
+			}
+			Action::GetRelativePrice => {
+				let base_currency_id = input.currency_id_at(1)?;
+				let quote_currency_id = input.currency_id_at(2)?;
+				// the relative price of the basic units, 18 decimals. Returns
+				// 0 if any price is missing, Solidity should handle it.
+				let price = PriceProvider::get_relative_price(base_currency_id, quote_currency_id);
+				let output = price.map(|price| price.into_inner()).unwrap_or_default();
+
+				log::debug!(target: "evm", "oracle: getRelativePrice base_currency_id: {:?}, quote_currency_id: {:?}, price: {:?}", base_currency_id, quote_currency_id, price);
+				Output::default().encode_u128(output)
+			}
+			Action::GetLockedPrice => {
+				let currency_id = input.currency_id_at(1)?;
+				let price = LockedPriceProvider::get_price(currency_id);
+				let output = Self::output_price(currency_id, price);
+
+				log::debug!(target: "evm", "oracle: getLockedPrice currency_id: {:?}, price: {:?}, output: {:?}", currency_id, price, output);
+				Output::default().encode_u128(output)
+			}
+			Action::LatestRoundData => {
+				let currency_id = input.currency_id_at(1)?;
+				let (round_id, answer) = Self::round_data(currency_id)?;
+
+				log::debug!(target: "evm", "oracle: latestRoundData currency_id: {:?}, round_id: {:?}, answer: {:?}", currency_id, round_id, answer);
+				// roundId, answer, startedAt, updatedAt, answeredInRound
+				Output::default().encode_u128_list(&[round_id, answer, round_id, round_id, round_id])
+			}
+			Action::GetRoundData => {
+				let currency_id = input.currency_id_at(1)?;
+				let round_id = input.u64_at(2)?;
+				// only the latest round is available, as the prices of the past
+				// rounds are not kept.
+				let (latest_round_id, answer) = Self::round_data(currency_id)?;
+				if u128::from(round_id) != latest_round_id {
+					return Err(ExitError::Other("No data present".into()));
+				}
+
+				log::debug!(target: "evm", "oracle: getRoundData currency_id: {:?}, round_id: {:?}, answer: {:?}", currency_id, round_id, answer);
+				// roundId, answer, startedAt, updatedAt, answeredInRound
+				Output::default().encode_u128_list(&[
+					latest_round_id,
+					answer,
+					latest_round_id,
+					latest_round_id,
+					latest_round_id,
+				])
 			}
+		};
+
+		Ok(PrecompileOutput {
+			exit_status: ExitSucceed::Returned,
+			cost: 0,
+			output,
+			logs: Default::default(),
+		})
+	}
+}
+
+impl<AccountId, AddressMapping, CurrencyIdMapping, PriceProvider, LockedPriceProvider, DataProvider, Time>
+	OraclePrecompile<AccountId, AddressMapping, CurrencyIdMapping, PriceProvider, LockedPriceProvider, DataProvider, Time>
+where
+	CurrencyIdMapping: CurrencyIdMappingT,
+	PriceProvider: PriceProviderT<CurrencyId>,
+	DataProvider: DataProviderExtended<CurrencyId, TimestampedValue<Price, Moment>>,
+	Time: TimeT<Moment = Moment>,
+{
+	/// Convert the price for 1 basic unit to the output of `getPrice`.
+	///
+	/// If the price or the decimals are none, returns 0. Solidity should
+	/// handle the situation of price 0.
+	fn output_price(currency_id: CurrencyId, price: Option<Price>) -> u128 {
+		let maybe_adjustment_multiplier = CurrencyIdMapping::decimals(currency_id)
+			.and_then(|decimals| 18u8.checked_sub(decimals))
+			.and_then(|exponent| 10u128.checked_pow(exponent.into()));
+
+		match (price, maybe_adjustment_multiplier) {
+			(Some(price), Some(adjustment_multiplier)) => price.into_inner().wrapping_div(adjustment_multiplier),
+			_ => Default::default(),
 		}
 	}
+
+	/// The oracle price of `currency_id` for 1 basic unit, and the time it
+	/// was fed.
+	///
+	/// The oracle price is for 1 unit of the currency, the price is none if
+	/// the decimals are unknown.
+	fn oracle_price(currency_id: CurrencyId) -> Option<(Option<Price>, Moment)> {
+		DataProvider::get_no_op(&currency_id).map(|TimestampedValue { value, timestamp }| {
+			let price = CurrencyIdMapping::decimals(currency_id)
+				.and_then(|decimals| Price::checked_from_rational(value.into_inner(), 10u128.checked_pow(decimals.into())?));
+			(price, timestamp)
+		})
+	}
+
+	/// The Chainlink round of the price of `currency_id`, returns the round id
+	/// and the answer.
+	///
+	/// The answer is the price for 1 unit of the currency with 18 decimals.
+	/// Rounds are identified by the update time in seconds. The answer and
+	/// the round always come from the same source:
+	/// - For currencies fed by the oracle, the last oracle feed and its time.
+	/// - For the others, such as pegged currencies and LP tokens, the price of
+	///   `PriceProvider` at the current time. Their round changes with the
+	///   block time, so `getRoundData` of an earlier round always reverts.
+	fn round_data(currency_id: CurrencyId) -> result::Result<(u128, u128), ExitError> {
+		let (price, updated_at) =
+			Self::oracle_price(currency_id).unwrap_or_else(|| (PriceProvider::get_price(currency_id), Time::now()));
+		let answer = Self::output_price(currency_id, price);
+		if answer.is_zero() {
+			return Err(ExitError::Other("No data present".into()));
+		}
+
+		Ok((Self::seconds(updated_at), answer))
+	}
+
+	/// Convert the timestamp in milliseconds to seconds, as used by Solidity.
+	fn seconds(timestamp: Moment) -> u128 {
+		u128::from(timestamp / 1000)
+	}
 }
@@ -24,9 +24,10 @@ use super::*;
 use crate::precompile::{
 	mock::{
 		setm_evm_address, alice, alice_evm_addr, setusd_evm_address, bob, bob_evm_addr, erc20_address_not_exists,
-		get_task_id, lp_setm_setusd_evm_address, new_test_ext, serp_evm_address, run_to_block, Balances, DexModule,
-		DexPrecompile, Event as TestEvent, MultiCurrencyPrecompile, Oracle, OraclePrecompile, Origin, Price,
-		ScheduleCallPrecompile, System, Test, ALICE, SETUSD, INITIAL_BALANCE, SERP,
+		get_task_id, lp_setm_setusd_evm_address, new_test_ext, serp_evm_address, run_to_block, Balances, Currencies,
+		DexModule, DexPrecompile, Event as TestEvent, MultiCurrencyPrecompile, NFTModule, NFTPrecompile, NftPalletId,
+		Oracle, OraclePrecompile, Origin, Price, Prices, ScheduleCallPrecompile, System, Test, Timestamp, ALICE, SETUSD,
+		INITIAL_BALANCE, SERP,
 	},
 	schedule_call::TaskInfo,
 };
@@ -34,11 +35,14 @@ use codec::Encode;
 use frame_support::{assert_noop, assert_ok};
 use hex_literal::hex;
 use module_evm::{Context, ExitError, ExitSucceed, Precompile};
-use module_support::AddressMapping;
-use orml_traits::DataFeeder;
+use module_support::{AddressMapping, MultiCurrencyAllowance, PriceProvider};
+use orml_traits::{DataFeeder, MultiCurrency};
 use primitives::{Balance, PREDEPLOY_ADDRESS_START};
 use sp_core::{H160, U256};
-use sp_runtime::FixedPointNumber;
+use sp_runtime::{
+	traits::{AccountIdConversion, Zero},
+	FixedPointNumber, Permill,
+};
 use std::str::FromStr;
 
 pub struct DummyPrecompile;
@@ -301,6 +305,399 @@ fn precompile_filter_does_not_work_on_non_system_contracts() {
 // 	});
 // }
 
+#[test]
+fn multicurrency_precompile_allowance_should_work() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: serp_evm_address(),
+			apparent_value: Default::default(),
+		};
+
+		// 1.Approve
+		let mut input = [0u8; 4 + 3 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(multicurrency::Action::Approve).to_be_bytes());
+		// owner
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+		// spender
+		U256::from(bob_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// amount
+		U256::from(100).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+
+		let resp = MultiCurrencyPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, Vec::<u8>::new());
+		assert_eq!(resp.cost, 0);
+		assert_eq!(Currencies::allowance(SERP, &alice(), &bob()), 100);
+
+		// 2.QueryAllowance
+		let mut input = [0u8; 4 + 2 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(multicurrency::Action::QueryAllowance).to_be_bytes());
+		// owner
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+		// spender
+		U256::from(bob_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+
+		let resp = MultiCurrencyPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		let mut expected_output = [0u8; 32];
+		expected_output[16..32].copy_from_slice(&100u128.to_be_bytes()[..]);
+		assert_eq!(resp.output, expected_output);
+		assert_eq!(resp.cost, 0);
+
+		// 3.TransferFrom
+		let mut input = [0u8; 4 + 4 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(multicurrency::Action::TransferFrom).to_be_bytes());
+		// spender
+		U256::from(bob_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+		// from
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// to
+		U256::from(bob_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		// amount
+		U256::from(60).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+
+		let resp = MultiCurrencyPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, Vec::<u8>::new());
+		assert_eq!(resp.cost, 0);
+		assert_eq!(Currencies::free_balance(SERP, &alice()), 1_000 - 60);
+		assert_eq!(Currencies::free_balance(SERP, &bob()), 60);
+		assert_eq!(Currencies::allowance(SERP, &alice(), &bob()), 40);
+
+		// the remaining allowance is too low
+		assert_noop!(
+			MultiCurrencyPrecompile::execute(&input, None, &context),
+			ExitError::Other("AllowanceTooLow".into())
+		);
+	});
+}
+
+#[test]
+fn multicurrency_precompile_transfer_with_memo_should_work() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: serp_evm_address(),
+			apparent_value: Default::default(),
+		};
+		let memo = b"deposit:42".to_vec();
+
+		let mut input = [0u8; 4 + 6 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(multicurrency::Action::TransferWithMemo).to_be_bytes());
+		// from
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+		// to
+		U256::from(bob_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// amount
+		U256::from(10).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		// memo offset
+		U256::from(4 * 32).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+		// memo length
+		U256::from(memo.len()).to_big_endian(&mut input[4 + 4 * 32..4 + 5 * 32]);
+		// memo bytes, right padded
+		input[4 + 5 * 32..4 + 5 * 32 + memo.len()].copy_from_slice(&memo);
+
+		let resp = MultiCurrencyPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, Vec::<u8>::new());
+		assert_eq!(resp.cost, 0);
+		assert_eq!(Currencies::free_balance(SERP, &alice()), 1_000 - 10);
+		assert_eq!(Currencies::free_balance(SERP, &bob()), 10);
+		System::assert_last_event(TestEvent::Currencies(module_currencies::Event::TransferredWithMemo(
+			SERP,
+			alice(),
+			bob(),
+			10,
+			memo,
+		)));
+
+		// zero amount
+		U256::from(0).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		assert_noop!(
+			MultiCurrencyPrecompile::execute(&input, None, &context),
+			ExitError::Other("ZeroAmount".into())
+		);
+	});
+}
+
+#[test]
+fn oracle_precompile_get_price_with_timestamp_should_work() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		let price = Price::from(30_000);
+
+		// action + currency_id
+		let mut input = [0u8; 4 + 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(oracle::Action::GetPriceWithTimestamp).to_be_bytes());
+		// SERP
+		U256::from(serp_evm_address().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+
+		// no price yet
+		let resp = OraclePrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, [0u8; 64]);
+		assert_eq!(resp.cost, 0);
+
+		Timestamp::set_timestamp(12_000);
+		assert_ok!(Oracle::feed_value(ALICE, SERP, price));
+
+		// returned price + timestamp in seconds
+		let mut expected_output = [0u8; 64];
+		U256::from(price.into_inner()).to_big_endian(&mut expected_output[0..32]);
+		U256::from(12).to_big_endian(&mut expected_output[32..64]);
+
+		let resp = OraclePrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, expected_output);
+		assert_eq!(resp.cost, 0);
+	});
+}
+
+#[test]
+fn oracle_precompile_get_relative_price_should_work() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		// action + base_currency_id + quote_currency_id
+		let mut input = [0u8; 4 + 2 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(oracle::Action::GetRelativePrice).to_be_bytes());
+		// SERP
+		U256::from(serp_evm_address().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+		// SETUSD
+		U256::from(setusd_evm_address().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+
+		// the SERP price is missing
+		let resp = OraclePrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, [0u8; 32]);
+		assert_eq!(resp.cost, 0);
+
+		assert_ok!(Oracle::feed_value(ALICE, SERP, Price::from(30_000)));
+
+		let relative_price =
+			module_prices::PriorityLockedPriceProvider::<Test>::get_relative_price(SERP, SETUSD).unwrap();
+		assert!(!relative_price.is_zero());
+		let mut expected_output = [0u8; 32];
+		U256::from(relative_price.into_inner()).to_big_endian(&mut expected_output[..]);
+
+		let resp = OraclePrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, expected_output);
+		assert_eq!(resp.cost, 0);
+	});
+}
+
+#[test]
+fn oracle_precompile_get_locked_price_should_work() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		let price = Price::from(30_000);
+
+		// action + currency_id
+		let mut input = [0u8; 4 + 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(oracle::Action::GetLockedPrice).to_be_bytes());
+		// SERP
+		U256::from(serp_evm_address().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+
+		// the price is fed but not locked
+		assert_ok!(Oracle::feed_value(ALICE, SERP, price));
+		let resp = OraclePrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, [0u8; 32]);
+		assert_eq!(resp.cost, 0);
+
+		assert_ok!(Prices::lock_price(Origin::signed(ALICE), SERP));
+
+		// a new feed doesn't change the locked price
+		assert_ok!(Oracle::feed_value(ALICE, SERP, Price::from(40_000)));
+
+		let mut expected_output = [0u8; 32];
+		U256::from(price.into_inner()).to_big_endian(&mut expected_output[..]);
+
+		let resp = OraclePrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, expected_output);
+		assert_eq!(resp.cost, 0);
+	});
+}
+
+#[test]
+fn oracle_precompile_latest_round_data_should_work() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		let price = Price::from(30_000);
+
+		// action + currency_id
+		let mut input = [0u8; 4 + 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(oracle::Action::LatestRoundData).to_be_bytes());
+		// SERP
+		U256::from(serp_evm_address().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+
+		// no price yet
+		assert_noop!(
+			OraclePrecompile::execute(&input, None, &context),
+			ExitError::Other("No data present".into())
+		);
+
+		Timestamp::set_timestamp(12_000);
+		assert_ok!(Oracle::feed_value(ALICE, SERP, price));
+
+		// roundId, answer, startedAt, updatedAt, answeredInRound
+		let mut expected_output = [0u8; 5 * 32];
+		U256::from(12).to_big_endian(&mut expected_output[0 * 32..1 * 32]);
+		U256::from(price.into_inner()).to_big_endian(&mut expected_output[1 * 32..2 * 32]);
+		U256::from(12).to_big_endian(&mut expected_output[2 * 32..3 * 32]);
+		U256::from(12).to_big_endian(&mut expected_output[3 * 32..4 * 32]);
+		U256::from(12).to_big_endian(&mut expected_output[4 * 32..5 * 32]);
+
+		let resp = OraclePrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, expected_output);
+		assert_eq!(resp.cost, 0);
+
+		// the round of a pegged price is the current time
+		Timestamp::set_timestamp(30_000);
+		U256::from(setusd_evm_address().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+
+		let mut expected_output = [0u8; 5 * 32];
+		U256::from(30).to_big_endian(&mut expected_output[0 * 32..1 * 32]);
+		U256::from(Price::from(1).into_inner()).to_big_endian(&mut expected_output[1 * 32..2 * 32]);
+		U256::from(30).to_big_endian(&mut expected_output[2 * 32..3 * 32]);
+		U256::from(30).to_big_endian(&mut expected_output[3 * 32..4 * 32]);
+		U256::from(30).to_big_endian(&mut expected_output[4 * 32..5 * 32]);
+
+		let resp = OraclePrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, expected_output);
+		assert_eq!(resp.cost, 0);
+	});
+}
+
+#[test]
+fn oracle_precompile_get_round_data_should_work() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		let price = Price::from(30_000);
+
+		Timestamp::set_timestamp(12_000);
+		assert_ok!(Oracle::feed_value(ALICE, SERP, price));
+
+		// action + currency_id + round_id
+		let mut input = [0u8; 4 + 2 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(oracle::Action::GetRoundData).to_be_bytes());
+		// SERP
+		U256::from(serp_evm_address().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+		// round_id
+		U256::from(12).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+
+		// roundId, answer, startedAt, updatedAt, answeredInRound
+		let mut expected_output = [0u8; 5 * 32];
+		U256::from(12).to_big_endian(&mut expected_output[0 * 32..1 * 32]);
+		U256::from(price.into_inner()).to_big_endian(&mut expected_output[1 * 32..2 * 32]);
+		U256::from(12).to_big_endian(&mut expected_output[2 * 32..3 * 32]);
+		U256::from(12).to_big_endian(&mut expected_output[3 * 32..4 * 32]);
+		U256::from(12).to_big_endian(&mut expected_output[4 * 32..5 * 32]);
+
+		let resp = OraclePrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, expected_output);
+		assert_eq!(resp.cost, 0);
+
+		// unknown round
+		U256::from(11).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		assert_noop!(
+			OraclePrecompile::execute(&input, None, &context),
+			ExitError::Other("No data present".into())
+		);
+
+		// the price of the round is no longer kept after a new feed
+		Timestamp::set_timestamp(24_000);
+		assert_ok!(Oracle::feed_value(ALICE, SERP, Price::from(40_000)));
+		U256::from(12).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		assert_noop!(
+			OraclePrecompile::execute(&input, None, &context),
+			ExitError::Other("No data present".into())
+		);
+	});
+}
+
+#[test]
+fn oracle_precompile_get_round_data_of_pegged_currency_should_work() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		// action + currency_id + round_id
+		let mut input = [0u8; 4 + 2 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(oracle::Action::GetRoundData).to_be_bytes());
+		// SETUSD
+		U256::from(setusd_evm_address().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+		// round_id
+		U256::from(30).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+
+		// the round of a pegged price is the current time
+		Timestamp::set_timestamp(30_000);
+
+		// roundId, answer, startedAt, updatedAt, answeredInRound
+		let mut expected_output = [0u8; 5 * 32];
+		U256::from(30).to_big_endian(&mut expected_output[0 * 32..1 * 32]);
+		U256::from(Price::from(1).into_inner()).to_big_endian(&mut expected_output[1 * 32..2 * 32]);
+		U256::from(30).to_big_endian(&mut expected_output[2 * 32..3 * 32]);
+		U256::from(30).to_big_endian(&mut expected_output[3 * 32..4 * 32]);
+		U256::from(30).to_big_endian(&mut expected_output[4 * 32..5 * 32]);
+
+		let resp = OraclePrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, expected_output);
+		assert_eq!(resp.cost, 0);
+
+		// an earlier round of a pegged price always reverts
+		Timestamp::set_timestamp(42_000);
+		assert_noop!(
+			OraclePrecompile::execute(&input, None, &context),
+			ExitError::Other("No data present".into())
+		);
+	});
+}
+
 // #[test]
 // fn oracle_precompile_should_work() {
 // 	new_test_ext().execute_with(|| {
@@ -881,6 +1278,62 @@ fn precompile_filter_does_not_work_on_non_system_contracts() {
 // 	});
 // }
 
+#[test]
+fn nft_precompile_query_royalty_info_should_work() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(alice()),
+			vec![1],
+			module_nft::Properties(module_nft::ClassProperty::Transferable.into()),
+			Default::default(),
+		));
+
+		// action + class_id + token_id + sale_price
+		let mut input = [0u8; 4 + 3 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(nft::Action::QueryRoyaltyInfo).to_be_bytes());
+		// class_id
+		U256::from(0).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+		// token_id
+		U256::from(0).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// sale_price
+		U256::from(1_000).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+
+		// no royalty: zero address and zero amount
+		let expected_output = [0u8; 2 * 32];
+		let resp = NFTPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, expected_output);
+		assert_eq!(resp.cost, 0);
+
+		let class_owner = NftPalletId::get().into_sub_account(0u32);
+		assert_ok!(NFTModule::set_class_royalty(
+			Origin::signed(class_owner),
+			0,
+			Some(module_nft::Royalty {
+				recipient: alice(),
+				rate: Permill::from_percent(10),
+			})
+		));
+
+		// receiver, royaltyAmount
+		let mut expected_output = [0u8; 2 * 32];
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut expected_output[0 * 32..1 * 32]);
+		U256::from(100).to_big_endian(&mut expected_output[1 * 32..2 * 32]);
+
+		let resp = NFTPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, expected_output);
+		assert_eq!(resp.cost, 0);
+	});
+}
+
 #[test]
 fn task_id_max_and_min() {
 	let task_id = TaskInfo {
@@ -21,11 +21,12 @@
 use crate::{dollar, AccountId, CurrencyId, Event, EvmAccounts, GetNativeCurrencyId, Origin, Runtime, System, EVM};
 
 use super::utils::set_balance;
-use frame_support::dispatch::DispatchError;
+use frame_support::{dispatch::DispatchError, weights::Weight};
 use frame_system::RawOrigin;
-use module_support::AddressMapping;
+use module_evm::EvmTask;
+use module_support::{AddressMapping, DispatchableTask};
 use orml_benchmarking::{runtime_benchmarks, whitelist_account};
-use sp_core::H160;
+use sp_core::{H160, H256};
 use sp_io::hashing::keccak_256;
 use sp_std::{str::FromStr, vec};
 
@@ -154,6 +155,25 @@ runtime_benchmarks! {
 
 		whitelist_account!(alice_account);
 	}: _(RawOrigin::Signed(alice_account_id()), contract)
+
+	remove_contract_storage {
+		let c in 0 .. module_evm::REMOVE_STORAGE_LIMIT;
+		let alice_account = alice_account_id();
+
+		set_balance(NATIVE, &alice_account, 1_000_000 * dollar(NATIVE));
+		let contract = deploy_contract(alice_account_id())?;
+		for i in 0 .. c {
+			module_evm::AccountStorages::<Runtime>::insert(contract, H256::from_low_u64_be(i.into()), H256::from_low_u64_be(1));
+		}
+		EVM::selfdestruct(Origin::signed(alice_account_id()), contract).map_err(|e| e.error)?;
+	}: {
+		let result = EvmTask::<Runtime>::Remove {
+			contract,
+			refund_to: Some(alice_account_id()),
+		}
+		.dispatch(Weight::max_value());
+		assert!(result.finished);
+	}
 }
 
 #[cfg(test)]
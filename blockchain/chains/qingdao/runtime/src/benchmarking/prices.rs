@@ -18,15 +18,51 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CurrencyId, GetSetUSDId, Origin, Price, Prices, Runtime};
+use crate::{
+	AssetRegistry, CurrencyId, GetDinarCurrencyId, GetSetUSDId, MaxBasketSize, MaxCircuitBreakers, MaxHistoryCurrencies,
+	Origin, PegDefinition, Price, PriceHistoryLength, Prices, Runtime, System,
+};
 
 use super::utils::feed_price;
 use frame_system::RawOrigin;
+use frame_support::traits::{Get, OnFinalize, OnInitialize};
+use module_prices::CircuitBreaker;
+use module_support::{AssetMetadata, Ratio};
 use orml_benchmarking::runtime_benchmarks;
-use sp_runtime::traits::One;
-use sp_std::vec;
+use primitives::ForeignAssetId;
+use sp_runtime::{traits::One, DispatchError, FixedPointNumber};
+use sp_std::prelude::*;
 
 const SETUSD: CurrencyId = GetSetUSDId::get();
+const DNAR: CurrencyId = GetDinarCurrencyId::get();
+
+fn circuit_breaker() -> CircuitBreaker {
+	CircuitBreaker {
+		max_deviation_per_block: Ratio::saturating_from_rational(1, 10),
+		max_staleness: 3_600_000,
+	}
+}
+
+/// Register `c` foreign assets with oracle prices.
+fn setup_priced_currencies(c: u32) -> Result<Vec<CurrencyId>, DispatchError> {
+	let mut currency_ids = vec![];
+	for i in 0..c {
+		let foreign_asset_id: ForeignAssetId = AssetRegistry::next_foreign_asset_id();
+		AssetRegistry::register_foreign_asset(
+			RawOrigin::Root.into(),
+			AssetMetadata {
+				name: b"Foreign Asset".to_vec(),
+				symbol: b"FA".to_vec(),
+				decimals: 12,
+				minimal_balance: 1 + i as u128,
+			},
+		)?;
+		currency_ids.push(CurrencyId::ForeignAsset(foreign_asset_id));
+	}
+	feed_price(currency_ids.iter().map(|currency_id| (*currency_id, Price::one())).collect())?;
+
+	Ok(currency_ids)
+}
 
 runtime_benchmarks! {
 	{ Runtime, module_prices }
@@ -41,6 +77,54 @@ runtime_benchmarks! {
 		feed_price(vec![(SETUSD, Price::one())])?;
 		Prices::lock_price(Origin::root(), SETUSD)?;
 	}: _(RawOrigin::Root, SETUSD)
+
+	set_peg {
+		let c in 1 .. MaxBasketSize::get();
+
+		let basket = vec![(DNAR, Price::one()); c as usize];
+	}: _(RawOrigin::Root, SETUSD, Some(PegDefinition::Basket(basket)))
+
+	set_circuit_breaker {
+		feed_price(vec![(DNAR, Price::one())])?;
+	}: _(RawOrigin::Root, DNAR, Some(circuit_breaker()))
+
+	on_initialize {
+		let c in 1 .. MaxCircuitBreakers::get();
+
+		let currency_ids = setup_priced_currencies(c)?;
+		for currency_id in currency_ids {
+			Prices::set_circuit_breaker(Origin::root(), currency_id, Some(circuit_breaker()))?;
+		}
+		// record the last good prices, which are then compared with the prices
+		Prices::on_initialize(1);
+	}: {
+		Prices::on_initialize(2);
+	}
+
+	set_history_currencies {
+		let c in 1 .. MaxHistoryCurrencies::get();
+
+		// replace the currencies, removing the histories of the former ones
+		let former_currency_ids = (0..c).map(CurrencyId::ForeignAsset).collect::<Vec<_>>();
+		Prices::set_history_currencies(Origin::root(), former_currency_ids.clone())?;
+		for currency_id in former_currency_ids {
+			module_prices::PriceHistory::<Runtime>::insert(currency_id, vec![Prices::price_record(currency_id)]);
+		}
+		let currency_ids = (c..2 * c).map(CurrencyId::ForeignAsset).collect::<Vec<_>>();
+	}: _(RawOrigin::Root, currency_ids)
+
+	record_price_history {
+		let c in 1 .. MaxHistoryCurrencies::get();
+
+		let currency_ids = setup_priced_currencies(c)?;
+		Prices::set_history_currencies(Origin::root(), currency_ids)?;
+		// fill the ring buffers, the next record overwrites the oldest one
+		for _ in 0..PriceHistoryLength::get() {
+			Prices::on_finalize(System::block_number());
+		}
+	}: {
+		Prices::on_finalize(System::block_number());
+	}
 }
 
 #[cfg(test)]
@@ -18,10 +18,20 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Origin, Runtime, TransactionPause};
+use crate::{MaxExpiringPauses, MaxPauseBatch, Origin, Runtime, System, TransactionPause};
 
+use frame_support::traits::{Get, OnInitialize};
 use frame_system::RawOrigin;
+use module_transaction_pause::PauseTarget;
 use orml_benchmarking::runtime_benchmarks;
+use primitives::evm::EvmAddress;
+use sp_std::prelude::*;
+
+fn pause_targets(c: u32) -> Vec<PauseTarget> {
+	(0..c)
+		.map(|i| PauseTarget::EvmCall(EvmAddress::from_low_u64_be(i.into()), None))
+		.collect()
+}
 
 runtime_benchmarks! {
 	{ Runtime, module_transaction_pause }
@@ -32,6 +42,27 @@ runtime_benchmarks! {
 	unpause_transaction {
 		TransactionPause::pause_transaction(Origin::root(), b"Balances".to_vec(), b"transfer".to_vec())?;
 	}: _(RawOrigin::Root, b"Balances".to_vec(), b"transfer".to_vec())
+
+	pause_batch {
+		let c in 1 .. MaxPauseBatch::get();
+	}: _(RawOrigin::Root, pause_targets(c), Some(100u32))
+
+	unpause_batch {
+		let c in 1 .. MaxPauseBatch::get();
+
+		TransactionPause::pause_batch(Origin::root(), pause_targets(c), Some(100u32))?;
+	}: _(RawOrigin::Root, pause_targets(c))
+
+	on_initialize {
+		let c in 1 .. MaxExpiringPauses::get();
+
+		for targets in pause_targets(c).chunks(MaxPauseBatch::get() as usize) {
+			TransactionPause::pause_batch(Origin::root(), targets.to_vec(), Some(100u32))?;
+		}
+		System::set_block_number(100u32);
+	}: {
+		TransactionPause::on_initialize(100u32);
+	}
 }
 
 #[cfg(test)]
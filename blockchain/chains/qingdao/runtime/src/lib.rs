@@ -69,8 +69,8 @@ pub use frame_support::{
 	construct_runtime, log, parameter_types,
 	traits::{
 		Contains, ContainsLengthBound, Currency as PalletCurrency, EnsureOrigin, Everything, Get, Imbalance,
-		InstanceFilter, IsSubType, IsType, KeyOwnerProofSystem, LockIdentifier, Nothing, OnUnbalanced, Randomness,
-		SortedMembers, U128CurrencyToVote, WithdrawReasons,
+		InstanceFilter, IsSubType, IsType, KeyOwnerProofSystem, LockIdentifier, Nothing, OnUnbalanced, PalletInfoAccess,
+		Randomness, SortedMembers, U128CurrencyToVote, WithdrawReasons,
 	},
 	weights::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
@@ -84,11 +84,14 @@ use orml_traits::{
 	// MultiCurrency,
 };
 use module_evm::Runner;
-use module_evm::{CallInfo, CreateInfo};
+use module_evm::{CallInfo, CreateInfo, EvmTask};
 use module_evm_accounts::EvmAddressMapping;
 pub use module_evm_manager::EvmCurrencyIdMapping;
+pub use module_prices::PegDefinition;
 use module_currencies::BasicCurrencyAdapter;
+use module_support::DispatchableTask;
 use module_transaction_payment::{Multiplier, TargetedFeeAdjustment};
+use scale_info::TypeInfo;
 
 // re-exports
 
@@ -96,7 +99,11 @@ pub use pallet_staking::StakerStatus;
 
 pub use authority::AuthorityConfigImpl;
 pub use constants::{fee::*, time::*};
-use primitives::evm::EthereumTransactionMessage;
+use primitives::{
+	define_combined_task,
+	evm::{EthereumTransactionMessage, EvmAddress, TransactionAction},
+	task::TaskResult,
+};
 pub use primitives::{
 	evm::EstimateResourcesRequest, AccountId, AccountIndex, Amount, AuctionId, AuthoritysOriginId, Balance, BlockNumber, CurrencyId,
 	DataProviderId, EraIndex, Hash, Moment, Nonce, ReserveIdentifier, Share, Signature, TokenSymbol, TradingPair, SerpStableCurrencyId,
@@ -135,9 +142,13 @@ parameter_types! {
 	pub const AirdropPalletId: PalletId = PalletId(*b"set/drop");		// 5EYCAe5jKgkuY1B3CkWQF41wzN62tTt8ptfmao31qYvMiVRD
 	pub const CDPTreasuryPalletId: PalletId = PalletId(*b"set/cdpt");	// 5EYCAe5jKgkuXyJQ3G8CXrRfmmqqe54Tye5wJDqim8cvHQi7
 	pub const DEXPalletId: PalletId = PalletId(*b"set/sdex");			// 5EYCAe5jKgkuYTiXRpXnghiur9sW2zJCp91xQRKKzhwjS2DC
+	pub const FeeTreasuryPalletId: PalletId = PalletId(*b"set/fees");	// 5EYCAe5jKgkuY4dowvg7T6QtrHwbALDiwSieu9DXoq8ir8zg
 	pub const LoansPalletId: PalletId = PalletId(*b"set/loan");			// 5EYCAe5jKgkuYFMt7CDpD9JGyD8eLr9DKZZ9mBNibUbs5xXo
 	pub const NftPalletId: PalletId = PalletId(*b"set/sNFT");			// 5EYCAe5jKgkuYTZd9to8S5wCPjCUQnDg57tU9BDgakrywBM2
+	pub const NftMarketplacePalletId: PalletId = PalletId(*b"set/nftm");	// 5EYCAe5jKgkuYJrJr38bdGvosdVSVLnb3tKSNtR5WJnz4Y6y
+	pub const NftVaultPalletId: PalletId = PalletId(*b"set/nftv");		// 5EYCAe5jKgkuYJrJrDuvpdjsrkabJYA28jfKHGrD85x6nTsR
 	pub const SerpTreasuryPalletId: PalletId = PalletId(*b"set/serp");	// 5EYCAe5jKgkuYTiwwziYLaTt4ZTSEikGfWNVyZ1PUdkBg78Z
+	pub const SponsorshipPalletId: PalletId = PalletId(*b"set/spsr");	// 5EYCAe5jKgkuYToNkxctZhYAj2ztmHwcj4s3CJ22zfQRDaQm
 	pub const TreasuryPalletId: PalletId = PalletId(*b"set/trsy");		// 5EYCAe5jKgkuYVbBxj3Gqkgew54j9TmR4Q8QLuBWHCApVqWn
 }
 
@@ -146,7 +157,9 @@ pub fn get_all_module_accounts() -> Vec<AccountId> {
 		AirdropPalletId::get().into_account(),
 		CDPTreasuryPalletId::get().into_account(),
 		DEXPalletId::get().into_account(),
+		FeeTreasuryPalletId::get().into_account(),
 		LoansPalletId::get().into_account(),
+		NftMarketplacePalletId::get().into_account(),
 		SerpTreasuryPalletId::get().into_account(),
 		TreasuryPalletId::get().into_account(),
 		ZeroAccountId::get(),		 	// ACCOUNT 0
@@ -274,9 +287,16 @@ parameter_types! {
 	pub const SS58Prefix: u8 = 42;
 }
 
+pub struct BaseCallFilter;
+impl Contains<Call> for BaseCallFilter {
+	fn contains(call: &Call) -> bool {
+		!module_transaction_pause::PausedTransactionFilter::<Runtime>::contains(call)
+	}
+}
+
 impl frame_system::Config for Runtime {
 	/// The basic call filter to use in dispatchable.
-	type BaseCallFilter = frame_support::traits::Everything;
+	type BaseCallFilter = BaseCallFilter;
 	/// Block & extrinsics weights: base values and limits.
 	type BlockWeights = BlockWeights;
 	/// The maximum length of a block (in bytes).
@@ -531,6 +551,15 @@ parameter_types! {
 	];
 }
 
+parameter_types! {
+	pub const MaxTransferBatch: u32 = 100;
+	// the gas limit of the ERC20 transfer of the EVM bridge
+	pub Erc20TransferWeight: Weight = GasToWeight::convert(2_100_000);
+	pub const MaxMemoLength: u32 = 256;
+	pub CurrentEra: EraIndex = Staking::active_era().map(|era| era.index).unwrap_or_default();
+	pub const DustHistoryDepth: EraIndex = 84;
+}
+
 impl module_currencies::Config for Runtime {
 	type Event = Event;
 	type MultiCurrency = Tokens;
@@ -543,6 +572,12 @@ impl module_currencies::Config for Runtime {
 	type EVMBridge = EVMBridge;
 	type SweepOrigin = EnsureRootOrOneShuraCouncil;
 	type OnDust = module_currencies::TransferDust<Runtime, TreasuryAccount>;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type CurrentEra = CurrentEra;
+	type DustHistoryDepth = DustHistoryDepth;
+	type MaxTransferBatch = MaxTransferBatch;
+	type Erc20TransferWeight = Erc20TransferWeight;
+	type MaxMemoLength = MaxMemoLength;
 }
 
 parameter_types! {
@@ -555,7 +590,7 @@ parameter_types! {
 type SetheumDataProvider = orml_oracle::Instance1;
 impl orml_oracle::Config<SetheumDataProvider> for Runtime {
 	type Event = Event;
-	type OnNewData = ();
+	type OnNewData = OracleRewards;
 	type CombineData = orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, SetheumDataProvider>;
 	type Time = Timestamp;
 	type OracleKey = CurrencyId;
@@ -566,6 +601,55 @@ impl orml_oracle::Config<SetheumDataProvider> for Runtime {
 	type WeightInfo = weights::orml_oracle::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const MaxFeedCurrencies: u32 = 32;
+	pub const OracleFeedPeriod: BlockNumber = 5 * MINUTES;
+	pub const OracleHttpRequestTimeout: u64 = 2_000; // 2 secs
+}
+
+impl module_oracle_feeder::Config for Runtime {
+	type Event = Event;
+	type AuthorityId = module_oracle_feeder::crypto::OracleAuthId;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type MaxFeedCurrencies = MaxFeedCurrencies;
+	type FeedPeriod = OracleFeedPeriod;
+	type HttpRequestTimeout = OracleHttpRequestTimeout;
+	type WeightInfo = weights::module_oracle_feeder::WeightInfo<Runtime>;
+}
+
+pub struct RemoveSetheumOracleOperator;
+impl module_oracle_rewards::RemoveOperator<AccountId> for RemoveSetheumOracleOperator {
+	fn remove_operator(who: &AccountId) -> DispatchResult {
+		OperatorMembershipSetheum::remove_member(Origin::root(), who.clone())
+	}
+}
+
+parameter_types! {
+	pub const OracleRoundPeriod: BlockNumber = HOURS;
+	pub OracleRewardPerRound: Balance = dollar(SEE);
+	pub OracleMaxRewardDeviation: Ratio = Ratio::saturating_from_rational(1, 100); // 1%
+	pub const OracleDeviationScoreSmoothing: Permill = Permill::from_percent(10);
+	pub OracleEjectDeviationScore: Ratio = Ratio::saturating_from_rational(5, 100); // 5%
+	pub const OracleEjectMissedRounds: u32 = 24;
+}
+
+impl module_oracle_rewards::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type Source = SetheumOracle;
+	type Members = OperatorMembershipSetheum;
+	type RemoveOperator = RemoveSetheumOracleOperator;
+	type TreasuryAccount = TreasuryAccount;
+	type RoundPeriod = OracleRoundPeriod;
+	type RewardPerRound = OracleRewardPerRound;
+	type MaxRewardDeviation = OracleMaxRewardDeviation;
+	type DeviationScoreSmoothing = OracleDeviationScoreSmoothing;
+	type EjectDeviationScore = OracleEjectDeviationScore;
+	type EjectMissedRounds = OracleEjectMissedRounds;
+	type EjectOrigin = EnsureRootOrTwoThirdsFinancialCouncil;
+	type WeightInfo = weights::module_oracle_rewards::WeightInfo<Runtime>;
+}
+
 create_median_value_data_provider!(
 	AggregatedDataProvider,
 	CurrencyId,
@@ -623,6 +707,12 @@ parameter_type_with_key! {
 				}
 			},
 			CurrencyId::Erc20(_) => Balance::max_value(), // not handled by orml-tokens
+			CurrencyId::ForeignAsset(foreign_asset_id) => {
+				// unregistered foreign asset can not be held
+				AssetRegistry::asset_metadatas(foreign_asset_id)
+					.map_or(Balance::max_value(), |metadata| metadata.minimal_balance)
+			},
+			CurrencyId::NftShare(_) => 1,
 		}
 	};
 }
@@ -630,6 +720,7 @@ parameter_type_with_key! {
 parameter_types! {
 	pub TreasuryAccount: AccountId = TreasuryPalletId::get().into_account();
 	pub CDPTreasuryAccount: AccountId = CDPTreasuryPalletId::get().into_account();
+	pub FeeTreasuryAccount: AccountId = FeeTreasuryPalletId::get().into_account();
 	// pub SerpTreasuryAccount: AccountId = SerpTreasuryPalletId::get().into_account();
 }
 
@@ -639,25 +730,29 @@ impl orml_tokens::Config for Runtime {
 	type Amount = Amount;
 	type CurrencyId = CurrencyId;
 	type WeightInfo = weights::orml_tokens::WeightInfo<Runtime>;
-	type ExistentialDeposits = ExistentialDeposits;
-	type OnDust = orml_tokens::TransferDust<Runtime, TreasuryAccount>;
+	type ExistentialDeposits = module_currencies::ExistentialDepositsOf<Runtime, ExistentialDeposits>;
+	type OnDust = module_currencies::RecordDust<Runtime, orml_tokens::TransferDust<Runtime, TreasuryAccount>>;
 	type MaxLocks = MaxLocks;
 	type DustRemovalWhitelist = DustRemovalWhitelist;
 }
 
 parameter_types! {
-	pub SetUSDFixedPrice: Price = Price::saturating_from_rational(1, 1); // $1
-	pub SetterFixedPrice: Price = Price::saturating_from_rational(1, 4); // $0.25
+	pub const MaxBasketSize: u32 = 10;
+	pub const PriceHistoryLength: u32 = 100;
+	pub const MaxHistoryCurrencies: u32 = 10;
+	pub const MaxCircuitBreakers: u32 = 20;
 }
 
 impl module_prices::Config for Runtime {
 	type Event = Event;
 	type Source = AggregatedDataProvider;
-	type GetSetUSDId = GetSetUSDId;
-	type SetterCurrencyId = SetterCurrencyId;
-	type SetUSDFixedPrice = SetUSDFixedPrice;
-	type SetterFixedPrice = SetterFixedPrice;
+	type Time = Timestamp;
+	type MaxBasketSize = MaxBasketSize;
+	type PriceHistoryLength = PriceHistoryLength;
+	type MaxHistoryCurrencies = MaxHistoryCurrencies;
+	type MaxCircuitBreakers = MaxCircuitBreakers;
 	type LockOrigin = EnsureRootOrTwoThirdsFinancialCouncil;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
 	type DEX = Dex;
 	type Currency = Currencies;
 	type CurrencyIdMapping = EvmCurrencyIdMapping<Runtime>;
@@ -671,12 +766,62 @@ impl module_prices::Config for Runtime {
 // 	type WeightInfo = weights::dex_oracle::WeightInfo<Runtime>;
 // }
 
+pub struct EvmPausableCall;
+impl module_transaction_pause::GetEvmCall<Call> for EvmPausableCall {
+	fn evm_call(call: &Call) -> Option<(EvmAddress, &[u8])> {
+		match call {
+			Call::EVM(module_evm::Call::call(target, input, ..)) => Some((*target, &input[..])),
+			Call::EVM(module_evm::Call::scheduled_call(_, target, input, ..)) => Some((*target, &input[..])),
+			Call::EVM(module_evm::Call::eth_call(TransactionAction::Call(target), input, ..)) => {
+				Some((*target, &input[..]))
+			}
+			_ => None,
+		}
+	}
+}
+
+/// The pallets governance acts through, which can't be paused all at once.
+pub struct GovernancePallets;
+impl Contains<Vec<u8>> for GovernancePallets {
+	fn contains(pallet_name: &Vec<u8>) -> bool {
+		[
+			<System as PalletInfoAccess>::name(),
+			<Sudo as PalletInfoAccess>::name(),
+			<Scheduler as PalletInfoAccess>::name(),
+			<ShuraCouncil as PalletInfoAccess>::name(),
+			<ShuraCouncilMembership as PalletInfoAccess>::name(),
+			<FinancialCouncil as PalletInfoAccess>::name(),
+			<FinancialCouncilMembership as PalletInfoAccess>::name(),
+			<TechnicalCommittee as PalletInfoAccess>::name(),
+			<TechnicalCommitteeMembership as PalletInfoAccess>::name(),
+			<Authority as PalletInfoAccess>::name(),
+		]
+		.iter()
+		.any(|name| name.as_bytes() == &pallet_name[..])
+	}
+}
+
+parameter_types! {
+	pub const MaxPauseBatch: u32 = 50;
+	pub const MaxExpiringPauses: u32 = 100;
+}
+
 impl module_transaction_pause::Config for Runtime {
 	type Event = Event;
 	type UpdateOrigin = EnsureRootOrThreeFourthsShuraCouncil;
+	type GovernancePallets = GovernancePallets;
+	type EvmCall = EvmPausableCall;
+	type MaxPauseBatch = MaxPauseBatch;
+	type MaxExpiringPauses = MaxExpiringPauses;
 	type WeightInfo = weights::module_transaction_pause::WeightInfo<Runtime>;
 }
 
+impl module_asset_registry::Config for Runtime {
+	type Event = Event;
+	type RegisterOrigin = EnsureRootOrHalfFinancialCouncil;
+	type WeightInfo = weights::module_asset_registry::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub MinimumIncrementSize: Rate = Rate::saturating_from_rational(2, 100); // 2%
 	pub const AuctionTimeToClose: BlockNumber = 15 * MINUTES;
@@ -855,18 +1000,19 @@ parameter_types! {
 // 	type ListingOrigin = EnsureRootOrHalfFinancialCouncil;
 // }
 
-// parameter_types! {
-// 	pub const MaxAirdropListSize: usize = 250;
-// }
+parameter_types! {
+	pub const MaxAirdropListSize: usize = 250;
+}
 
-// impl module_airdrop::Config for Runtime {
-// 	type Event = Event;
-// 	type MultiCurrency = Currencies;
-// 	type MaxAirdropListSize = MaxAirdropListSize;
-// 	type FundingOrigin = TreasuryAccount;
-// 	type DropOrigin = EnsureRootOrTwoThirdsShuraCouncil;
-// 	type PalletId = AirdropPalletId;
-// }
+impl module_airdrop::Config for Runtime {
+	type Event = Event;
+	type MultiCurrency = Currencies;
+	type MaxAirdropListSize = MaxAirdropListSize;
+	type FundingOrigin = TreasuryAccount;
+	type DropOrigin = EnsureRootOrTwoThirdsShuraCouncil;
+	type VestedTransfer = Vesting;
+	type PalletId = AirdropPalletId;
+}
 
 parameter_types! {
     pub const StableCurrencyInflationPeriod: BlockNumber = MINUTES;
@@ -956,9 +1102,21 @@ parameter_types! {
 	pub const TargetBlockFullness: Perquintill = Perquintill::from_percent(25);
 	pub AdjustmentVariable: Multiplier = Multiplier::saturating_from_rational(1, 100_000);
 	pub MinimumMultiplier: Multiplier = Multiplier::saturating_from_rational(1, 1_000_000_000u128);
+	pub const MaxSponsorshipRemovals: u32 = 100;
+}
+
+pub struct EvmCallContract;
+impl module_transaction_payment::EvmCallContract<Call> for EvmCallContract {
+	fn contract(call: &Call) -> Option<EvmAddress> {
+		match call {
+			Call::EVM(module_evm::Call::call(target, ..)) => Some(*target),
+			_ => None,
+		}
+	}
 }
 
 impl module_transaction_payment::Config for Runtime {
+	type Event = Event;
 	type NativeCurrencyId = GetNativeCurrencyId;
 	type DefaultFeeSwapPathList = DefaultFeeSwapPathList;
 	type Currency = Balances;
@@ -971,6 +1129,11 @@ impl module_transaction_payment::Config for Runtime {
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
+	type FeeTreasuryAccount = FeeTreasuryAccount;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type SponsorshipPalletId = SponsorshipPalletId;
+	type MaxSponsorshipRemovals = MaxSponsorshipRemovals;
+	type EvmCallContract = EvmCallContract;
 	type WeightInfo = weights::module_transaction_payment::WeightInfo<Runtime>;
 }
 
@@ -985,6 +1148,7 @@ impl module_evm_accounts::Config for Runtime {
 impl module_evm_manager::Config for Runtime {
 	type Currency = Balances;
 	type EVMBridge = EVMBridge;
+	type ForeignAssetRegistry = AssetRegistry;
 }
 
 #[cfg(feature = "with-ethereum-compatibility")]
@@ -1018,6 +1182,9 @@ pub type OraclePrecompile = runtime_common::OraclePrecompile<
 	EvmAddressMapping<Runtime>,
 	EvmCurrencyIdMapping<Runtime>,
 	module_prices::RealTimePriceProvider<Runtime>,
+	module_prices::LockedPriceProvider<Runtime>,
+	AggregatedDataProvider,
+	Timestamp,
 >;
 pub type ScheduleCallPrecompile = runtime_common::ScheduleCallPrecompile<
 	AccountId,
@@ -1049,6 +1216,7 @@ impl module_evm::Config for Runtime {
 		ScheduleCallPrecompile,
 		// DexPrecompile,
 	>;
+	type PausedCalls = module_transaction_pause::PausedEvmCallFilter<Runtime>;
 	type ChainId = ChainId;
 	type GasToWeight = GasToWeight;
 	type ChargeTransactionPayment = module_transaction_payment::ChargeTransactionPayment<Runtime>;
@@ -1060,6 +1228,8 @@ impl module_evm::Config for Runtime {
 	type FreeDeploymentOrigin = EnsureRootOrHalfShuraCouncil;
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Babe>;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = weights::module_evm::WeightInfo<Runtime>;
 
 	#[cfg(feature = "with-ethereum-compatibility")]
@@ -1072,10 +1242,45 @@ impl module_evm_bridge::Config for Runtime {
 	type EVM = EVM;
 }
 
+define_combined_task! {
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+	pub enum ScheduledTasks {
+		EvmTask(EvmTask<Runtime>),
+	}
+}
+
+parameter_types! {
+	pub MinimumWeightRemainInBlock: Weight = Perbill::from_percent(1) * BlockWeights::get().max_block;
+	pub MaxTaskWeight: Weight = Perbill::from_percent(10) * BlockWeights::get().max_block;
+	pub const MaxQueuedTasks: u32 = 1_000;
+	pub const MaxRetries: u32 = 5;
+	pub const RetryDelay: BlockNumber = 10 * MINUTES;
+}
+
+impl module_idle_scheduler::Config for Runtime {
+	type Event = Event;
+	type WeightInfo = ();
+	type Task = ScheduledTasks;
+	type MinimumWeightRemainInBlock = MinimumWeightRemainInBlock;
+	type MaxTaskWeight = MaxTaskWeight;
+	type MaxQueuedTasks = MaxQueuedTasks;
+	type MaxRetries = MaxRetries;
+	type RetryDelay = RetryDelay;
+	type ScheduleOrigin = EnsureRootOrTwoThirdsTechnicalCommittee;
+}
+
 parameter_types! {
 	pub CreateClassDeposit: Balance = 11 * dollar(SEE);
 	pub CreateTokenDeposit: Balance = 7 * dollar(SEE);
 	pub MaxAttributesBytes: u32 = 2048;
+	pub MaxRoyaltyRate: Permill = Permill::from_percent(50);
+}
+
+pub struct NftCustodyAccounts;
+impl Contains<AccountId> for NftCustodyAccounts {
+	fn contains(a: &AccountId) -> bool {
+		NFTMarketplace::contains(a) || NFTVault::contains(a)
+	}
 }
 
 impl module_nft::Config for Runtime {
@@ -1086,9 +1291,35 @@ impl module_nft::Config for Runtime {
 	type DataDepositPerByte = DataDepositPerByte;
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = MaxAttributesBytes;
+	type MultiCurrency = Currencies;
+	type MaxRoyaltyRate = MaxRoyaltyRate;
+	type CustodyAccounts = NftCustodyAccounts;
 	type WeightInfo = weights::module_nft::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const MinimumAuctionDuration: BlockNumber = HOURS;
+	pub const MaximumAuctionDuration: BlockNumber = 30 * DAYS;
+	pub const AuctionExtendPeriod: BlockNumber = 10 * MINUTES;
+	pub NftMinimumIncrementSize: Permill = Permill::from_percent(5);
+}
+
+impl module_nft_marketplace::Config for Runtime {
+	type Event = Event;
+	type PalletId = NftMarketplacePalletId;
+	type MinimumAuctionDuration = MinimumAuctionDuration;
+	type MaximumAuctionDuration = MaximumAuctionDuration;
+	type AuctionExtendPeriod = AuctionExtendPeriod;
+	type MinimumIncrementSize = NftMinimumIncrementSize;
+	type WeightInfo = weights::module_nft_marketplace::WeightInfo<Runtime>;
+}
+
+impl module_nft_vault::Config for Runtime {
+	type Event = Event;
+	type PalletId = NftVaultPalletId;
+	type WeightInfo = weights::module_nft_vault::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub MaxClassMetadata: u32 = 1024;
 	pub MaxTokenMetadata: u32 = 1024;
@@ -1523,7 +1754,7 @@ construct_runtime!(
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent} = 2,
 		Sudo: pallet_sudo::{Pallet, Call, Config<T>, Storage, Event<T>} = 3,
 		Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>} = 4,
-		Prices: module_prices::{Pallet, Storage, Call, Event<T>} = 5,
+		Prices: module_prices::{Pallet, Storage, Call, Config, Event<T>} = 5,
 		// Dex: module_dex::{Pallet, Storage, Call, Event<T>, Config<T>} = 6,
 
 		Multisig: pallet_multisig::{Pallet, Call, Storage, Event<T>} = 7,
@@ -1552,6 +1783,8 @@ construct_runtime!(
 		// DexOracle: dex_oracle::{Pallet, Storage, Call}, = 20
 		SetheumOracle: orml_oracle::<Instance1>::{Pallet, Storage, Call, Event<T>} = 21,
 		OperatorMembershipSetheum: pallet_membership::<Instance4>::{Pallet, Call, Storage, Event<T>, Config<T>} = 22,
+		SetheumOracleFeeder: module_oracle_feeder::{Pallet, Call, Storage, Event<T>} = 59,
+		OracleRewards: module_oracle_rewards::{Pallet, Call, Storage, Event<T>} = 60,
 
 		// SERP
 		// AuctionManager: auction_manager::{Pallet, Storage, Call, Event<T>, ValidateUnsigned} = 23,
@@ -1570,8 +1803,9 @@ construct_runtime!(
 		Tips: pallet_tips::{Pallet, Call, Storage, Event<T>} = 32,
 
 		// Extras
-		NFT: module_nft::{Pallet, Call, Event<T>} = 33,
-		// AirDrop: module_airdrop::{Pallet, Call, Storage, Event<T>} = 34,
+		NFT: module_nft::{Pallet, Call, Storage, Event<T>} = 33,
+		NFTMarketplace: module_nft_marketplace::{Pallet, Call, Storage, Event<T>} = 57,
+		NFTVault: module_nft_vault::{Pallet, Call, Storage, Event<T>} = 58,
 
 		// Account lookup
 		Indices: pallet_indices::{Pallet, Call, Storage, Config<T>, Event<T>} = 35,
@@ -1580,9 +1814,12 @@ construct_runtime!(
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>} = 36,
 		Currencies: module_currencies::{Pallet, Call, Event<T>} = 37,
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>} = 38,
-		TransactionPayment: module_transaction_payment::{Pallet, Call, Storage} = 39,
+		TransactionPayment: module_transaction_payment::{Pallet, Call, Storage, Event<T>} = 39,
 		TransactionPause: module_transaction_pause::{Pallet, Call, Storage, Event<T>} = 40,
 		Vesting: module_vesting::{Pallet, Storage, Call, Event<T>, Config<T>} = 41,
+		AssetRegistry: module_asset_registry::{Pallet, Call, Storage, Event<T>} = 56,
+		// Declared after `Tokens` so the genesis airdrop is paid from an endowed treasury.
+		AirDrop: module_airdrop::{Pallet, Call, Storage, Config<T>, Event<T>} = 34,
 
 		// Identity
 		Identity: pallet_identity::{Pallet, Call, Storage, Event<T>} = 42,
@@ -1592,6 +1829,7 @@ construct_runtime!(
 		EvmAccounts: module_evm_accounts::{Pallet, Call, Storage, Event<T>} = 44,
 		EVMBridge: module_evm_bridge::{Pallet} = 45,
 		EvmManager: module_evm_manager::{Pallet, Storage} = 46,
+		IdleScheduler: module_idle_scheduler::{Pallet, Call, Storage, Event<T>} = 61,
 
 		// Consensus
 		Authorship: pallet_authorship::{Pallet, Call, Storage, Inherent} = 47,
@@ -1609,8 +1847,11 @@ construct_runtime!(
 pub struct OnRuntimeUpgrade;
 impl frame_support::traits::OnRuntimeUpgrade for OnRuntimeUpgrade {
 	fn on_runtime_upgrade() -> u64 {
-		// no migration
-		0
+		// the fixed prices replaced by the pegs of module_prices
+		Prices::migrate_fixed_prices_to_pegs(vec![
+			(SETUSD, Price::saturating_from_rational(1, 1)), // $1
+			(SETR, Price::saturating_from_rational(1, 4)),   // $0.25
+		])
 	}
 }
 
@@ -1897,6 +2138,29 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_transaction_payment_rpc_runtime_api::FeeQuoteApi<Block, AccountId, Balance> for Runtime {
+		fn query_fee_quote(
+			who: AccountId,
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+		) -> Result<
+			module_transaction_payment_rpc_runtime_api::FeeQuote<AccountId, Balance>,
+			module_transaction_payment_rpc_runtime_api::FeeQuoteError,
+		> {
+			TransactionPayment::quote_fee(&who, &uxt.function, len)
+		}
+	}
+
+	impl module_prices_rpc_runtime_api::PricesApi<Block, BlockNumber> for Runtime {
+		fn get_price(currency_id: CurrencyId) -> module_prices_rpc_runtime_api::PriceRecord<BlockNumber> {
+			Prices::price_record(currency_id)
+		}
+
+		fn get_price_history(currency_id: CurrencyId) -> Vec<module_prices_rpc_runtime_api::PriceRecord<BlockNumber>> {
+			Prices::price_history(currency_id)
+		}
+	}
+
 	impl module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance> for Runtime {
 		fn call(
 			from: H160,
@@ -22,6 +22,7 @@
 #![allow(clippy::unnecessary_cast)]
 
 // pub mod dex_oracle;
+pub mod module_asset_registry;
 pub mod module_auction_manager;
 pub mod module_cdp_engine;
 pub mod module_cdp_treasury;
@@ -33,6 +34,10 @@ pub mod module_evm_accounts;
 pub mod serp_setmint;
 pub mod serp_treasury;
 pub mod module_nft;
+pub mod module_nft_marketplace;
+pub mod module_nft_vault;
+pub mod module_oracle_feeder;
+pub mod module_oracle_rewards;
 pub mod module_prices;
 pub mod module_transaction_pause;
 pub mod module_transaction_payment;
@@ -83,4 +83,16 @@ impl<T: frame_system::Config> module_currencies::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
 	}
+	fn transfer_batch_base() -> Weight {
+		(5_870_000 as Weight)
+	}
+	fn approve() -> Weight {
+		(17_640_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_existential_deposit() -> Weight {
+		(11_820_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
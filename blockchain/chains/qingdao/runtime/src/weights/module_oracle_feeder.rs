@@ -0,0 +1,41 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Weights for module_oracle_feeder
+//!
+//! The weights are hand estimates, not measured: module_oracle_feeder has no
+//! benchmarks yet. Replace them with the benchmark CLI output once it has.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for module_oracle_feeder.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> module_oracle_feeder::WeightInfo for WeightInfo<T> {
+	fn set_feed_currencies(c: u32, ) -> Weight {
+		(6_000_000 as Weight)
+			.saturating_add((37_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
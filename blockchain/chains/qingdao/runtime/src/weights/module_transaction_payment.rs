@@ -53,6 +53,43 @@ impl<T: frame_system::Config> module_transaction_payment::WeightInfo for WeightI
 		(4_730_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_fee_currency() -> Weight {
+		(4_730_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_sponsorship() -> Weight {
+		(4_210_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn remove_sponsorship(c: u32, ) -> Weight {
+		(61_340_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_sponsored_calls(c: u32, ) -> Weight {
+		(5_120_000 as Weight)
+			.saturating_add((3_270_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_sponsored_users(c: u32, ) -> Weight {
+		(5_040_000 as Weight)
+			.saturating_add((1_180_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn fund_sponsorship_pool() -> Weight {
+		(56_870_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn withdraw_sponsorship_pool() -> Weight {
+		(55_920_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 	fn on_finalize() -> Weight {
 		(15_104_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
@@ -26,6 +26,14 @@
 //! acccounts in the airdrops from an update origin. 
 //! The module for distributing Setheum Airdrops,
 //! it will be used for the Setheum IAE (Initial Airdrop Event).
+//!
+//! Airdrops can either be liquid (`make_airdrop`) or vested
+//! (`make_vested_airdrop`), in which case every beneficiary receives a
+//! vesting schedule built from the same template.
+//!
+//! A genesis airdrop list can be provided through `GenesisConfig`, it is paid
+//! out of the airdrop treasury at block 0 after checking that the treasury
+//! holds enough of every airdropped currency.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -34,10 +42,15 @@ use frame_support::{pallet_prelude::*, transactional, PalletId, traits::Get};
 use frame_system::pallet_prelude::*;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use primitives::{Balance, CurrencyId};
-use sp_std::vec::Vec;
-use sp_runtime::traits::AccountIdConversion;
+use sp_std::{
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	vec::Vec,
+};
+use sp_runtime::traits::{AccountIdConversion, Zero};
+use support::VestedTransfer;
 
 mod mock;
+mod tests;
 
 pub use module::*;
 
@@ -63,6 +76,9 @@ pub mod module {
 
 		/// The origin which may update and fund the Airdrop Treasury.
 		type DropOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Vesting used to lock up vested airdrops.
+		type VestedTransfer: VestedTransfer<Self::AccountId, CurrencyId, Self::BlockNumber, Balance>;
 		
 		#[pallet::constant]
 		/// The Airdrop module pallet id, keeps airdrop funds.
@@ -76,17 +92,29 @@ pub mod module {
 		DuplicateAccounts,
 		// The airdrop list is over the max size limit `MaxAirdropListSize`
 		OverSizedAirdropList,
+		// The vesting period or period count of the schedule template is zero
+		InvalidVestingSchedule,
+		// The airdrop amount is zero or not divisible by the vesting `period_count`
+		InvalidVestingAmount,
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
-	#[pallet::metadata(T::AccountId = "AccountId", BalanceOf<T> = "Balance", CurrencyId = "CurrencyId")]
+	#[pallet::metadata(T::AccountId = "AccountId", BalanceOf<T> = "Balance", CurrencyId = "CurrencyId", T::BlockNumber = "BlockNumber")]
 	pub enum Event<T: Config> {
 		/// Drop Airdrop
 		Airdrop {
 			currency_id: CurrencyId,
 			airdrop_list: Vec<(T::AccountId, Balance)>
 		},
+		/// Drop Vested Airdrop, every beneficiary gets the same schedule template
+		VestedAirdrop {
+			currency_id: CurrencyId,
+			start: T::BlockNumber,
+			period: T::BlockNumber,
+			period_count: u32,
+			airdrop_list: Vec<(T::AccountId, Balance)>
+		},
 		/// Fund the Airdrop Treasury from `FundingOrigin` \[from, currency_id, amount\]
 		FundAirdropTreasury {
 			funder: T::AccountId,
@@ -95,6 +123,47 @@ pub mod module {
 		}
 	}
 
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		pub airdrop_list: Vec<(T::AccountId, CurrencyId, Balance)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			GenesisConfig { airdrop_list: vec![] }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			let treasury = Pallet::<T>::account_id();
+
+			let totals = self.airdrop_list.iter().fold(
+				BTreeMap::<CurrencyId, Balance>::new(),
+				|mut acc, (_, currency_id, amount)| {
+					let total = acc.entry(*currency_id).or_default();
+					*total = total
+						.checked_add(*amount)
+						.expect("airdrop total cannot overflow when building genesis");
+					acc
+				},
+			);
+			for (currency_id, total) in totals.iter() {
+				assert!(
+					T::MultiCurrency::free_balance(*currency_id, &treasury) >= *total,
+					"Airdrop treasury does not have enough balance for the genesis airdrop"
+				);
+			}
+
+			self.airdrop_list.iter().for_each(|(beneficiary, currency_id, amount)| {
+				T::MultiCurrency::transfer(*currency_id, &treasury, beneficiary, *amount)
+					.expect("genesis airdrop transfer cannot fail after the treasury balance check; qed");
+			});
+		}
+	}
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(PhantomData<T>);
 
@@ -151,6 +220,39 @@ pub mod module {
 			Self::do_make_airdrop(currency_id, airdrop_list)?;
 			Ok(())
 		}
+
+		/// Make Vested Airdrop to beneficiaries.
+		///
+		/// Each beneficiary's amount is locked under a vesting schedule that
+		/// starts at `start` and releases `amount / period_count` every `period` blocks.
+		///
+		/// The dispatch origin of this call must be `DropOrigin`.
+		///
+		/// - `currency_id`: `CurrencyId` airdrop currency type.
+		/// - `start`: vesting starting block.
+		/// - `period`: number of blocks between vests.
+		/// - `period_count`: number of vests.
+		/// - `airdrop_list`: airdrop accounts and respective total amounts.
+		#[pallet::weight((100_000_000 as Weight, DispatchClass::Operational))]
+		#[transactional]
+		pub fn make_vested_airdrop(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			start: T::BlockNumber,
+			period: T::BlockNumber,
+			period_count: u32,
+			airdrop_list: Vec<(T::AccountId, Balance)>,
+		) -> DispatchResult {
+			T::DropOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				airdrop_list.len() <= T::MaxAirdropListSize::get(),
+				Error::<T>::OverSizedAirdropList,
+			);
+
+			Self::do_make_vested_airdrop(currency_id, start, period, period_count, airdrop_list)?;
+			Ok(())
+		}
 	}
 }
 
@@ -160,17 +262,21 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_account()
 	}
 
-	fn do_make_airdrop(currency_id: CurrencyId, airdrop_list: Vec<(T::AccountId, Balance)>) -> DispatchResult {
+	/// Make sure only unique accounts receive Airdrop
+	fn ensure_unique_accounts(airdrop_list: &[(T::AccountId, Balance)]) -> DispatchResult {
+		let unique_accounts = airdrop_list
+			.iter()
+			.map(|(x, _)| x)
+			.collect::<BTreeSet<_>>();
+		ensure!(
+			unique_accounts.len() == airdrop_list.len(),
+			Error::<T>::DuplicateAccounts,
+		);
+		Ok(())
+	}
 
-		// Make sure only unique accounts receive Airdrop
-        let unique_accounts = airdrop_list
-		.iter()
-		.map(|(x,_)| x)
-		.cloned();
-        ensure!(
-            unique_accounts.len() == airdrop_list.len(),
-            Error::<T>::DuplicateAccounts,
-        );
+	fn do_make_airdrop(currency_id: CurrencyId, airdrop_list: Vec<(T::AccountId, Balance)>) -> DispatchResult {
+		Self::ensure_unique_accounts(&airdrop_list)?;
 
 		for (beneficiary, amount) in airdrop_list.iter() {
 			T::MultiCurrency::transfer(currency_id, &Self::account_id(), beneficiary, *amount)?;
@@ -179,4 +285,45 @@ impl<T: Config> Pallet<T> {
 		Self::deposit_event(Event::Airdrop { currency_id, airdrop_list });
 		Ok(())
 	}
+
+	fn do_make_vested_airdrop(
+		currency_id: CurrencyId,
+		start: T::BlockNumber,
+		period: T::BlockNumber,
+		period_count: u32,
+		airdrop_list: Vec<(T::AccountId, Balance)>,
+	) -> DispatchResult {
+		ensure!(
+			!period.is_zero() && !period_count.is_zero(),
+			Error::<T>::InvalidVestingSchedule,
+		);
+		Self::ensure_unique_accounts(&airdrop_list)?;
+
+		for (beneficiary, amount) in airdrop_list.iter() {
+			let per_period = amount / Balance::from(period_count);
+			ensure!(
+				!per_period.is_zero() && per_period * Balance::from(period_count) == *amount,
+				Error::<T>::InvalidVestingAmount,
+			);
+
+			T::VestedTransfer::vested_transfer(
+				currency_id,
+				&Self::account_id(),
+				beneficiary,
+				start,
+				period,
+				period_count,
+				per_period,
+			)?;
+		}
+
+		Self::deposit_event(Event::VestedAirdrop {
+			currency_id,
+			start,
+			period,
+			period_count,
+			airdrop_list,
+		});
+		Ok(())
+	}
 }
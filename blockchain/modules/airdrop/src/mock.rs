@@ -39,11 +39,15 @@ pub type BlockNumber = u64;
 pub const TREASURY: AccountId = AccountId32::new([0u8; 32]);
 pub const ALICE: AccountId = AccountId32::new([2u8; 32]);
 pub const BOB: AccountId = AccountId32::new([3u8; 32]);
+pub const CHARLIE: AccountId = AccountId32::new([4u8; 32]);
+pub const DAVE: AccountId = AccountId32::new([5u8; 32]);
+pub const EVE: AccountId = AccountId32::new([6u8; 32]);
 pub const SETR: CurrencyId = CurrencyId::Token(TokenSymbol::SETR);
 pub const SETUSD: CurrencyId = CurrencyId::Token(TokenSymbol::SETUSD);
 pub const SEE: CurrencyId = CurrencyId::Token(TokenSymbol::SEE);
 pub const DNAR: CurrencyId = CurrencyId::Token(TokenSymbol::DNAR);
 pub const HELP: CurrencyId = CurrencyId::Token(TokenSymbol::HELP);
+pub const SERP: CurrencyId = CurrencyId::Token(TokenSymbol::SERP);
 
 mod airdrop {
 	pub use super::super::*;
@@ -51,6 +55,7 @@ mod airdrop {
 
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
+	pub const MaxLocks: u32 = 100;
 }
 
 impl frame_system::Config for Runtime {
@@ -93,7 +98,7 @@ impl orml_tokens::Config for Runtime {
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
 	type OnDust = ();
-	type MaxLocks = ();
+	type MaxLocks = MaxLocks;
 	type DustRemovalWhitelist = ();
 }
 
@@ -107,20 +112,48 @@ parameter_types! {
 	pub const GetNativeCurrencyId: CurrencyId = SEE;  // Setheum native currency ticker is SEE/
 	pub const GetDinarCurrencyId: CurrencyId = DNAR;  // Setheum native currency ticker is SEE/
 	pub const GetHelpCurrencyId: CurrencyId = HELP;  // Setheum native currency ticker is SEE/
+	pub const GetSerpCurrencyId: CurrencyId = SERP;  // Serp currency ticker is SERP/
 	pub const AirdropPalletId: PalletId = PalletId(*b"set/drop");
 	pub const MaxAirdropListSize: usize = 4;
 }
 
 ord_parameter_types! {
 	pub const TreasuryAccount: AccountId = TREASURY;
-	pub const One: AccountId = AccountId32::new([1u8; 32]);
+	pub const One: AccountId = ALICE;
 }
+parameter_types! {
+	pub const MaxVestingSchedules: u32 = 2;
+	pub const MinVestedTransfer: Balance = 0;
+}
+
+impl module_vesting::Config for Runtime {
+	type Event = Event;
+	type MultiCurrency = Tokens;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type GetSerpCurrencyId = GetSerpCurrencyId;
+	type GetDinarCurrencyId = GetDinarCurrencyId;
+	type GetHelpCurrencyId = GetHelpCurrencyId;
+	type SetterCurrencyId = SetterCurrencyId;
+	type GetSetUSDId = GetSetUSDId;
+	type MinVestedTransfer = MinVestedTransfer;
+	type TreasuryAccount = TreasuryAccount;
+	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+	type WeightInfo = ();
+	type MaxNativeVestingSchedules = MaxVestingSchedules;
+	type MaxSerpVestingSchedules = MaxVestingSchedules;
+	type MaxDinarVestingSchedules = MaxVestingSchedules;
+	type MaxHelpVestingSchedules = MaxVestingSchedules;
+	type MaxSetterVestingSchedules = MaxVestingSchedules;
+	type MaxSetUSDVestingSchedules = MaxVestingSchedules;
+}
+
 impl Config for Runtime {
 	type Event = Event;
 	type MultiCurrency = Tokens;
 	type MaxAirdropListSize = MaxAirdropListSize;
 	type FundingOrigin = TreasuryAccount;
 	type DropOrigin = EnsureSignedBy<One, AccountId>;
+	type VestedTransfer = Vesting;
 	type PalletId = AirdropPalletId;
 }
 
@@ -134,13 +167,15 @@ construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic
 	{
 		System: frame_system::{Pallet, Storage, Call, Config, Event<T>},
-		AirDrop: airdrop::{Pallet, Storage, Call, Event<T>},
+		Airdrop: airdrop::{Pallet, Storage, Call, Config<T>, Event<T>},
 		Tokens: orml_tokens::{Pallet, Storage, Call, Event<T>},
+		Vesting: module_vesting::{Pallet, Storage, Call, Event<T>},
 	}
 );
 
 pub struct ExtBuilder {
 	_balances: Vec<(AccountId, CurrencyId, Balance)>,
+	airdrop_list: Vec<(AccountId, CurrencyId, Balance)>,
 }
 
 impl Default for ExtBuilder {
@@ -163,6 +198,41 @@ impl Default for ExtBuilder {
 				(BOB, HELP, 1000),
 				(TREASURY, HELP, 1000),
 			],
+			airdrop_list: vec![],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn balances(mut self, balances: Vec<(AccountId, CurrencyId, Balance)>) -> Self {
+		self._balances = balances;
+		self
+	}
+
+	pub fn airdrop_list(mut self, airdrop_list: Vec<(AccountId, CurrencyId, Balance)>) -> Self {
+		self.airdrop_list = airdrop_list;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		orml_tokens::GenesisConfig::<Runtime> {
+			balances: self._balances,
 		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		airdrop::GenesisConfig::<Runtime> {
+			airdrop_list: self.airdrop_list,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
 	}
 }
@@ -24,152 +24,202 @@
 
 use super::*;
 use frame_support::{assert_noop, assert_ok};
-use mock::{Airdrop, Event, ExtBuilder, Origin, System, SETR, ALICE, BOB, CHARLIE, DAVE, EVE, TREASURY, SETUSD};
+use mock::{
+	Airdrop, Event, ExtBuilder, Origin, Runtime, System, Tokens, Vesting, ALICE, BOB, CHARLIE, DAVE, EVE, SETR, SETUSD,
+	TREASURY,
+};
 use sp_runtime::traits::BadOrigin;
 
 #[test]
 fn fund_airdrop_treasury_works() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_noop!(Airdrop::fund_airdrop_treasury(
-            Origin::signed(BOB),
-            SETUSD, 10
-        ),
-        BadOrigin
-        );
-        
-        let airdrop_treasury = Airdrop::account_id();
-
-        assert_ok!(Airdrop::fund_airdrop_treasury(Origin::signed(ALICE), SETR, 258));
-        System::assert_last_event(Event::AirDrop(
-            crate::Event::FundAirdropTreasury {
-                funder: ALICE,
-                currency_id: SETR,
-                amount: 258
-            },
-        ));
-        assert_eq!(Tokens::free_balance(SETUSD, airdrop_treasury), 0);
-        assert_eq!(Tokens::free_balance(SETR, airdrop_treasury), 258);
-
-
-        assert_ok!(Airdrop::fund_airdrop_treasury(Origin::signed(ALICE), SETR, 10));
-        System::assert_last_event(Event::AirDrop(
-            crate::Event::FundAirdropTreasury {
-                funder: ALICE,
-                currency_id: SETR,
-                amount: 10
-            },
-        ));
-         assert_eq!(Tokens::free_balance(SETR, airdrop_treasury), 268);
-
-        assert_ok!(Airdrop::fund_airdrop_treasury(Origin::signed(ALICE), SETUSD, 258));
-        System::assert_last_event(Event::AirDrop(
-            crate::Event::FundAirdropTreasury {
-                funder: ALICE,
-                currency_id: SETUSD,
-                amount: 258
-            },
-        ));
-        assert_eq!(Tokens::free_balance(SETUSD, airdrop_treasury), 258);
+		assert_noop!(Airdrop::fund_airdrop_treasury(Origin::signed(BOB), SETUSD, 10), BadOrigin);
+
+		let airdrop_treasury = Airdrop::account_id();
+
+		assert_ok!(Airdrop::fund_airdrop_treasury(Origin::signed(ALICE), SETR, 258));
+		System::assert_last_event(Event::Airdrop(crate::Event::FundAirdropTreasury {
+			funder: TREASURY,
+			currency_id: SETR,
+			amount: 258,
+		}));
+		assert_eq!(Tokens::free_balance(SETUSD, &airdrop_treasury), 0);
+		assert_eq!(Tokens::free_balance(SETR, &airdrop_treasury), 258);
+
+		assert_ok!(Airdrop::fund_airdrop_treasury(Origin::signed(ALICE), SETR, 10));
+		System::assert_last_event(Event::Airdrop(crate::Event::FundAirdropTreasury {
+			funder: TREASURY,
+			currency_id: SETR,
+			amount: 10,
+		}));
+		assert_eq!(Tokens::free_balance(SETR, &airdrop_treasury), 268);
+
+		assert_ok!(Airdrop::fund_airdrop_treasury(Origin::signed(ALICE), SETUSD, 258));
+		System::assert_last_event(Event::Airdrop(crate::Event::FundAirdropTreasury {
+			funder: TREASURY,
+			currency_id: SETUSD,
+			amount: 258,
+		}));
+		assert_eq!(Tokens::free_balance(SETUSD, &airdrop_treasury), 258);
+		assert_eq!(Tokens::free_balance(SETUSD, &TREASURY), 1000 - 258);
 	});
 }
 
 #[test]
 fn make_airdrop_works() {
 	ExtBuilder::default().build().execute_with(|| {
-        let airdrop_list = vec![
-            (ALICE, 10),
-            (BOB, 5),
-            (CHARLIE, 20),
-        ];
-
-		assert_noop!(Airdrop::make_airdrop(
-            Origin::signed(BOB),
-            SETUSD,
-            airdrop_list
-        ),
-        BadOrigin
-        );
-
-        assert_ok!(Airdrop::fund_airdrop_treasury(Origin::signed(ALICE), SETR, 258));
-        System::assert_last_event(Event::AirDrop(
-            crate::Event::FundAirdropTreasury {
-                funder: ALICE,
-                currency_id: SETR,
-                amount: 258
-            },
-        ));
-        assert_eq!(Tokens::free_balance(SETR, airdrop_treasury), 258);
-
-        assert_ok!(Airdrop::fund_airdrop_treasury(Origin::signed(ALICE), SETUSD, 258));
-        System::assert_last_event(Event::AirDrop(
-            crate::Event::FundAirdropTreasury {
-                funder: ALICE,
-                currency_id: SETUSD,
-                amount: 258
-            },
-        ));
-        assert_eq!(Tokens::free_balance(SETUSD, airdrop_treasury), 258);
-
-        assert_ok!(Airdrop::make_airdrop(
-            Origin::signed(ALICE),
-            SETR,
-            airdrop_list
-        ));
-        System::assert_last_event(Event::AirDrop(
-            crate::Event::Airdrop {
-                currency_id: SETR,
-                airdrop_list
-            },
-        ));
-        assert_ok!(Airdrop::make_airdrop(
-            Origin::signed(ALICE),
-            SETUSD,
-            airdrop_list
-        ));
-        System::assert_last_event(Event::AirDrop(
-            crate::Event::Airdrop {
-                currency_id: SETUSD,
-                airdrop_list
-            },
-        ));
+		let airdrop_list = vec![(ALICE, 10), (BOB, 5), (CHARLIE, 20)];
+		let airdrop_treasury = Airdrop::account_id();
+
+		assert_noop!(
+			Airdrop::make_airdrop(Origin::signed(BOB), SETUSD, airdrop_list.clone()),
+			BadOrigin
+		);
+
+		assert_ok!(Airdrop::fund_airdrop_treasury(Origin::signed(ALICE), SETR, 258));
+		assert_ok!(Airdrop::fund_airdrop_treasury(Origin::signed(ALICE), SETUSD, 258));
+
+		assert_ok!(Airdrop::make_airdrop(Origin::signed(ALICE), SETR, airdrop_list.clone()));
+		System::assert_last_event(Event::Airdrop(crate::Event::Airdrop {
+			currency_id: SETR,
+			airdrop_list: airdrop_list.clone(),
+		}));
+		assert_eq!(Tokens::free_balance(SETR, &airdrop_treasury), 258 - 35);
+		assert_eq!(Tokens::free_balance(SETR, &ALICE), 1000 + 10);
+		assert_eq!(Tokens::free_balance(SETR, &BOB), 1000 + 5);
+		assert_eq!(Tokens::free_balance(SETR, &CHARLIE), 20);
+
+		assert_ok!(Airdrop::make_airdrop(Origin::signed(ALICE), SETUSD, airdrop_list.clone()));
+		System::assert_last_event(Event::Airdrop(crate::Event::Airdrop {
+			currency_id: SETUSD,
+			airdrop_list,
+		}));
+		assert_eq!(Tokens::free_balance(SETUSD, &airdrop_treasury), 258 - 35);
+		assert_eq!(Tokens::free_balance(SETUSD, &CHARLIE), 20);
 	});
 }
 
 #[test]
 fn make_airdrop_does_not_work() {
 	ExtBuilder::default().build().execute_with(|| {
-        let airdrop_list = vec![
-            (ALICE, 10),
-            (BOB, 5),
-            (CHARLIE, 20),
-            (DAVE, 20),
-            (EVE, 20),
-        ];
-
-		assert_noop!(Airdrop::make_airdrop(
-            Origin::signed(BOB),
-            SETUSD,
-            airdrop_list
-        ),
-        BadOrigin
-        );
-
-        assert_ok!(Airdrop::fund_airdrop_treasury(Origin::signed(ALICE), SETR, 258));
-        System::assert_last_event(Event::AirDrop(
-            crate::Event::FundAirdropTreasury {
-                funder: ALICE,
-                currency_id: SETR,
-                amount: 258
-            },
-        ));
-        assert_eq!(Tokens::free_balance(SETR, airdrop_treasury), 258);
-
-        assert_noop!(Airdrop::make_airdrop(
-            Origin::signed(ALICE),
-            SETR,
-            airdrop_list
-        )
-        Error::<Runtime>::OverSizedAirdropList,
-        );
+		let airdrop_list = vec![(ALICE, 10), (BOB, 5), (CHARLIE, 20), (DAVE, 20), (EVE, 20)];
+
+		assert_noop!(
+			Airdrop::make_airdrop(Origin::signed(BOB), SETUSD, airdrop_list.clone()),
+			BadOrigin
+		);
+
+		assert_ok!(Airdrop::fund_airdrop_treasury(Origin::signed(ALICE), SETR, 258));
+
+		assert_noop!(
+			Airdrop::make_airdrop(Origin::signed(ALICE), SETR, airdrop_list),
+			Error::<Runtime>::OverSizedAirdropList,
+		);
+		assert_noop!(
+			Airdrop::make_airdrop(Origin::signed(ALICE), SETR, vec![(BOB, 10), (BOB, 20)]),
+			Error::<Runtime>::DuplicateAccounts,
+		);
 	});
 }
+
+#[test]
+fn make_vested_airdrop_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let airdrop_list = vec![(BOB, 10), (CHARLIE, 20)];
+		let airdrop_treasury = Airdrop::account_id();
+
+		assert_noop!(
+			Airdrop::make_vested_airdrop(Origin::signed(BOB), SETR, 0, 10, 2, airdrop_list.clone()),
+			BadOrigin
+		);
+
+		assert_ok!(Airdrop::fund_airdrop_treasury(Origin::signed(ALICE), SETR, 258));
+		assert_eq!(Tokens::free_balance(SETR, &airdrop_treasury), 258);
+
+		assert_ok!(Airdrop::make_vested_airdrop(
+			Origin::signed(ALICE),
+			SETR,
+			0,
+			10,
+			2,
+			airdrop_list.clone()
+		));
+		System::assert_last_event(Event::Airdrop(crate::Event::VestedAirdrop {
+			currency_id: SETR,
+			start: 0,
+			period: 10,
+			period_count: 2,
+			airdrop_list,
+		}));
+		assert_eq!(Tokens::free_balance(SETR, &airdrop_treasury), 228);
+		assert_eq!(
+			Vesting::setter_vesting_schedules(&CHARLIE),
+			vec![module_vesting::VestingSchedule {
+				start: 0,
+				period: 10,
+				period_count: 2,
+				per_period: 10,
+			}]
+		);
+		// the whole airdrop is locked until the schedule vests
+		assert_eq!(Tokens::free_balance(SETR, &CHARLIE), 20);
+		assert!(Tokens::ensure_can_withdraw(SETR, &CHARLIE, 1).is_err());
+	});
+}
+
+#[test]
+fn make_vested_airdrop_does_not_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Airdrop::fund_airdrop_treasury(Origin::signed(ALICE), SETR, 258));
+
+		assert_noop!(
+			Airdrop::make_vested_airdrop(Origin::signed(ALICE), SETR, 0, 0, 2, vec![(BOB, 10)]),
+			Error::<Runtime>::InvalidVestingSchedule,
+		);
+
+		assert_noop!(
+			Airdrop::make_vested_airdrop(Origin::signed(ALICE), SETR, 0, 10, 3, vec![(BOB, 10)]),
+			Error::<Runtime>::InvalidVestingAmount,
+		);
+
+		assert_noop!(
+			Airdrop::make_vested_airdrop(Origin::signed(ALICE), SETR, 0, 10, 2, vec![(BOB, 10), (BOB, 20)]),
+			Error::<Runtime>::DuplicateAccounts,
+		);
+
+		// only the currencies supported by module_vesting can be vested
+		assert_noop!(
+			Airdrop::make_vested_airdrop(
+				Origin::signed(ALICE),
+				CurrencyId::ForeignAsset(0),
+				0,
+				10,
+				2,
+				vec![(BOB, 10)]
+			),
+			module_vesting::Error::<Runtime>::NotVestedCurrency,
+		);
+	});
+}
+
+#[test]
+fn genesis_airdrop_works() {
+	ExtBuilder::default()
+		.balances(vec![(Airdrop::account_id(), SETR, 100)])
+		.airdrop_list(vec![(ALICE, SETR, 10), (BOB, SETR, 20), (ALICE, SETR, 5)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(Tokens::free_balance(SETR, &Airdrop::account_id()), 65);
+			assert_eq!(Tokens::free_balance(SETR, &ALICE), 15);
+			assert_eq!(Tokens::free_balance(SETR, &BOB), 20);
+		});
+}
+
+#[test]
+#[should_panic(expected = "Airdrop treasury does not have enough balance for the genesis airdrop")]
+fn genesis_airdrop_over_treasury_balance_panics() {
+	ExtBuilder::default()
+		.balances(vec![(Airdrop::account_id(), SETR, 20)])
+		.airdrop_list(vec![(ALICE, SETR, 10), (BOB, SETR, 20)])
+		.build();
+}
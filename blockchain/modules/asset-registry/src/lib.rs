@@ -0,0 +1,155 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Asset Registry Module
+//!
+//! ## Overview
+//!
+//! The asset registry module keeps the metadata of foreign assets, so new
+//! tokens can be added as `CurrencyId::ForeignAsset` without a runtime upgrade.
+//! Registered assets are mirrored as ERC20 by `EvmCurrencyIdMapping`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::pallet_prelude::*;
+use primitives::{Balance, ForeignAssetId};
+use sp_runtime::ArithmeticError;
+use support::{AssetMetadata, ForeignAssetRegistry};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin which may register and update foreign assets.
+		type RegisterOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The foreign asset does not exist
+		AssetIdNotExists,
+		/// The name or symbol of the foreign asset is empty
+		InvalidMetadata,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A foreign asset is registered. \[foreign_asset_id, metadata\]
+		ForeignAssetRegistered(ForeignAssetId, AssetMetadata<Balance>),
+		/// The metadata of a foreign asset is updated. \[foreign_asset_id, metadata\]
+		ForeignAssetUpdated(ForeignAssetId, AssetMetadata<Balance>),
+	}
+
+	/// The id of the next registered foreign asset.
+	///
+	/// NextForeignAssetId: ForeignAssetId
+	#[pallet::storage]
+	#[pallet::getter(fn next_foreign_asset_id)]
+	pub type NextForeignAssetId<T: Config> = StorageValue<_, ForeignAssetId, ValueQuery>;
+
+	/// The metadata of the registered foreign assets.
+	///
+	/// AssetMetadatas: map ForeignAssetId => Option<AssetMetadata>
+	#[pallet::storage]
+	#[pallet::getter(fn asset_metadatas)]
+	pub type AssetMetadatas<T: Config> =
+		StorageMap<_, Twox64Concat, ForeignAssetId, AssetMetadata<Balance>, OptionQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register a new foreign asset under the next foreign asset id.
+		#[pallet::weight(T::WeightInfo::register_foreign_asset())]
+		#[transactional]
+		pub fn register_foreign_asset(origin: OriginFor<T>, metadata: AssetMetadata<Balance>) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+			Self::ensure_valid_metadata(&metadata)?;
+
+			let foreign_asset_id = NextForeignAssetId::<T>::try_mutate(|id| -> Result<ForeignAssetId, DispatchError> {
+				let current_id = *id;
+				*id = id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+				Ok(current_id)
+			})?;
+			AssetMetadatas::<T>::insert(foreign_asset_id, &metadata);
+
+			Self::deposit_event(Event::ForeignAssetRegistered(foreign_asset_id, metadata));
+			Ok(())
+		}
+
+		/// Update the metadata of a registered foreign asset.
+		#[pallet::weight(T::WeightInfo::update_foreign_asset())]
+		#[transactional]
+		pub fn update_foreign_asset(
+			origin: OriginFor<T>,
+			foreign_asset_id: ForeignAssetId,
+			metadata: AssetMetadata<Balance>,
+		) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+			Self::ensure_valid_metadata(&metadata)?;
+
+			AssetMetadatas::<T>::try_mutate(foreign_asset_id, |maybe_metadata| -> DispatchResult {
+				let old_metadata = maybe_metadata.as_mut().ok_or(Error::<T>::AssetIdNotExists)?;
+				*old_metadata = metadata.clone();
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ForeignAssetUpdated(foreign_asset_id, metadata));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn ensure_valid_metadata(metadata: &AssetMetadata<Balance>) -> DispatchResult {
+		ensure!(
+			!metadata.name.is_empty() && !metadata.symbol.is_empty(),
+			Error::<T>::InvalidMetadata
+		);
+		Ok(())
+	}
+}
+
+impl<T: Config> ForeignAssetRegistry<Balance> for Pallet<T> {
+	fn metadata(foreign_asset_id: ForeignAssetId) -> Option<AssetMetadata<Balance>> {
+		AssetMetadatas::<T>::get(foreign_asset_id)
+	}
+}
@@ -0,0 +1,108 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the asset registry module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn register_foreign_asset_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			AssetRegistry::register_foreign_asset(Origin::signed(5), dot_metadata()),
+			BadOrigin
+		);
+		assert_noop!(
+			AssetRegistry::register_foreign_asset(
+				Origin::signed(ALICE),
+				AssetMetadata {
+					symbol: Vec::new(),
+					..dot_metadata()
+				}
+			),
+			Error::<Runtime>::InvalidMetadata
+		);
+
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			Origin::signed(ALICE),
+			dot_metadata()
+		));
+		System::assert_last_event(Event::AssetRegistry(crate::Event::ForeignAssetRegistered(
+			0,
+			dot_metadata(),
+		)));
+		assert_eq!(AssetRegistry::asset_metadatas(0), Some(dot_metadata()));
+		assert_eq!(AssetRegistry::next_foreign_asset_id(), 1);
+		assert_eq!(
+			<AssetRegistry as ForeignAssetRegistry<Balance>>::metadata(0),
+			Some(dot_metadata())
+		);
+
+		NextForeignAssetId::<Runtime>::put(u32::max_value());
+		assert_noop!(
+			AssetRegistry::register_foreign_asset(Origin::signed(ALICE), dot_metadata()),
+			ArithmeticError::Overflow
+		);
+	});
+}
+
+#[test]
+fn update_foreign_asset_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		let new_metadata = AssetMetadata {
+			minimal_balance: 1_000_000_000,
+			..dot_metadata()
+		};
+		assert_noop!(
+			AssetRegistry::update_foreign_asset(Origin::signed(ALICE), 0, new_metadata.clone()),
+			Error::<Runtime>::AssetIdNotExists
+		);
+
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			Origin::signed(ALICE),
+			dot_metadata()
+		));
+		assert_noop!(
+			AssetRegistry::update_foreign_asset(Origin::signed(5), 0, new_metadata.clone()),
+			BadOrigin
+		);
+
+		assert_ok!(AssetRegistry::update_foreign_asset(
+			Origin::signed(ALICE),
+			0,
+			new_metadata.clone()
+		));
+		System::assert_last_event(Event::AssetRegistry(crate::Event::ForeignAssetUpdated(
+			0,
+			new_metadata.clone(),
+		)));
+		assert_eq!(AssetRegistry::asset_metadatas(0), Some(new_metadata));
+		assert_eq!(AssetRegistry::next_foreign_asset_id(), 1);
+	});
+}
@@ -31,19 +31,21 @@ use frame_support::{
 		Currency as PalletCurrency, ExistenceRequirement, Get, LockableCurrency as PalletLockableCurrency,
 		ReservableCurrency as PalletReservableCurrency, WithdrawReasons,
 	},
+	storage::{with_transaction, TransactionOutcome},
 	transactional,
+	weights::Weight,
 };
 use frame_system::pallet_prelude::*;
 use orml_traits::{
 	arithmetic::{Signed, SimpleArithmetic},
 	currency::TransferAll,
-	BalanceStatus, BasicCurrency, BasicCurrencyExtended, BasicLockableCurrency, BasicReservableCurrency,
+	BalanceStatus, BasicCurrency, BasicCurrencyExtended, BasicLockableCurrency, BasicReservableCurrency, GetByKey,
 	LockIdentifier, MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency, MultiReservableCurrency, OnDust,
 };
-use primitives::{evm::EvmAddress, CurrencyId};
+use primitives::{evm::EvmAddress, CurrencyId, EraIndex};
 use sp_io::hashing::blake2_256;
 use sp_runtime::{
-	traits::{CheckedSub, MaybeSerializeDeserialize, Saturating, StaticLookup, Zero},
+	traits::{Bounded, CheckedAdd, CheckedSub, MaybeSerializeDeserialize, Saturating, StaticLookup, Zero},
 	DispatchError, DispatchResult,
 };
 use sp_std::{
@@ -52,7 +54,7 @@ use sp_std::{
 	marker, result,
 	vec::Vec,
 };
-use support::{AddressMapping, EVMBridge, InvokeContext};
+use support::{AddressMapping, EVMBridge, InvokeContext, MultiCurrencyAllowance, MultiCurrencyMemo};
 
 mod mock;
 mod tests;
@@ -99,6 +101,30 @@ pub mod module {
 
 		/// Handler to burn or transfer account's dust
 		type OnDust: OnDust<Self::AccountId, CurrencyId, BalanceOf<Self>>;
+
+		/// The origin which may set the existential deposits.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The current era, the collected dust is reported per era.
+		type CurrentEra: Get<EraIndex>;
+
+		/// The number of eras the collected dust is kept for, the current era
+		/// included.
+		#[pallet::constant]
+		type DustHistoryDepth: Get<EraIndex>;
+
+		/// The maximum number of transfers in a `transfer_batch`.
+		#[pallet::constant]
+		type MaxTransferBatch: Get<u32>;
+
+		/// The weight of the EVM call of an ERC20 transfer, charged on top of
+		/// the transfer weight.
+		#[pallet::constant]
+		type Erc20TransferWeight: Get<Weight>;
+
+		/// The maximum length of the memo of a transfer.
+		#[pallet::constant]
+		type MaxMemoLength: Get<u32>;
 	}
 
 	#[pallet::error]
@@ -113,6 +139,19 @@ pub mod module {
 		EvmAccountNotFound,
 		/// Real origin not found
 		RealOriginNotFound,
+		/// The batch exceeds `MaxTransferBatch`
+		TooManyTransfers,
+		/// The allowance is not enough
+		AllowanceTooLow,
+		/// The allowance overflows
+		AllowanceOverflow,
+		/// The existential deposit of native currency and Erc20 can not be
+		/// set
+		CannotSetExistentialDeposit,
+		/// The memo is longer than `MaxMemoLength`
+		MemoTooLong,
+		/// The amount of a transfer with memo is zero
+		ZeroAmount,
 	}
 
 	#[pallet::event]
@@ -129,7 +168,57 @@ pub mod module {
 		Withdrawn(CurrencyIdOf<T>, T::AccountId, BalanceOf<T>),
 		/// Dust swept. \[currency_id, who, amount\]
 		DustSwept(CurrencyIdOf<T>, T::AccountId, BalanceOf<T>),
-	}
+		/// Batch transfer completed, with the indexes of the failed
+		/// transfers. \[from, transferred_count, failed_indexes\]
+		BatchTransferred(T::AccountId, u32, Vec<u32>),
+		/// The allowance is set. \[currency_id, owner, spender, amount\]
+		Approval(CurrencyIdOf<T>, T::AccountId, T::AccountId, BalanceOf<T>),
+		/// The existential deposit is set, `None` means the default is
+		/// restored. \[currency_id, existential_deposit\]
+		ExistentialDepositUpdated(CurrencyIdOf<T>, Option<BalanceOf<T>>),
+		/// Currency transfer with memo success. \[currency_id, from, to,
+		/// amount, memo\]
+		TransferredWithMemo(CurrencyIdOf<T>, T::AccountId, T::AccountId, BalanceOf<T>, Vec<u8>),
+	}
+
+	/// The amount of currency that the spender is allowed to transfer from
+	/// the owner.
+	///
+	/// Allowances: double_map owner, (spender, currency_id) => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn allowances)]
+	pub type Allowances<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		(T::AccountId, CurrencyIdOf<T>),
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// The existential deposits set by governance, which override the
+	/// default existential deposits.
+	///
+	/// ExistentialDeposits: map CurrencyId => Option<Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn existential_deposits)]
+	pub type ExistentialDeposits<T: Config> = StorageMap<_, Twox64Concat, CurrencyIdOf<T>, BalanceOf<T>, OptionQuery>;
+
+	/// The dust collected in each era.
+	///
+	/// DustCollected: double_map EraIndex, CurrencyId => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn dust_collected)]
+	pub type DustCollected<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, EraIndex, Twox64Concat, CurrencyIdOf<T>, BalanceOf<T>, ValueQuery>;
+
+	/// The eras in `DustCollected`, oldest first.
+	///
+	/// DustEras: Vec<EraIndex>
+	#[pallet::storage]
+	#[pallet::getter(fn dust_eras)]
+	pub type DustEras<T: Config> = StorageValue<_, Vec<EraIndex>, ValueQuery>;
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
@@ -192,6 +281,147 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Transfer some balance of native or non-native currency to another
+		/// account with a memo, e.g. the payment reference of a deposit.
+		///
+		/// The dispatch origin for this call must be `Signed` by the
+		/// transactor.
+		///
+		/// - `amount`: must be non-zero, so a memo always records a payment.
+		/// - `memo`: at most `MaxMemoLength` bytes, deposited in the
+		///   `TransferredWithMemo` event.
+		#[pallet::weight(Pallet::<T>::transfer_weight(*currency_id))]
+		pub fn transfer_with_memo(
+			origin: OriginFor<T>,
+			dest: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyIdOf<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+			memo: Vec<u8>,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(dest)?;
+			<Self as MultiCurrencyMemo<_, _, _>>::transfer_with_memo(currency_id, &from, &to, amount, memo)
+		}
+
+		/// Transfer some balances to other accounts under multiple currencies,
+		/// deposits a single `BatchTransferred` event.
+		///
+		/// The dispatch origin for this call must be `Signed` by the
+		/// transactor.
+		///
+		/// - `transfers`: the (dest, currency_id, amount) of the transfers, at
+		///   most `MaxTransferBatch`.
+		/// - `atomic`: if true, fails and reverts all the transfers when any
+		///   of them fails, otherwise skips the failed transfers.
+		#[pallet::weight(Pallet::<T>::transfer_batch_weight(transfers))]
+		#[transactional]
+		pub fn transfer_batch(
+			origin: OriginFor<T>,
+			transfers: Vec<(<T::Lookup as StaticLookup>::Source, CurrencyIdOf<T>, BalanceOf<T>)>,
+			atomic: bool,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			ensure!(
+				transfers.len() <= T::MaxTransferBatch::get() as usize,
+				Error::<T>::TooManyTransfers
+			);
+
+			let mut transferred_count: u32 = 0;
+			let mut failed_indexes: Vec<u32> = Vec::new();
+			for (index, (dest, currency_id, amount)) in transfers.into_iter().enumerate() {
+				let result = with_transaction(|| {
+					let result = T::Lookup::lookup(dest)
+						.map_err(DispatchError::from)
+						.and_then(|to| Self::do_transfer(currency_id, &from, &to, amount));
+					match result {
+						Ok(()) => TransactionOutcome::Commit(Ok(())),
+						Err(e) => TransactionOutcome::Rollback(Err(e)),
+					}
+				});
+
+				match result {
+					Ok(()) => transferred_count += 1,
+					Err(e) if atomic => return Err(e),
+					Err(_) => failed_indexes.push(index as u32),
+				}
+			}
+
+			Self::deposit_event(Event::BatchTransferred(from, transferred_count, failed_indexes));
+			Ok(())
+		}
+
+		/// Set the allowance of `spender` over the `currency_id` of the
+		/// caller.
+		///
+		/// The dispatch origin for this call must be `Signed` by the owner.
+		#[pallet::weight(T::WeightInfo::approve())]
+		pub fn approve(
+			origin: OriginFor<T>,
+			spender: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyIdOf<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let spender = T::Lookup::lookup(spender)?;
+			<Self as MultiCurrencyAllowance<_, _, _>>::approve(currency_id, &owner, &spender, amount)
+		}
+
+		/// Increase the allowance of `spender` over the `currency_id` of the
+		/// caller by `amount`.
+		///
+		/// The dispatch origin for this call must be `Signed` by the owner.
+		#[pallet::weight(T::WeightInfo::approve())]
+		pub fn increase_allowance(
+			origin: OriginFor<T>,
+			spender: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyIdOf<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let spender = T::Lookup::lookup(spender)?;
+			let allowance = Self::allowance(currency_id, &owner, &spender)
+				.checked_add(&amount)
+				.ok_or(Error::<T>::AllowanceOverflow)?;
+			<Self as MultiCurrencyAllowance<_, _, _>>::approve(currency_id, &owner, &spender, allowance)
+		}
+
+		/// Decrease the allowance of `spender` over the `currency_id` of the
+		/// caller by `amount`.
+		///
+		/// The dispatch origin for this call must be `Signed` by the owner.
+		#[pallet::weight(T::WeightInfo::approve())]
+		pub fn decrease_allowance(
+			origin: OriginFor<T>,
+			spender: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyIdOf<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let spender = T::Lookup::lookup(spender)?;
+			let allowance = Self::allowance(currency_id, &owner, &spender)
+				.checked_sub(&amount)
+				.ok_or(Error::<T>::AllowanceTooLow)?;
+			<Self as MultiCurrencyAllowance<_, _, _>>::approve(currency_id, &owner, &spender, allowance)
+		}
+
+		/// Transfer some balance of `from` to another account under
+		/// `currency_id`, deducted from the allowance of the caller.
+		///
+		/// The dispatch origin for this call must be `Signed` by the spender.
+		#[pallet::weight(Pallet::<T>::transfer_weight(*currency_id).saturating_add(T::WeightInfo::approve()))]
+		pub fn transfer_from(
+			origin: OriginFor<T>,
+			from: <T::Lookup as StaticLookup>::Source,
+			dest: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyIdOf<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let spender = ensure_signed(origin)?;
+			let from = T::Lookup::lookup(from)?;
+			let to = T::Lookup::lookup(dest)?;
+			<Self as MultiCurrencyAllowance<_, _, _>>::transfer_from(currency_id, &spender, &from, &to, amount)
+		}
+
 		#[pallet::weight(T::WeightInfo::sweep_dust(accounts.len() as u32))]
 		pub fn sweep_dust(
 			origin: OriginFor<T>,
@@ -213,11 +443,115 @@ pub mod module {
 				}
 				if free_balance < Self::minimum_balance(currency_id) {
 					T::OnDust::on_dust(&account, currency_id, free_balance);
+					Self::record_dust(currency_id, free_balance);
 					Self::deposit_event(Event::DustSwept(currency_id, account, free_balance));
 				}
 			}
 			Ok(())
 		}
+
+		/// Set the existential deposit of `currency_id`, `None` restores the
+		/// default. Accounts below the new existential deposit are reaped on
+		/// their next balance change, or by `sweep_dust`.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		#[pallet::weight(T::WeightInfo::set_existential_deposit())]
+		pub fn set_existential_deposit(
+			origin: OriginFor<T>,
+			currency_id: CurrencyIdOf<T>,
+			existential_deposit: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				currency_id != T::GetNativeCurrencyId::get() && !currency_id.is_erc20_currency_id(),
+				Error::<T>::CannotSetExistentialDeposit
+			);
+
+			ExistentialDeposits::<T>::set(currency_id, existential_deposit);
+			Self::deposit_event(Event::ExistentialDepositUpdated(currency_id, existential_deposit));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Add `amount` to the dust of `currency_id` collected in the current era.
+	/// The first dust of an era prunes the eras older than `DustHistoryDepth`.
+	fn record_dust(currency_id: CurrencyIdOf<T>, amount: BalanceOf<T>) {
+		let era = T::CurrentEra::get();
+		DustEras::<T>::mutate(|eras| {
+			if eras.last() == Some(&era) {
+				return;
+			}
+			eras.push(era);
+			let depth = T::DustHistoryDepth::get();
+			eras.retain(|&old_era| {
+				let expired = old_era.saturating_add(depth) <= era;
+				if expired {
+					DustCollected::<T>::remove_prefix(old_era, None);
+				}
+				!expired
+			});
+		});
+
+		DustCollected::<T>::mutate(era, currency_id, |collected| {
+			*collected = collected.saturating_add(amount)
+		});
+	}
+
+	/// The weight of `transfer_batch`, the weight of each transfer depends
+	/// on its currency.
+	fn transfer_batch_weight(
+		transfers: &[(<T::Lookup as StaticLookup>::Source, CurrencyIdOf<T>, BalanceOf<T>)],
+	) -> Weight {
+		transfers
+			.iter()
+			.fold(T::WeightInfo::transfer_batch_base(), |weight, (_, currency_id, _)| {
+				weight.saturating_add(Self::transfer_weight(*currency_id))
+			})
+	}
+
+	/// The weight of a transfer of `currency_id`, including the EVM call of
+	/// ERC20.
+	fn transfer_weight(currency_id: CurrencyIdOf<T>) -> Weight {
+		match currency_id {
+			CurrencyId::Erc20(_) => {
+				T::WeightInfo::transfer_non_native_currency().saturating_add(T::Erc20TransferWeight::get())
+			}
+			id if id == T::GetNativeCurrencyId::get() => T::WeightInfo::transfer_native_currency(),
+			_ => T::WeightInfo::transfer_non_native_currency(),
+		}
+	}
+
+	fn do_transfer(
+		currency_id: CurrencyIdOf<T>,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if amount.is_zero() || from == to {
+			return Ok(());
+		}
+
+		match currency_id {
+			CurrencyId::Erc20(contract) => {
+				let sender = T::AddressMapping::get_evm_address(from).ok_or(Error::<T>::EvmAccountNotFound)?;
+				let origin = T::EVMBridge::get_origin().ok_or(Error::<T>::RealOriginNotFound)?;
+				let origin_address = T::AddressMapping::get_or_create_evm_address(&origin);
+				let address = T::AddressMapping::get_or_create_evm_address(to);
+				T::EVMBridge::transfer(
+					InvokeContext {
+						contract,
+						sender,
+						origin: origin_address,
+					},
+					address,
+					amount,
+				)
+			}
+			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::transfer(from, to, amount),
+			_ => T::MultiCurrency::transfer(currency_id, from, to, amount),
+		}
 	}
 }
 
@@ -313,25 +647,7 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 			return Ok(());
 		}
 
-		match currency_id {
-			CurrencyId::Erc20(contract) => {
-				let sender = T::AddressMapping::get_evm_address(from).ok_or(Error::<T>::EvmAccountNotFound)?;
-				let origin = T::EVMBridge::get_origin().ok_or(Error::<T>::RealOriginNotFound)?;
-				let origin_address = T::AddressMapping::get_or_create_evm_address(&origin);
-				let address = T::AddressMapping::get_or_create_evm_address(to);
-				T::EVMBridge::transfer(
-					InvokeContext {
-						contract,
-						sender,
-						origin: origin_address,
-					},
-					address,
-					amount,
-				)?;
-			}
-			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::transfer(from, to, amount)?,
-			_ => T::MultiCurrency::transfer(currency_id, from, to, amount)?,
-		}
+		Self::do_transfer(currency_id, from, to, amount)?;
 
 		Self::deposit_event(Event::Transferred(currency_id, from.clone(), to.clone(), amount));
 		Ok(())
@@ -380,6 +696,77 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 	}
 }
 
+impl<T: Config> MultiCurrencyMemo<T::AccountId, CurrencyIdOf<T>, BalanceOf<T>> for Pallet<T> {
+	fn transfer_with_memo(
+		currency_id: CurrencyIdOf<T>,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: BalanceOf<T>,
+		memo: Vec<u8>,
+	) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+		ensure!(
+			memo.len() <= T::MaxMemoLength::get() as usize,
+			Error::<T>::MemoTooLong
+		);
+		Self::do_transfer(currency_id, from, to, amount)?;
+
+		Self::deposit_event(Event::TransferredWithMemo(
+			currency_id,
+			from.clone(),
+			to.clone(),
+			amount,
+			memo,
+		));
+		Ok(())
+	}
+}
+
+impl<T: Config> MultiCurrencyAllowance<T::AccountId, CurrencyIdOf<T>, BalanceOf<T>> for Pallet<T> {
+	fn allowance(currency_id: CurrencyIdOf<T>, owner: &T::AccountId, spender: &T::AccountId) -> BalanceOf<T> {
+		Allowances::<T>::get(owner, (spender, currency_id))
+	}
+
+	fn approve(
+		currency_id: CurrencyIdOf<T>,
+		owner: &T::AccountId,
+		spender: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			Allowances::<T>::remove(owner, (spender, currency_id));
+		} else {
+			Allowances::<T>::insert(owner, (spender, currency_id), amount);
+		}
+		Self::deposit_event(Event::Approval(currency_id, owner.clone(), spender.clone(), amount));
+		Ok(())
+	}
+
+	#[transactional]
+	fn transfer_from(
+		currency_id: CurrencyIdOf<T>,
+		spender: &T::AccountId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if spender != from {
+			let allowance = Self::allowance(currency_id, from, spender);
+			// the max allowance is never deducted
+			if allowance != BalanceOf::<T>::max_value() {
+				let remaining = allowance.checked_sub(&amount).ok_or(Error::<T>::AllowanceTooLow)?;
+				if remaining.is_zero() {
+					Allowances::<T>::remove(from, (spender, currency_id));
+				} else {
+					Allowances::<T>::insert(from, (spender, currency_id), remaining);
+				}
+			}
+		}
+
+		<Self as MultiCurrency<T::AccountId>>::transfer(currency_id, from, to, amount)
+	}
+}
+
 impl<T: Config> MultiCurrencyExtended<T::AccountId> for Pallet<T> {
 	type Amount = AmountOf<T>;
 
@@ -908,3 +1295,33 @@ where
 		};
 	}
 }
+
+/// The existential deposits of the tokens, the ones set by governance
+/// override `DefaultExistentialDeposits`.
+pub struct ExistentialDepositsOf<T, DefaultExistentialDeposits>(
+	marker::PhantomData<(T, DefaultExistentialDeposits)>,
+);
+impl<T, DefaultExistentialDeposits> GetByKey<CurrencyIdOf<T>, BalanceOf<T>>
+	for ExistentialDepositsOf<T, DefaultExistentialDeposits>
+where
+	T: Config,
+	DefaultExistentialDeposits: GetByKey<CurrencyIdOf<T>, BalanceOf<T>>,
+{
+	fn get(currency_id: &CurrencyIdOf<T>) -> BalanceOf<T> {
+		ExistentialDeposits::<T>::get(currency_id).unwrap_or_else(|| DefaultExistentialDeposits::get(currency_id))
+	}
+}
+
+/// Records the dust collected by the tokens in the report of the current
+/// era, then handles it by `Handler`.
+pub struct RecordDust<T, Handler>(marker::PhantomData<(T, Handler)>);
+impl<T, Handler> OnDust<T::AccountId, CurrencyIdOf<T>, BalanceOf<T>> for RecordDust<T, Handler>
+where
+	T: Config,
+	Handler: OnDust<T::AccountId, CurrencyIdOf<T>, BalanceOf<T>>,
+{
+	fn on_dust(who: &T::AccountId, currency_id: CurrencyIdOf<T>, amount: BalanceOf<T>) {
+		Pallet::<T>::record_dust(currency_id, amount);
+		Handler::on_dust(who, currency_id, amount);
+	}
+}
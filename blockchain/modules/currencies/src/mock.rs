@@ -24,7 +24,7 @@
 
 use frame_support::{assert_ok, ord_parameter_types, parameter_types, traits::GenesisBuild, PalletId};
 use orml_traits::parameter_type_with_key;
-use primitives::{CurrencyId, ReserveIdentifier, TokenSymbol};
+use primitives::{CurrencyId, EraIndex, ReserveIdentifier, TokenSymbol};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
@@ -110,8 +110,8 @@ impl tokens::Config for Runtime {
 	type Balance = Balance;
 	type Amount = i64;
 	type CurrencyId = CurrencyId;
-	type ExistentialDeposits = ExistentialDeposits;
-	type OnDust = tokens::TransferDust<Runtime, DustAccount>;
+	type ExistentialDeposits = crate::ExistentialDepositsOf<Runtime, ExistentialDeposits>;
+	type OnDust = crate::RecordDust<Runtime, tokens::TransferDust<Runtime, DustAccount>>;
 	type WeightInfo = ();
 	type MaxLocks = MaxLocks;
 	type DustRemovalWhitelist = ();
@@ -172,6 +172,7 @@ impl module_evm::Config for Runtime {
 	type StorageDepositPerByte = StorageDepositPerByte;
 	type Event = Event;
 	type Precompiles = ();
+	type PausedCalls = ();
 	type ChainId = ();
 	type GasToWeight = ();
 	type ChargeTransactionPayment = ();
@@ -185,6 +186,8 @@ impl module_evm::Config for Runtime {
 
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = ();
+	type Task = module_evm::EvmTask<Runtime>;
+	type IdleScheduler = ();
 	type WeightInfo = ();
 }
 
@@ -288,6 +291,22 @@ impl PriceProvider<CurrencyId> for MockPriceSource {
 
 }
 
+thread_local! {
+	static CURRENT_ERA: RefCell<EraIndex> = RefCell::new(0);
+}
+
+pub struct MockCurrentEra;
+impl MockCurrentEra {
+	pub fn set(era: EraIndex) {
+		CURRENT_ERA.with(|v| *v.borrow_mut() = era);
+	}
+}
+impl Get<EraIndex> for MockCurrentEra {
+	fn get() -> EraIndex {
+		CURRENT_ERA.with(|v| *v.borrow())
+	}
+}
+
 parameter_type_with_key! {
 	pub GetStableCurrencyMinimumSupply: |currency_id: CurrencyId| -> Balance {
 		match currency_id {
@@ -319,6 +338,13 @@ ord_parameter_types! {
 	pub const Root: AccountId = alice();
 }
 
+parameter_types! {
+	pub const MaxTransferBatch: u32 = 10;
+	pub const DustHistoryDepth: EraIndex = 2;
+	pub const Erc20TransferWeight: u64 = 1_000_000;
+	pub const MaxMemoLength: u32 = 32;
+}
+
 impl Config for Runtime {
 	type Event = Event;
 	type MultiCurrency = Tokens;
@@ -329,6 +355,12 @@ impl Config for Runtime {
 	type EVMBridge = EVMBridge;
 	type SweepOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
 	type OnDust = crate::TransferDust<Runtime, DustAccount>;
+	type UpdateOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
+	type CurrentEra = MockCurrentEra;
+	type DustHistoryDepth = DustHistoryDepth;
+	type MaxTransferBatch = MaxTransferBatch;
+	type Erc20TransferWeight = Erc20TransferWeight;
+	type MaxMemoLength = MaxMemoLength;
 }
 
 pub type NativeCurrency = Currency<Runtime, GetNativeCurrencyId>;
@@ -26,8 +26,8 @@ use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
 	alice, bob, deploy_contracts, erc20_address, eva, AccountId, AdaptedBasicCurrency, CouncilAccount, Currencies,
-	DustAccount, Event, ExtBuilder, NativeCurrency, Origin, PalletBalances, Runtime, System, Tokens, DNAR, EVM, ID_1,
-	NATIVE_CURRENCY_ID, X_TOKEN_ID,
+	DustAccount, Event, ExtBuilder, MockCurrentEra, NativeCurrency, Origin, PalletBalances, Runtime, System, Tokens,
+	DNAR, EVM, ID_1, NATIVE_CURRENCY_ID, X_TOKEN_ID,
 };
 use sp_core::H160;
 use sp_runtime::traits::BadOrigin;
@@ -315,6 +315,153 @@ fn call_event_should_work() {
 		});
 }
 
+#[test]
+fn transfer_batch_should_work() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_ok!(Currencies::transfer_batch(
+				Origin::signed(alice()),
+				vec![
+					(bob(), X_TOKEN_ID, 10),
+					(bob(), NATIVE_CURRENCY_ID, 20),
+					(eva(), X_TOKEN_ID, 30),
+				],
+				true
+			));
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &alice()), 60);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &bob()), 110);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &eva()), 30);
+			assert_eq!(NativeCurrency::free_balance(&alice()), 80);
+			assert_eq!(NativeCurrency::free_balance(&bob()), 120);
+			System::assert_last_event(Event::Currencies(crate::Event::BatchTransferred(alice(), 3, vec![])));
+
+			// best effort, skips the failed transfers
+			assert_ok!(Currencies::transfer_batch(
+				Origin::signed(alice()),
+				vec![(bob(), X_TOKEN_ID, 1000), (bob(), X_TOKEN_ID, 10)],
+				false
+			));
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &alice()), 50);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &bob()), 120);
+			System::assert_last_event(Event::Currencies(crate::Event::BatchTransferred(alice(), 1, vec![0])));
+
+			// atomic, reverts all the transfers
+			assert_noop!(
+				Currencies::transfer_batch(
+					Origin::signed(alice()),
+					vec![(bob(), X_TOKEN_ID, 10), (bob(), X_TOKEN_ID, 1000)],
+					true
+				),
+				tokens::Error::<Runtime>::BalanceTooLow
+			);
+		});
+}
+
+#[test]
+fn transfer_batch_should_fail_if_too_many_transfers() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Currencies::transfer_batch(Origin::signed(alice()), vec![(bob(), X_TOKEN_ID, 1); 11], false),
+				Error::<Runtime>::TooManyTransfers
+			);
+		});
+}
+
+#[test]
+fn transfer_batch_weight_should_include_erc20_transfer() {
+	let native_transfer = <() as WeightInfo>::transfer_native_currency();
+	let token_transfer = <() as WeightInfo>::transfer_non_native_currency();
+	assert_eq!(
+		Currencies::transfer_batch_weight(&[
+			(bob(), NATIVE_CURRENCY_ID, 1),
+			(bob(), X_TOKEN_ID, 1),
+			(bob(), CurrencyId::Erc20(erc20_address()), 1),
+		]),
+		<() as WeightInfo>::transfer_batch_base() + native_transfer + token_transfer * 2 + 1_000_000
+	);
+}
+
+#[test]
+fn allowance_should_work() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_ok!(Currencies::approve(Origin::signed(alice()), bob(), X_TOKEN_ID, 50));
+			assert_eq!(Currencies::allowances(alice(), (bob(), X_TOKEN_ID)), 50);
+			System::assert_last_event(Event::Currencies(crate::Event::Approval(X_TOKEN_ID, alice(), bob(), 50)));
+
+			assert_ok!(Currencies::increase_allowance(Origin::signed(alice()), bob(), X_TOKEN_ID, 20));
+			assert_eq!(Currencies::allowances(alice(), (bob(), X_TOKEN_ID)), 70);
+			assert_ok!(Currencies::decrease_allowance(Origin::signed(alice()), bob(), X_TOKEN_ID, 30));
+			assert_eq!(Currencies::allowances(alice(), (bob(), X_TOKEN_ID)), 40);
+			assert_noop!(
+				Currencies::decrease_allowance(Origin::signed(alice()), bob(), X_TOKEN_ID, 41),
+				Error::<Runtime>::AllowanceTooLow
+			);
+			assert_noop!(
+				Currencies::increase_allowance(Origin::signed(alice()), bob(), X_TOKEN_ID, u128::max_value()),
+				Error::<Runtime>::AllowanceOverflow
+			);
+
+			// the allowances are per currency
+			assert_eq!(Currencies::allowances(alice(), (bob(), NATIVE_CURRENCY_ID)), 0);
+		});
+}
+
+#[test]
+fn transfer_from_should_work() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Currencies::transfer_from(Origin::signed(bob()), alice(), eva(), X_TOKEN_ID, 10),
+				Error::<Runtime>::AllowanceTooLow
+			);
+
+			assert_ok!(Currencies::approve(Origin::signed(alice()), bob(), X_TOKEN_ID, 50));
+			assert_ok!(Currencies::transfer_from(Origin::signed(bob()), alice(), eva(), X_TOKEN_ID, 30));
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &alice()), 70);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &eva()), 30);
+			assert_eq!(Currencies::allowances(alice(), (bob(), X_TOKEN_ID)), 20);
+
+			assert_noop!(
+				Currencies::transfer_from(Origin::signed(bob()), alice(), eva(), X_TOKEN_ID, 21),
+				Error::<Runtime>::AllowanceTooLow
+			);
+			assert_ok!(Currencies::transfer_from(Origin::signed(bob()), alice(), bob(), X_TOKEN_ID, 20));
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &bob()), 120);
+			assert!(!Allowances::<Runtime>::contains_key(alice(), (bob(), X_TOKEN_ID)));
+
+			// the max allowance is never deducted
+			assert_ok!(Currencies::approve(
+				Origin::signed(alice()),
+				bob(),
+				NATIVE_CURRENCY_ID,
+				u128::max_value()
+			));
+			assert_ok!(Currencies::transfer_from(
+				Origin::signed(bob()),
+				alice(),
+				eva(),
+				NATIVE_CURRENCY_ID,
+				40
+			));
+			assert_eq!(NativeCurrency::free_balance(&alice()), 60);
+			assert_eq!(NativeCurrency::free_balance(&eva()), 40);
+			assert_eq!(
+				Currencies::allowances(alice(), (bob(), NATIVE_CURRENCY_ID)),
+				u128::max_value()
+			);
+		});
+}
+
 #[test]
 fn erc20_total_issuance_should_work() {
 	ExtBuilder::default()
@@ -970,3 +1117,150 @@ fn sweep_dust_erc20_not_allowed() {
 		);
 	});
 }
+
+#[test]
+fn set_existential_deposit_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			Currencies::set_existential_deposit(Origin::signed(bob()), X_TOKEN_ID, Some(10)),
+			BadOrigin
+		);
+		assert_noop!(
+			Currencies::set_existential_deposit(Origin::signed(CouncilAccount::get()), NATIVE_CURRENCY_ID, Some(10)),
+			Error::<Runtime>::CannotSetExistentialDeposit
+		);
+		assert_noop!(
+			Currencies::set_existential_deposit(
+				Origin::signed(CouncilAccount::get()),
+				CurrencyId::Erc20(erc20_address()),
+				Some(10)
+			),
+			Error::<Runtime>::CannotSetExistentialDeposit
+		);
+
+		assert_eq!(Currencies::minimum_balance(X_TOKEN_ID), 0);
+		assert_ok!(Currencies::set_existential_deposit(
+			Origin::signed(CouncilAccount::get()),
+			X_TOKEN_ID,
+			Some(10)
+		));
+		System::assert_last_event(Event::Currencies(crate::Event::ExistentialDepositUpdated(
+			X_TOKEN_ID,
+			Some(10),
+		)));
+		assert_eq!(Currencies::existential_deposits(X_TOKEN_ID), Some(10));
+		assert_eq!(Currencies::minimum_balance(X_TOKEN_ID), 10);
+
+		assert_ok!(Currencies::set_existential_deposit(
+			Origin::signed(CouncilAccount::get()),
+			X_TOKEN_ID,
+			None
+		));
+		System::assert_last_event(Event::Currencies(crate::Event::ExistentialDepositUpdated(X_TOKEN_ID, None)));
+		assert_eq!(Currencies::existential_deposits(X_TOKEN_ID), None);
+		assert_eq!(Currencies::minimum_balance(X_TOKEN_ID), 0);
+	});
+}
+
+#[test]
+fn dust_below_existential_deposit_is_collected_and_reported_per_era() {
+	ExtBuilder::default()
+		.balances(vec![
+			(alice(), X_TOKEN_ID, 100),
+			(bob(), X_TOKEN_ID, 100),
+			(eva(), X_TOKEN_ID, 5),
+			(DustAccount::get(), X_TOKEN_ID, 100),
+		])
+		.build()
+		.execute_with(|| {
+			MockCurrentEra::set(1);
+			assert_ok!(Currencies::set_existential_deposit(
+				Origin::signed(CouncilAccount::get()),
+				X_TOKEN_ID,
+				Some(10)
+			));
+
+			// alice falls below the existential deposit, the dust is collected automatically
+			assert_ok!(Currencies::transfer(Origin::signed(alice()), bob(), X_TOKEN_ID, 95));
+			assert_eq!(tokens::Accounts::<Runtime>::contains_key(alice(), X_TOKEN_ID), false);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &bob()), 195);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &DustAccount::get()), 105);
+			assert_eq!(Currencies::dust_collected(1, X_TOKEN_ID), 5);
+
+			// eva was below the new existential deposit before it was set
+			MockCurrentEra::set(2);
+			assert_ok!(Currencies::sweep_dust(
+				Origin::signed(CouncilAccount::get()),
+				X_TOKEN_ID,
+				vec![eva()]
+			));
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &eva()), 0);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &DustAccount::get()), 110);
+			assert_eq!(Currencies::dust_collected(1, X_TOKEN_ID), 5);
+			assert_eq!(Currencies::dust_collected(2, X_TOKEN_ID), 5);
+			assert_eq!(Currencies::dust_eras(), vec![1, 2]);
+
+			// the eras older than DustHistoryDepth are pruned
+			MockCurrentEra::set(3);
+			assert_ok!(Currencies::transfer(Origin::signed(bob()), alice(), X_TOKEN_ID, 190));
+			assert_eq!(Currencies::dust_collected(1, X_TOKEN_ID), 0);
+			assert_eq!(Currencies::dust_collected(2, X_TOKEN_ID), 5);
+			assert_eq!(Currencies::dust_collected(3, X_TOKEN_ID), 5);
+			assert_eq!(Currencies::dust_eras(), vec![2, 3]);
+		});
+}
+
+#[test]
+fn transfer_with_memo_works() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_noop!(
+				Currencies::transfer_with_memo(Origin::signed(alice()), bob(), X_TOKEN_ID, 50, vec![1u8; 33]),
+				Error::<Runtime>::MemoTooLong
+			);
+			assert_noop!(
+				Currencies::transfer_with_memo(Origin::signed(alice()), bob(), X_TOKEN_ID, 0, b"deposit:42".to_vec()),
+				Error::<Runtime>::ZeroAmount
+			);
+
+			assert_ok!(Currencies::transfer_with_memo(
+				Origin::signed(alice()),
+				bob(),
+				X_TOKEN_ID,
+				50,
+				b"deposit:42".to_vec()
+			));
+			System::assert_last_event(Event::Currencies(crate::Event::TransferredWithMemo(
+				X_TOKEN_ID,
+				alice(),
+				bob(),
+				50,
+				b"deposit:42".to_vec(),
+			)));
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &alice()), 50);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &bob()), 150);
+
+			assert_ok!(Currencies::transfer_with_memo(
+				Origin::signed(alice()),
+				bob(),
+				NATIVE_CURRENCY_ID,
+				50,
+				vec![1u8; 32]
+			));
+			System::assert_last_event(Event::Currencies(crate::Event::TransferredWithMemo(
+				NATIVE_CURRENCY_ID,
+				alice(),
+				bob(),
+				50,
+				vec![1u8; 32],
+			)));
+			assert_eq!(NativeCurrency::free_balance(&alice()), 50);
+			assert_eq!(NativeCurrency::free_balance(&bob()), 150);
+		});
+}
@@ -55,6 +55,9 @@ pub trait WeightInfo {
 	fn update_balance_native_currency_creating() -> Weight;
 	fn update_balance_native_currency_killing() -> Weight;
 	fn sweep_dust(c: u32, ) -> Weight;
+	fn transfer_batch_base() -> Weight;
+	fn approve() -> Weight;
+	fn set_existential_deposit() -> Weight;
 }
 
 /// Weights for module_currencies using the Setheum node and recommended hardware.
@@ -94,6 +97,18 @@ impl<T: frame_system::Config> WeightInfo for SetheumWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
 	}
+	fn transfer_batch_base() -> Weight {
+		(6_000_000 as Weight)
+	}
+	fn approve() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_existential_deposit() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -132,4 +147,16 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
 	}
+	fn transfer_batch_base() -> Weight {
+		(6_000_000 as Weight)
+	}
+	fn approve() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_existential_deposit() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 }
@@ -119,6 +119,7 @@ impl module_evm::Config for Runtime {
 	type StorageDepositPerByte = StorageDepositPerByte;
 	type Event = Event;
 	type Precompiles = ();
+	type PausedCalls = ();
 	type ChainId = ();
 	type GasToWeight = ();
 	type ChargeTransactionPayment = ();
@@ -132,6 +133,8 @@ impl module_evm::Config for Runtime {
 
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = ();
+	type Task = module_evm::EvmTask<Runtime>;
+	type IdleScheduler = ();
 	type WeightInfo = ();
 }
 
@@ -24,12 +24,13 @@
 //!
 //! Evm manager module provides common support features for Evm, including:
 //! - A two way mapping between `u32` and `Erc20 address` so user can use Erc20 address as LP token.
+//! - ERC20 mirror addresses of the foreign assets registered in `ForeignAssetRegistry`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
 use frame_support::{ensure, pallet_prelude::*, require_transactional, traits::Currency};
-use module_support::{CurrencyIdMapping, EVMBridge, InvokeContext};
+use module_support::{CurrencyIdMapping, EVMBridge, ForeignAssetRegistry, InvokeContext};
 use primitives::{
 	currency::TokenInfo,
 	evm::{Erc20Info, EvmAddress},
@@ -55,6 +56,9 @@ pub mod module {
 	pub trait Config: frame_system::Config {
 		type Currency: Currency<Self::AccountId>;
 		type EVMBridge: EVMBridge<Self::AccountId, BalanceOf<Self>>;
+
+		/// The registry of foreign assets.
+		type ForeignAssetRegistry: ForeignAssetRegistry<Balance>;
 	}
 
 	/// Error for evm accounts module.
@@ -153,6 +157,10 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 			CurrencyId::Erc20(address) => CurrencyIdMap::<T>::get(Into::<u32>::into(DexShare::Erc20(address)))
 				.filter(|v| v.address == address)
 				.map(|v| v.name),
+			CurrencyId::ForeignAsset(foreign_asset_id) => {
+				T::ForeignAssetRegistry::metadata(foreign_asset_id).map(|v| v.name)
+			}
+			CurrencyId::NftShare(_) => None,
 		}?;
 
 		// More than 32 bytes will be truncated.
@@ -193,6 +201,10 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 			CurrencyId::Erc20(address) => CurrencyIdMap::<T>::get(Into::<u32>::into(DexShare::Erc20(address)))
 				.filter(|v| v.address == address)
 				.map(|v| v.symbol),
+			CurrencyId::ForeignAsset(foreign_asset_id) => {
+				T::ForeignAssetRegistry::metadata(foreign_asset_id).map(|v| v.symbol)
+			}
+			CurrencyId::NftShare(_) => None,
 		}?;
 
 		// More than 32 bytes will be truncated.
@@ -222,6 +234,10 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 			CurrencyId::Erc20(address) => CurrencyIdMap::<T>::get(Into::<u32>::into(DexShare::Erc20(address)))
 				.filter(|v| v.address == address)
 				.map(|v| v.decimals),
+			CurrencyId::ForeignAsset(foreign_asset_id) => {
+				T::ForeignAssetRegistry::metadata(foreign_asset_id).map(|v| v.decimals)
+			}
+			CurrencyId::NftShare(_) => None,
 		}
 	}
 
@@ -251,6 +267,10 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 				Some(prefix | EvmAddress::from_low_u64_be(u64::from(symbol_0) << 32 | u64::from(symbol_1)))
 			}
 
+			// Only the registered foreign assets have the mirror address.
+			CurrencyId::ForeignAsset(foreign_asset_id) => T::ForeignAssetRegistry::metadata(foreign_asset_id)
+				.and_then(|_| EvmAddress::try_from(v).ok()),
+
 			// Token or Erc20
 			_ => EvmAddress::try_from(v).ok(),
 		}
@@ -267,6 +287,13 @@ impl<T: Config> CurrencyIdMapping for EvmCurrencyIdMapping<T> {
 			return address[H160_POSITION_TOKEN].try_into().map(CurrencyId::Token).ok();
 		}
 
+		// ForeignAsset
+		if address.starts_with(&H160_PREFIX_FOREIGN_ASSET) {
+			let foreign_asset_id = u32::from_be_bytes(address[H160_POSITION_FOREIGN_ASSET].try_into().ok()?);
+			return T::ForeignAssetRegistry::metadata(foreign_asset_id)
+				.map(|_| CurrencyId::ForeignAsset(foreign_asset_id));
+		}
+
 		// DexShare
 		if address.starts_with(&H160_PREFIX_DEXSHARE) {
 			let left = {
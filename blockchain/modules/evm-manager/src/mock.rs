@@ -25,7 +25,7 @@
 use super::*;
 use frame_support::{assert_ok, construct_runtime, ord_parameter_types, parameter_types};
 use frame_system::EnsureSignedBy;
-use module_support::{mocks::MockAddressMapping, AddressMapping};
+use module_support::{mocks::MockAddressMapping, AddressMapping, AssetMetadata, ForeignAssetRegistry};
 use orml_traits::parameter_type_with_key;
 use primitives::{Amount, Balance, CurrencyId, ReserveIdentifier, TokenSymbol};
 use sp_core::{bytes::from_hex, crypto::AccountId32, H160, H256};
@@ -148,6 +148,7 @@ impl module_evm::Config for Runtime {
 	type StorageDepositPerByte = StorageDepositPerByte;
 	type Event = Event;
 	type Precompiles = ();
+	type PausedCalls = ();
 	type ChainId = ();
 	type GasToWeight = ();
 	type ChargeTransactionPayment = ();
@@ -161,6 +162,8 @@ impl module_evm::Config for Runtime {
 
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = ();
+	type Task = module_evm::EvmTask<Runtime>;
+	type IdleScheduler = ();
 	type WeightInfo = ();
 }
 
@@ -168,9 +171,26 @@ impl module_evm_bridge::Config for Runtime {
 	type EVM = EVM;
 }
 
+pub struct MockForeignAssetRegistry;
+impl ForeignAssetRegistry<Balance> for MockForeignAssetRegistry {
+	fn metadata(foreign_asset_id: ForeignAssetId) -> Option<AssetMetadata<Balance>> {
+		if foreign_asset_id == 0 {
+			Some(AssetMetadata {
+				name: b"Polkadot".to_vec(),
+				symbol: b"DOT".to_vec(),
+				decimals: 10,
+				minimal_balance: 100_000_000,
+			})
+		} else {
+			None
+		}
+	}
+}
+
 impl Config for Runtime {
 	type Currency = Balances;
 	type EVMBridge = EVMBridge;
+	type ForeignAssetRegistry = MockForeignAssetRegistry;
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -458,3 +458,44 @@ fn decode_evm_address_works() {
 			);
 		});
 }
+
+#[test]
+fn foreign_asset_mapping_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			EvmCurrencyIdMapping::<Runtime>::name(CurrencyId::ForeignAsset(0)),
+			Some(b"Polkadot".to_vec())
+		);
+		assert_eq!(
+			EvmCurrencyIdMapping::<Runtime>::symbol(CurrencyId::ForeignAsset(0)),
+			Some(b"DOT".to_vec())
+		);
+		assert_eq!(
+			EvmCurrencyIdMapping::<Runtime>::decimals(CurrencyId::ForeignAsset(0)),
+			Some(10)
+		);
+		assert_eq!(EvmCurrencyIdMapping::<Runtime>::name(CurrencyId::ForeignAsset(1)), None);
+
+		let address = H160::from_str("0x0000000000000000000000000000000100000000").unwrap();
+		assert_eq!(
+			EvmCurrencyIdMapping::<Runtime>::encode_evm_address(CurrencyId::ForeignAsset(0)),
+			Some(address)
+		);
+		assert_eq!(
+			EvmCurrencyIdMapping::<Runtime>::decode_evm_address(address),
+			Some(CurrencyId::ForeignAsset(0))
+		);
+
+		// unregistered foreign asset has no mirror address
+		assert_eq!(
+			EvmCurrencyIdMapping::<Runtime>::encode_evm_address(CurrencyId::ForeignAsset(1)),
+			None
+		);
+		assert_eq!(
+			EvmCurrencyIdMapping::<Runtime>::decode_evm_address(
+				H160::from_str("0x0000000000000000000000000000000100000001").unwrap()
+			),
+			None
+		);
+	});
+}
@@ -53,15 +53,18 @@ use frame_support::{
 use frame_system::{ensure_root, ensure_signed, pallet_prelude::*, EnsureOneOf, EnsureRoot, EnsureSigned};
 use hex_literal::hex;
 pub use module_support::{
-	AddressMapping, EVMStateRentTrait, ExecutionMode, InvokeContext, TransactionPayment, EVM as EVMTrait,
+	AddressMapping, DispatchableTask, EVMStateRentTrait, EvmCallPaused, ExecutionMode, IdleScheduler, InvokeContext,
+	TransactionPayment, EVM as EVMTrait,
 };
 pub use orml_traits::currency::TransferAll;
 use primitive_types::{H160, H256, U256};
 pub use primitives::{
 	evm::{Account, CallInfo, CreateInfo, EvmAddress, ExecutionInfo, Log, TransactionAction, Vicinity},
+	task::TaskResult,
 	ReserveIdentifier, H160_PREFIX_DEXSHARE, H160_PREFIX_TOKEN, MIRRORED_NFT_ADDRESS_START, PRECOMPILE_ADDRESS_START,
 	SYSTEM_CONTRACT_ADDRESS_PREFIX,
 };
+use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sha3::{Digest, Keccak256};
@@ -72,7 +75,8 @@ use sp_runtime::{
 	transaction_validity::TransactionValidityError,
 	Either, TransactionOutcome,
 };
-use sp_std::{collections::btree_map::BTreeMap, convert::TryInto, fmt::Write, marker::PhantomData, prelude::*};
+use sp_io::KillStorageResult::{AllRemoved, SomeRemaining};
+use sp_std::{cmp, collections::btree_map::BTreeMap, convert::TryInto, fmt::Write, marker::PhantomData, prelude::*};
 
 pub mod precompiles;
 pub mod runner;
@@ -87,6 +91,9 @@ pub use weights::WeightInfo;
 /// Storage key size and storage value size.
 pub const STORAGE_SIZE: u32 = 64;
 
+/// The max number of storage items removed by one dispatch of `EvmTask::Remove`.
+pub const REMOVE_STORAGE_LIMIT: u32 = 100;
+
 /// Type alias for currency balance.
 pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 pub type NegativeImbalanceOf<T> =
@@ -170,6 +177,10 @@ pub mod module {
 		/// Precompiles associated with this EVM engine.
 		type Precompiles: PrecompileSet;
 
+		/// The paused calls, checked for every call to a contract or
+		/// precompile, including the internal calls.
+		type PausedCalls: EvmCallPaused;
+
 		/// Chain ID of EVM.
 		#[pallet::constant]
 		type ChainId: Get<u64>;
@@ -211,6 +222,12 @@ pub mod module {
 		/// Find author for the current block.
 		type FindAuthor: FindAuthor<Self::AccountId>;
 
+		/// Dispatchable tasks, used to remove the storage of removed contracts.
+		type Task: From<EvmTask<Self>>;
+
+		/// Scheduler of the tasks, dispatched when the chain is idle.
+		type IdleScheduler: IdleScheduler<Self::Task>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -307,6 +324,22 @@ pub mod module {
 	#[pallet::getter(fn extrinsic_origin)]
 	pub type ExtrinsicOrigin<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
+	/// The removed contracts whose storage removal couldn't be scheduled, as
+	/// the idle queue was full. Their storage is removed in `on_idle`
+	/// instead, and the deposit refunded to the account if any.
+	///
+	/// PendingRemovals: map EvmAddress => Option<Option<AccountId>>
+	#[pallet::storage]
+	pub type PendingRemovals<T: Config> = StorageMap<_, Twox64Concat, EvmAddress, Option<T::AccountId>, OptionQuery>;
+
+	/// The removed contracts whose storage is not all removed yet, by the
+	/// idle task or from `PendingRemovals`. No contract can be created at
+	/// these addresses meanwhile.
+	///
+	/// RemovingContracts: map EvmAddress => Option<()>
+	#[pallet::storage]
+	pub type RemovingContracts<T: Config> = StorageMap<_, Twox64Concat, EvmAddress, (), OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub accounts: BTreeMap<EvmAddress, GenesisAccount<BalanceOf<T>, T::Index>>,
@@ -452,7 +485,41 @@ pub mod module {
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Remove the storage of the pending removals with the remaining
+		/// weight.
+		fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let mut used_weight: Weight = 0;
+			loop {
+				used_weight = used_weight.saturating_add(T::DbWeight::get().reads(1));
+				let (contract, refund_to) = match PendingRemovals::<T>::iter().next() {
+					Some(removal) => removal,
+					None => break,
+				};
+
+				let TaskResult {
+					result,
+					used_weight: task_weight,
+					finished,
+				} = EvmTask::<T>::Remove {
+					contract,
+					refund_to,
+				}
+				.dispatch(remaining_weight.saturating_sub(used_weight));
+				used_weight = used_weight.saturating_add(task_weight);
+				if !finished {
+					break;
+				}
+
+				if let Err(e) = result {
+					log::warn!(target: "evm", "on_idle: remove contract {:?} failed: {:?}", contract, e);
+				}
+				PendingRemovals::<T>::remove(contract);
+				used_weight = used_weight.saturating_add(T::DbWeight::get().writes(1));
+			}
+			used_weight
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -835,6 +902,12 @@ pub mod module {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Whether the storage of the removed contract at `address` is still
+	/// being removed.
+	pub fn is_removing(address: &H160) -> bool {
+		RemovingContracts::<T>::contains_key(address)
+	}
+
 	/// Check whether an account is empty.
 	pub fn is_account_empty(address: &H160) -> bool {
 		let account_id = T::AddressMapping::get_account_id(address);
@@ -858,11 +931,15 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Remove the contract at `address`. Its storage is removed later by an
+	/// `EvmTask::Remove` dispatched when the chain is idle, or by `on_idle`
+	/// if the idle queue is full, which refunds the storage deposit to
+	/// `refund_to` if any.
 	#[transactional]
-	pub fn remove_contract(address: &EvmAddress) -> Result<u32, DispatchError> {
+	pub fn remove_contract(address: &EvmAddress, refund_to: Option<T::AccountId>) -> DispatchResult {
 		let address_account = T::AddressMapping::get_account_id(address);
 
-		let size = Accounts::<T>::try_mutate_exists(address, |account_info| -> Result<u32, DispatchError> {
+		Accounts::<T>::try_mutate_exists(address, |account_info| -> DispatchResult {
 			let account_info = account_info.as_mut().ok_or(Error::<T>::ContractNotFound)?;
 			let contract_info = account_info.contract_info.take().ok_or(Error::<T>::ContractNotFound)?;
 
@@ -882,18 +959,76 @@ impl<T: Config> Pallet<T> {
 				}
 			});
 
-			AccountStorages::<T>::remove_prefix(address, None);
+			Ok(())
+		})?;
+
+		RemovingContracts::<T>::insert(address, ());
+		let task = EvmTask::Remove {
+			contract: *address,
+			refund_to: refund_to.clone(),
+		};
+		if T::IdleScheduler::schedule(task.into()).is_err() {
+			// the idle queue is full, don't fail the removal but remove the storage
+			// in `on_idle` instead
+			PendingRemovals::<T>::insert(address, refund_to);
+		}
+		Ok(())
+	}
+
+	/// Remove up to `limit` storage items of the removed `contract`, and refund
+	/// their storage deposit to `refund_to` if any. Once all are removed, the
+	/// rest of the deposit is refunded and the contract account released.
+	///
+	/// Returns the number of storage items removed, the result, and whether
+	/// all are removed.
+	fn remove_contract_storage(
+		contract: &EvmAddress,
+		refund_to: Option<&T::AccountId>,
+		limit: u32,
+	) -> (u32, DispatchResult, bool) {
+		let (count, finished) = match AccountStorages::<T>::remove_prefix(contract, Some(limit)) {
+			AllRemoved(count) => (count, true),
+			SomeRemaining(count) => (count, false),
+		};
 
-			let size = ContractStorageSizes::<T>::take(address);
+		let storage = count.saturating_mul(STORAGE_SIZE);
+		Self::update_contract_storage_size(contract, -(storage as i32));
+		Self::refund_storage(contract, refund_to, storage);
+		if !finished {
+			return (count, Ok(()), false);
+		}
 
-			Ok(size)
-		})?;
+		// code size and extra bytes
+		let storage = ContractStorageSizes::<T>::take(contract);
+		Self::refund_storage(contract, refund_to, storage);
+		RemovingContracts::<T>::remove(contract);
 
-		// this should happen after `Accounts` is updated because this could trigger another updates on
-		// `Accounts`
-		frame_system::Pallet::<T>::dec_providers(&address_account)?;
+		// release the provider of the contract, this could kill the account and trigger another
+		// updates on `Accounts`
+		let contract_account = T::AddressMapping::get_account_id(contract);
+		let result = frame_system::Pallet::<T>::dec_providers(&contract_account).map(|_| ());
 
-		Ok(size)
+		(count, result, true)
+	}
+
+	/// Refund the storage deposit of `storage` bytes of the removed `contract`
+	/// to `refund_to`.
+	fn refund_storage(contract: &EvmAddress, refund_to: Option<&T::AccountId>, storage: u32) {
+		let refund_to = match refund_to {
+			Some(refund_to) if !storage.is_zero() => refund_to,
+			_ => return,
+		};
+
+		let contract_account = T::AddressMapping::get_account_id(contract);
+		let amount = T::StorageDepositPerByte::get().saturating_mul(storage.into());
+		let res = T::Currency::repatriate_reserved_named(
+			&RESERVE_ID_STORAGE_DEPOSIT,
+			&contract_account,
+			refund_to,
+			amount,
+			BalanceStatus::Free,
+		);
+		debug_assert!(res.map_or(false, |val| val.is_zero()));
 	}
 
 	/// Removes an account from Accounts and AccountStorages.
@@ -1181,21 +1316,8 @@ impl<T: Config> Pallet<T> {
 		ensure!(contract_info.maintainer == *maintainer, Error::<T>::NoPermission);
 		ensure!(!contract_info.deployed, Error::<T>::ContractAlreadyDeployed);
 
-		let storage = Self::remove_contract(&contract)?;
-
-		let contract_account = T::AddressMapping::get_account_id(&contract);
-
-		let amount = T::StorageDepositPerByte::get().saturating_mul(storage.into());
-		let val = T::Currency::repatriate_reserved_named(
-			&RESERVE_ID_STORAGE_DEPOSIT,
-			&contract_account,
-			&who,
-			amount,
-			BalanceStatus::Free,
-		)?;
-		debug_assert!(val.is_zero());
-
-		Ok(())
+		// the storage deposit is refunded to `who` as the storage is removed
+		Self::remove_contract(&contract, Some(who))
 	}
 
 	fn ensure_root_or_signed(o: T::Origin) -> Result<Either<(), T::AccountId>, BadOrigin> {
@@ -1406,6 +1528,52 @@ impl<T: Config> OnKilledAccount<T::AccountId> for CallKillAccount<T> {
 	}
 }
 
+/// Tasks of the EVM, dispatched when the chain is idle.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub enum EvmTask<T: Config> {
+	/// Remove the storage of the removed `contract`, and refund the storage
+	/// deposit to `refund_to` if any.
+	Remove {
+		contract: EvmAddress,
+		refund_to: Option<T::AccountId>,
+	},
+}
+
+impl<T: Config> DispatchableTask for EvmTask<T> {
+	fn dispatch(self, weight: Weight) -> TaskResult {
+		match self {
+			EvmTask::Remove { contract, refund_to } => {
+				let base_weight = T::WeightInfo::remove_contract_storage(0);
+				let limit = if weight < base_weight {
+					0
+				} else {
+					(weight - base_weight)
+						.checked_div(T::DbWeight::get().write)
+						.map_or(REMOVE_STORAGE_LIMIT, |limit| {
+							cmp::min(limit, REMOVE_STORAGE_LIMIT as Weight) as u32
+						})
+				};
+				if limit.is_zero() {
+					return TaskResult {
+						result: Ok(()),
+						used_weight: 0,
+						finished: false,
+					};
+				}
+
+				let (count, result, finished) =
+					Pallet::<T>::remove_contract_storage(&contract, refund_to.as_ref(), limit);
+				TaskResult {
+					result,
+					used_weight: T::WeightInfo::remove_contract_storage(count),
+					finished,
+				}
+			}
+		}
+	}
+}
+
 pub fn code_hash(code: &[u8]) -> H256 {
 	H256::from_slice(Keccak256::digest(code).as_slice())
 }
@@ -26,14 +26,18 @@ use frame_support::{construct_runtime, ord_parameter_types, parameter_types, tra
 use frame_system::EnsureSignedBy;
 use module_support::mocks::MockAddressMapping;
 use orml_traits::parameter_type_with_key;
-use primitives::{Amount, BlockNumber, CurrencyId, ReserveIdentifier, TokenSymbol};
+use primitives::{task::TaskPriority, Amount, BlockNumber, CurrencyId, ReserveIdentifier, TokenSymbol};
 use sp_core::{H160, H256};
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
 	AccountId32,
 };
-use std::{collections::BTreeMap, str::FromStr};
+use std::{
+	cell::RefCell,
+	collections::{BTreeMap, BTreeSet},
+	str::FromStr,
+};
 
 mod evm_mod {
 	pub use super::super::*;
@@ -159,6 +163,48 @@ ord_parameter_types! {
 	pub const ChainId: u64 = 1;
 }
 
+thread_local! {
+	static PAUSED_CONTRACTS: RefCell<BTreeSet<H160>> = RefCell::new(BTreeSet::new());
+	static IDLE_TASKS: RefCell<Vec<EvmTask<Runtime>>> = RefCell::new(Vec::new());
+	static IDLE_QUEUE_FULL: RefCell<bool> = RefCell::new(false);
+}
+
+pub struct MockIdleScheduler;
+impl MockIdleScheduler {
+	pub fn take_tasks() -> Vec<EvmTask<Runtime>> {
+		IDLE_TASKS.with(|v| v.borrow_mut().drain(..).collect())
+	}
+
+	pub fn set_queue_full(full: bool) {
+		IDLE_QUEUE_FULL.with(|v| *v.borrow_mut() = full);
+	}
+}
+impl IdleScheduler<EvmTask<Runtime>> for MockIdleScheduler {
+	fn schedule(task: EvmTask<Runtime>) -> DispatchResult {
+		if IDLE_QUEUE_FULL.with(|v| *v.borrow()) {
+			return Err(DispatchError::Other("QueueFull"));
+		}
+		IDLE_TASKS.with(|v| v.borrow_mut().push(task));
+		Ok(())
+	}
+
+	fn schedule_with(task: EvmTask<Runtime>, _priority: TaskPriority, _max_weight: Weight) -> DispatchResult {
+		Self::schedule(task)
+	}
+}
+
+pub struct MockPausedCalls;
+impl MockPausedCalls {
+	pub fn pause(contract: H160) {
+		PAUSED_CONTRACTS.with(|v| v.borrow_mut().insert(contract));
+	}
+}
+impl EvmCallPaused for MockPausedCalls {
+	fn is_paused(contract: H160, _input: &[u8]) -> bool {
+		PAUSED_CONTRACTS.with(|v| v.borrow().contains(&contract))
+	}
+}
+
 impl Config for Runtime {
 	type AddressMapping = MockAddressMapping;
 	type Currency = Balances;
@@ -168,6 +214,7 @@ impl Config for Runtime {
 
 	type Event = Event;
 	type Precompiles = ();
+	type PausedCalls = MockPausedCalls;
 	type ChainId = ChainId;
 	type GasToWeight = GasToWeight;
 	type ChargeTransactionPayment = ();
@@ -181,6 +228,8 @@ impl Config for Runtime {
 
 	type Runner = crate::runner::stack::Runner<Self>;
 	type FindAuthor = AuthorGiven;
+	type Task = EvmTask<Runtime>;
+	type IdleScheduler = MockIdleScheduler;
 	type WeightInfo = ();
 }
 
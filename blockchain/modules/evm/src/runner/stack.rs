@@ -36,7 +36,7 @@ use frame_support::{
 	ensure, log,
 	traits::{Currency, ExistenceRequirement, Get},
 };
-use module_support::AddressMapping;
+use module_support::{AddressMapping, EvmCallPaused};
 pub use primitives::{
 	evm::{Account, EvmAddress, Log, Vicinity},
 	ReserveIdentifier, MIRRORED_NFT_ADDRESS_START,
@@ -45,7 +45,13 @@ use sha3::{Digest, Keccak256};
 use sp_core::{H160, H256, U256};
 use sp_io::KillStorageResult::{AllRemoved, SomeRemaining};
 use sp_runtime::traits::UniqueSaturatedInto;
-use sp_std::{boxed::Box, collections::btree_set::BTreeSet, marker::PhantomData, mem, vec::Vec};
+use sp_std::{
+	boxed::Box,
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	marker::PhantomData,
+	mem,
+	vec::Vec,
+};
 
 #[derive(Default)]
 pub struct Runner<T: Config> {
@@ -162,7 +168,8 @@ impl<T: Config> Runner<T> {
 				"Deleting account at {:?}",
 				address
 			);
-			Pallet::<T>::remove_contract(&address).map_err(|e| {
+			// the storage deposit is already refunded by the storage meter
+			Pallet::<T>::remove_contract(&address, None).map_err(|e| {
 				log::debug!(
 					target: "evm",
 					"CannotKillContract address {:?}, reason: {:?}",
@@ -427,6 +434,9 @@ impl<'config> SubstrateStackSubstate<'config> {
 pub struct SubstrateStackState<'vicinity, 'config, T> {
 	vicinity: &'vicinity Vicinity,
 	substate: SubstrateStackSubstate<'config>,
+	/// The paused checks of the transaction by contract and selector, so that
+	/// the pauses are read once however often a contract is called.
+	paused_calls: BTreeMap<(H160, Option<[u8; 4]>), bool>,
 	_marker: PhantomData<T>,
 }
 
@@ -442,6 +452,7 @@ impl<'vicinity, 'config, T: Config> SubstrateStackState<'vicinity, 'config, T> {
 				storage_logs: Vec::new(),
 				parent: None,
 			},
+			paused_calls: BTreeMap::new(),
 			_marker: PhantomData,
 		}
 	}
@@ -549,6 +560,22 @@ impl<'vicinity, 'config, T: Config> StackStateT<'config> for SubstrateStackState
 		self.substate.deleted(address)
 	}
 
+	fn is_removing(&self, address: H160) -> bool {
+		Pallet::<T>::is_removing(&address)
+	}
+
+	fn is_paused(&mut self, address: H160, input: &[u8]) -> bool {
+		let selector = input.get(0..4).map(|bytes| {
+			let mut selector = [0u8; 4];
+			selector.copy_from_slice(bytes);
+			selector
+		});
+		*self
+			.paused_calls
+			.entry((address, selector))
+			.or_insert_with(|| T::PausedCalls::is_paused(address, input))
+	}
+
 	fn inc_nonce(&mut self, address: H160) {
 		Accounts::<T>::mutate(&address, |maybe_account| {
 			if let Some(account) = maybe_account.as_mut() {
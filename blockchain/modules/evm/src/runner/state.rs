@@ -565,6 +565,12 @@ impl<'config, S: StackState<'config>> StackExecutor<'config, S> {
 				return Capture::Exit((ExitError::CreateCollision.into(), None, Vec::new()));
 			}
 
+			// the storage of the contract removed at the address is not all removed yet
+			if self.state.is_removing(address) {
+				let _ = self.exit_substate(StackExitKind::Failed);
+				return Capture::Exit((ExitError::CreateCollision.into(), None, Vec::new()));
+			}
+
 			self.state.reset_storage(address);
 		}
 
@@ -713,6 +719,15 @@ impl<'config, S: StackState<'config>> StackExecutor<'config, S> {
 			}
 		}
 
+		if self.state.is_paused(code_address, &input) {
+			log::debug!(target: "evm", "Call to paused contract {:?}", code_address);
+			let _ = self.exit_substate(StackExitKind::Reverted);
+			return Capture::Exit((
+				ExitReason::Revert(ExitRevert::Reverted),
+				encode_revert_message(&ExitError::Other("contract is paused".into())),
+			));
+		}
+
 		if let Some(transfer) = transfer {
 			match self.state.transfer(transfer) {
 				Ok(()) => (),
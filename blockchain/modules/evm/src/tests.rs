@@ -37,6 +37,30 @@ use sp_core::{
 use sp_runtime::{traits::BadOrigin, AccountId32};
 use std::str::FromStr;
 
+// pragma solidity ^0.5.16;
+//
+// contract SendEther {
+//     function sendViaTransfer(address payable _to) public payable {
+//         // This function is no longer recommended for sending Ether.
+//         _to.transfer(msg.value);
+//     }
+//
+//     function sendViaSend(address payable _to) public payable {
+//         // Send returns a boolean value indicating success or failure.
+//         // This function is not recommended for sending Ether.
+//         bool sent = _to.send(msg.value);
+//         require(sent, "Failed to send Ether");
+//     }
+//
+//     function sendViaCall(address payable _to) public payable {
+//         // Call returns a boolean value indicating success or failure.
+//         // This is the current recommended method to use.
+//         (bool sent, bytes memory data) = _to.call.value(msg.value)("");
+//         require(sent, "Failed to send Ether");
+//     }
+// }
+const SEND_ETHER: &str = "0x608060405234801561001057600080fd5b50610318806100206000396000f3fe6080604052600436106100345760003560e01c8063636e082b1461003957806374be48061461007d578063830c29ae146100c1575b600080fd5b61007b6004803603602081101561004f57600080fd5b81019080803573ffffffffffffffffffffffffffffffffffffffff169060200190929190505050610105565b005b6100bf6004803603602081101561009357600080fd5b81019080803573ffffffffffffffffffffffffffffffffffffffff16906020019092919050505061014f565b005b610103600480360360208110156100d757600080fd5b81019080803573ffffffffffffffffffffffffffffffffffffffff1690602001909291905050506101ff565b005b8073ffffffffffffffffffffffffffffffffffffffff166108fc349081150290604051600060405180830381858888f1935050505015801561014b573d6000803e3d6000fd5b5050565b60008173ffffffffffffffffffffffffffffffffffffffff166108fc349081150290604051600060405180830381858888f193505050509050806101fb576040517f08c379a00000000000000000000000000000000000000000000000000000000081526004018080602001828103825260148152602001807f4661696c656420746f2073656e6420457468657200000000000000000000000081525060200191505060405180910390fd5b5050565b600060608273ffffffffffffffffffffffffffffffffffffffff163460405180600001905060006040518083038185875af1925050503d8060008114610261576040519150601f19603f3d011682016040523d82523d6000602084013e610266565b606091505b5091509150816102de576040517f08c379a00000000000000000000000000000000000000000000000000000000081526004018080602001828103825260148152602001807f4661696c656420746f2073656e6420457468657200000000000000000000000081525060200191505060405180910390fd5b50505056fea265627a7a723158201b401be037c87d59ec386e75b0166702abb5a64f93ea20080904b6791bd88d1564736f6c63430005110032";
+
 #[test]
 fn fail_call_return_ok() {
 	new_test_ext().execute_with(|| {
@@ -261,6 +285,198 @@ fn call_reverts_with_message() {
 	});
 }
 
+#[test]
+fn call_reverts_if_contract_paused() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//	 function multiply(uint a, uint b) public pure returns(uint) {
+	// 	 	return a * b;
+	// 	 }
+	// }
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		// deploy contract
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			1000000,
+			1000000,
+			<Runtime as Config>::config(),
+		).unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+
+		let contract_address = result.value;
+
+		#[cfg(not(feature = "with-ethereum-compatibility"))]
+		deploy_free(contract_address);
+
+		MockPausedCalls::pause(contract_address);
+
+		// multiply(2, 3)
+		let multiply = from_hex(
+			"0x165c4a1600000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000003"
+		).unwrap();
+
+		let result = <Runtime as Config>::Runner::call(
+			alice(),
+			alice(),
+			contract_address,
+			multiply,
+			0,
+			1000000,
+			1000000,
+			<Runtime as Config>::config(),
+		).unwrap();
+
+		assert_eq!(result.exit_reason, ExitReason::Revert(ExitRevert::Reverted));
+		let message = String::from_utf8_lossy(&result.value);
+		assert!(message.contains("contract is paused"));
+	});
+}
+
+#[test]
+fn internal_call_reverts_if_contract_paused() {
+	// the target is called with `sendViaCall`
+	let send_ether = from_hex(SEND_ETHER).unwrap();
+	// a contract whose code is a single STOP
+	let stop = from_hex("0x6001600c60003960016000f300").unwrap();
+
+	new_test_ext().execute_with(|| {
+		let amount = 1000u64;
+
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			send_ether,
+			0,
+			10000000,
+			10000000,
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		let contract_a = result.value;
+
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			stop,
+			0,
+			10000000,
+			10000000,
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		let contract_b = result.value;
+
+		#[cfg(not(feature = "with-ethereum-compatibility"))]
+		{
+			deploy_free(contract_a);
+			deploy_free(contract_b);
+		}
+
+		// sendViaCall(contract_b)
+		let mut via_call = from_hex("0x830c29ae").unwrap();
+		via_call.append(&mut Vec::from(H256::from(contract_b).as_bytes()));
+
+		let result = <Runtime as Config>::Runner::call(
+			alice(),
+			alice(),
+			contract_a,
+			via_call.clone(),
+			amount,
+			1000000,
+			1000000,
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Stopped));
+		assert_eq!(balance(contract_b), amount);
+
+		MockPausedCalls::pause(contract_b);
+
+		let result = <Runtime as Config>::Runner::call(
+			alice(),
+			alice(),
+			contract_a,
+			via_call,
+			amount,
+			1000000,
+			1000000,
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Revert(ExitRevert::Reverted));
+		let message = String::from_utf8_lossy(&result.value);
+		assert!(message.contains("Failed to send Ether"));
+		assert_eq!(balance(contract_b), amount);
+	});
+}
+
+#[test]
+fn internal_call_reverts_if_precompile_paused() {
+	// the target is called with `sendViaCall`
+	let send_ether = from_hex(SEND_ETHER).unwrap();
+	// the first Setheum precompile address, the pause is checked before the
+	// precompiles are run
+	let precompile = H160::from_low_u64_be(0x400);
+
+	new_test_ext().execute_with(|| {
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			send_ether,
+			0,
+			10000000,
+			10000000,
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		let contract_address = result.value;
+
+		#[cfg(not(feature = "with-ethereum-compatibility"))]
+		deploy_free(contract_address);
+
+		// sendViaCall(precompile)
+		let mut via_call = from_hex("0x830c29ae").unwrap();
+		via_call.append(&mut Vec::from(H256::from(precompile).as_bytes()));
+
+		let result = <Runtime as Config>::Runner::call(
+			alice(),
+			alice(),
+			contract_address,
+			via_call.clone(),
+			0,
+			1000000,
+			1000000,
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Stopped));
+
+		MockPausedCalls::pause(precompile);
+
+		let result = <Runtime as Config>::Runner::call(
+			alice(),
+			alice(),
+			contract_address,
+			via_call,
+			0,
+			1000000,
+			1000000,
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Revert(ExitRevert::Reverted));
+		let message = String::from_utf8_lossy(&result.value);
+		assert!(message.contains("Failed to send Ether"));
+	});
+}
+
 #[test]
 fn should_deploy_payable_contract() {
 	// pragma solidity ^0.5.0;
@@ -339,31 +555,7 @@ fn should_deploy_payable_contract() {
 
 #[test]
 fn should_transfer_from_contract() {
-	// pragma solidity ^0.5.16;
-	//
-	// contract SendEther {
-	//     function sendViaTransfer(address payable _to) public payable {
-	//         // This function is no longer recommended for sending Ether.
-	//         _to.transfer(msg.value);
-	//     }
-	//
-	//     function sendViaSend(address payable _to) public payable {
-	//         // Send returns a boolean value indicating success or failure.
-	//         // This function is not recommended for sending Ether.
-	//         bool sent = _to.send(msg.value);
-	//         require(sent, "Failed to send Ether");
-	//     }
-	//
-	//     function sendViaCall(address payable _to) public payable {
-	//         // Call returns a boolean value indicating success or failure.
-	//         // This is the current recommended method to use.
-	//         (bool sent, bytes memory data) = _to.call.value(msg.value)("");
-	//         require(sent, "Failed to send Ether");
-	//     }
-	// }
-	let contract = from_hex(
-		"0x608060405234801561001057600080fd5b50610318806100206000396000f3fe6080604052600436106100345760003560e01c8063636e082b1461003957806374be48061461007d578063830c29ae146100c1575b600080fd5b61007b6004803603602081101561004f57600080fd5b81019080803573ffffffffffffffffffffffffffffffffffffffff169060200190929190505050610105565b005b6100bf6004803603602081101561009357600080fd5b81019080803573ffffffffffffffffffffffffffffffffffffffff16906020019092919050505061014f565b005b610103600480360360208110156100d757600080fd5b81019080803573ffffffffffffffffffffffffffffffffffffffff1690602001909291905050506101ff565b005b8073ffffffffffffffffffffffffffffffffffffffff166108fc349081150290604051600060405180830381858888f1935050505015801561014b573d6000803e3d6000fd5b5050565b60008173ffffffffffffffffffffffffffffffffffffffff166108fc349081150290604051600060405180830381858888f193505050509050806101fb576040517f08c379a00000000000000000000000000000000000000000000000000000000081526004018080602001828103825260148152602001807f4661696c656420746f2073656e6420457468657200000000000000000000000081525060200191505060405180910390fd5b5050565b600060608273ffffffffffffffffffffffffffffffffffffffff163460405180600001905060006040518083038185875af1925050503d8060008114610261576040519150601f19603f3d011682016040523d82523d6000602084013e610266565b606091505b5091509150816102de576040517f08c379a00000000000000000000000000000000000000000000000000000000081526004018080602001828103825260148152602001807f4661696c656420746f2073656e6420457468657200000000000000000000000081525060200191505060405180910390fd5b50505056fea265627a7a723158201b401be037c87d59ec386e75b0166702abb5a64f93ea20080904b6791bd88d1564736f6c63430005110032"
-	).unwrap();
+	let contract = from_hex(SEND_ETHER).unwrap();
 	new_test_ext().execute_with(|| {
 		let amount = 1000u64;
 
@@ -1229,7 +1421,31 @@ fn should_selfdestruct() {
 		);
 		let contract_account_id = <Runtime as Config>::AddressMapping::get_account_id(&contract_address);
 		assert_eq!(System::providers(&contract_account_id), 2);
-		assert_ok!(EVM::selfdestruct(Origin::signed(alice_account_id), contract_address));
+		assert_ok!(EVM::selfdestruct(Origin::signed(alice_account_id.clone()), contract_address));
+
+		// the storage is removed by the idle task
+		assert!(!CodeInfos::<Runtime>::contains_key(&code_hash));
+		assert_eq!(AccountStorages::<Runtime>::iter_prefix(&contract_address).count(), 1);
+		assert_eq!(balance(alice()), alice_balance + amount);
+		let tasks = MockIdleScheduler::take_tasks();
+		assert_eq!(
+			tasks,
+			vec![EvmTask::Remove {
+				contract: contract_address,
+				refund_to: Some(alice_account_id),
+			}]
+		);
+
+		// not enough weight to remove any storage
+		let result = tasks[0].clone().dispatch(0);
+		assert!(!result.finished);
+		assert_eq!(AccountStorages::<Runtime>::iter_prefix(&contract_address).count(), 1);
+
+		let result = tasks[0].clone().dispatch(Weight::max_value());
+		assert!(result.finished);
+		assert_ok!(result.result);
+		// storage deposit refunded
+		assert_eq!(balance(alice()), INITIAL_BALANCE);
 
 		assert_eq!(System::providers(&contract_account_id), 0);
 		assert!(!System::account_exists(&contract_account_id));
@@ -1241,6 +1457,130 @@ fn should_selfdestruct() {
 	});
 }
 
+#[test]
+fn selfdestruct_should_work_when_idle_queue_full() {
+	// the Test contract of `should_selfdestruct`
+	let mut contract = from_hex(
+		"0x60806040526040516100c73803806100c783398181016040526020811015602557600080fd5b81019080805190602001909291905050508060008190555050607b8061004c6000396000f3fe608060405260043610601c5760003560e01c806320965255146021575b600080fd5b6027603d565b6040518082815260200191505060405180910390f35b6000805490509056fea265627a7a72315820b832564a9db725638dcef03d07bfbdd2dc818020ea359630317e2126e95c314964736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+
+		let stored_value: Vec<u8> =
+			from_hex("0x000000000000000000000000000000000000000000000000000000000000007b").unwrap();
+		contract.append(&mut stored_value.clone());
+
+		// create contract
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			1000000,
+			100000,
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		let contract_address = result.value;
+		let contract_account_id = <Runtime as Config>::AddressMapping::get_account_id(&contract_address);
+
+		MockIdleScheduler::set_queue_full(true);
+		assert_ok!(EVM::selfdestruct(Origin::signed(alice_account_id.clone()), contract_address));
+
+		// the storage removal is pending instead of queued
+		assert!(MockIdleScheduler::take_tasks().is_empty());
+		assert_eq!(
+			PendingRemovals::<Runtime>::get(&contract_address),
+			Some(Some(alice_account_id))
+		);
+		assert!(!Accounts::<Runtime>::contains_key(&contract_address));
+		assert_eq!(AccountStorages::<Runtime>::iter_prefix(&contract_address).count(), 1);
+
+		// not enough weight to remove any storage
+		EVM::on_idle(1, 0);
+		assert!(PendingRemovals::<Runtime>::contains_key(&contract_address));
+		assert_eq!(AccountStorages::<Runtime>::iter_prefix(&contract_address).count(), 1);
+
+		EVM::on_idle(1, Weight::max_value());
+		assert!(!PendingRemovals::<Runtime>::contains_key(&contract_address));
+		// storage deposit refunded
+		assert_eq!(balance(alice()), INITIAL_BALANCE);
+		assert_eq!(System::providers(&contract_account_id), 0);
+		assert!(!System::account_exists(&contract_account_id));
+		assert!(!ContractStorageSizes::<Runtime>::contains_key(&contract_address));
+		assert_eq!(AccountStorages::<Runtime>::iter_prefix(&contract_address).count(), 0);
+	});
+}
+
+#[test]
+fn create2_should_fail_while_removing_selfdestructed_contract() {
+	// the Test contract of `should_selfdestruct`
+	let mut contract = from_hex(
+		"0x60806040526040516100c73803806100c783398181016040526020811015602557600080fd5b81019080805190602001909291905050508060008190555050607b8061004c6000396000f3fe608060405260043610601c5760003560e01c806320965255146021575b600080fd5b6027603d565b6040518082815260200191505060405180910390f35b6000805490509056fea265627a7a72315820b832564a9db725638dcef03d07bfbdd2dc818020ea359630317e2126e95c314964736f6c63430005110032"
+	).unwrap();
+	let salt = H256::repeat_byte(1);
+
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+
+		let stored_value: Vec<u8> =
+			from_hex("0x000000000000000000000000000000000000000000000000000000000000007b").unwrap();
+		contract.append(&mut stored_value.clone());
+
+		let result = <Runtime as Config>::Runner::create2(
+			alice(),
+			contract.clone(),
+			salt,
+			0,
+			1000000,
+			100000,
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		let contract_address = result.value;
+
+		assert_ok!(EVM::selfdestruct(Origin::signed(alice_account_id), contract_address));
+		assert!(RemovingContracts::<Runtime>::contains_key(&contract_address));
+		assert_eq!(AccountStorages::<Runtime>::iter_prefix(&contract_address).count(), 1);
+
+		// can't redeploy while the storage is being removed
+		let result = <Runtime as Config>::Runner::create2(
+			alice(),
+			contract.clone(),
+			salt,
+			0,
+			1000000,
+			100000,
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Error(ExitError::CreateCollision));
+		assert!(Accounts::<Runtime>::get(&contract_address).unwrap().contract_info.is_none());
+		assert_eq!(AccountStorages::<Runtime>::iter_prefix(&contract_address).count(), 1);
+
+		let tasks = MockIdleScheduler::take_tasks();
+		let result = tasks[0].clone().dispatch(Weight::max_value());
+		assert!(result.finished);
+		assert!(!RemovingContracts::<Runtime>::contains_key(&contract_address));
+
+		// redeployed once the storage is removed
+		let result = <Runtime as Config>::Runner::create2(
+			alice(),
+			contract,
+			salt,
+			0,
+			1000000,
+			100000,
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		assert_eq!(result.value, contract_address);
+		assert_eq!(AccountStorages::<Runtime>::iter_prefix(&contract_address).count(), 1);
+	});
+}
+
 #[test]
 fn storage_limit_should_work() {
 	// pragma solidity ^0.5.0;
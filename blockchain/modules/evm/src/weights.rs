@@ -57,6 +57,7 @@ pub trait WeightInfo {
 	fn disable_contract_development() -> Weight;
 	fn set_code() -> Weight;
 	fn selfdestruct() -> Weight;
+	fn remove_contract_storage(c: u32, ) -> Weight;
 }
 
 /// Weights for module_evm using the Setheum node and recommended hardware.
@@ -97,6 +98,12 @@ impl<T: frame_system::Config> WeightInfo for SetheumWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(7 as Weight))
 			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 	}
+	fn remove_contract_storage(c: u32, ) -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add((T::DbWeight::get().writes(1 as Weight)).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -136,4 +143,10 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
 	}
+	fn remove_contract_storage(c: u32, ) -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add((RocksDbWeight::get().writes(1 as Weight)).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
 }
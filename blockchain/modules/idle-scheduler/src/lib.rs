@@ -21,19 +21,26 @@
 //! # Idle scheduler Module
 //!
 //! Allow pallets and chain maintainer to schedule a task to be dispatched when chain is idle.
+//!
+//! Tasks are queued by priority and dispatched in order, highest priority first, each with
+//! at most its own max weight. A task that fails is retried with exponential back-off, and
+//! dropped once it has failed `MaxRetries` times.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 #![allow(unused_must_use)]
-use setheum_primitives::{task::TaskResult, Nonce};
+use setheum_primitives::{
+	task::{TaskPriority, TaskResult},
+	Nonce,
+};
 use codec::FullCodec;
 use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::*;
 pub use module_support::{DispatchableTask, IdleScheduler};
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{One, Zero},
-	ArithmeticError,
+	traits::{One, Saturating, Zero},
+	ArithmeticError, RuntimeDebug,
 };
 use sp_std::{cmp::PartialEq, fmt::Debug, prelude::*};
 
@@ -43,6 +50,19 @@ mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The scheduling information of a task.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct TaskInfo<BlockNumber> {
+	/// The queue the task is in.
+	pub priority: TaskPriority,
+	/// The max weight given to the task each time it is dispatched.
+	pub max_weight: Weight,
+	/// The number of failed dispatches.
+	pub failures: u32,
+	/// The task is not dispatched before this block.
+	pub next_attempt: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -60,6 +80,36 @@ pub mod module {
 		/// The minimum weight that should remain before idle tasks are dispatched.
 		#[pallet::constant]
 		type MinimumWeightRemainInBlock: Get<Weight>;
+
+		/// The max weight a task can be given each time it is dispatched, also
+		/// the max weight of tasks scheduled without one.
+		#[pallet::constant]
+		type MaxTaskWeight: Get<Weight>;
+
+		/// The max number of tasks in each priority queue.
+		#[pallet::constant]
+		type MaxQueuedTasks: Get<u32>;
+
+		/// The number of times a task failing without finishing is retried
+		/// before it is dropped.
+		#[pallet::constant]
+		type MaxRetries: Get<u32>;
+
+		/// The delay before the first retry of a failed task, doubled on each
+		/// following failure.
+		#[pallet::constant]
+		type RetryDelay: Get<Self::BlockNumber>;
+
+		/// The origin which may schedule tasks.
+		type ScheduleOrigin: EnsureOrigin<Self::Origin>;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The priority queue is full.
+		QueueFull,
+		/// The max weight of the task is zero or above `MaxTaskWeight`.
+		InvalidTaskWeight,
 	}
 
 	#[pallet::event]
@@ -67,6 +117,20 @@ pub mod module {
 	pub enum Event<T: Config> {
 		/// A task has been dispatched on_idle.
 		TaskDispatched { task_id: Nonce, result: DispatchResult },
+		/// A task has been scheduled.
+		TaskScheduled {
+			task_id: Nonce,
+			priority: TaskPriority,
+			max_weight: Weight,
+		},
+		/// A failed task will be retried at `next_attempt`.
+		TaskRetryScheduled {
+			task_id: Nonce,
+			failures: u32,
+			next_attempt: T::BlockNumber,
+		},
+		/// A task has been dropped after failing too many times.
+		TaskDropped { task_id: Nonce, failures: u32 },
 	}
 
 	/// Some documentation
@@ -74,6 +138,20 @@ pub mod module {
 	#[pallet::getter(fn tasks)]
 	pub type Tasks<T: Config> = StorageMap<_, Twox64Concat, Nonce, T::Task, OptionQuery>;
 
+	/// The scheduling information of the tasks.
+	///
+	/// TaskInfos: map Nonce => Option<TaskInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn task_infos)]
+	pub type TaskInfos<T: Config> = StorageMap<_, Twox64Concat, Nonce, TaskInfo<T::BlockNumber>, OptionQuery>;
+
+	/// The ids of the tasks of each priority, in the order they are dispatched.
+	///
+	/// TaskQueues: map TaskPriority => Vec<Nonce>
+	#[pallet::storage]
+	#[pallet::getter(fn task_queues)]
+	pub type TaskQueues<T: Config> = StorageMap<_, Twox64Concat, TaskPriority, Vec<Nonce>, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn next_task_id)]
 	pub type NextTaskId<T: Config> = StorageValue<_, Nonce, ValueQuery>;
@@ -83,8 +161,8 @@ pub mod module {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
-		fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
-			Self::do_dispatch_tasks(remaining_weight)
+		fn on_idle(n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			Self::do_dispatch_tasks(n, remaining_weight)
 		}
 	}
 
@@ -92,17 +170,56 @@ pub mod module {
 	impl<T: Config> Pallet<T> {
 		#[pallet::weight(< T as Config >::WeightInfo::schedule_task())]
 		pub fn schedule_task(origin: OriginFor<T>, task: T::Task) -> DispatchResult {
-			ensure_root(origin)?;
-			Self::do_schedule_task(task)
+			T::ScheduleOrigin::ensure_origin(origin)?;
+			Self::do_schedule_task(task, TaskPriority::Normal, T::MaxTaskWeight::get())
+		}
+
+		/// Schedule `task` in the `priority` queue, giving it at most
+		/// `max_weight` each time it is dispatched.
+		#[pallet::weight(< T as Config >::WeightInfo::schedule_task())]
+		pub fn schedule_task_with(
+			origin: OriginFor<T>,
+			task: T::Task,
+			priority: TaskPriority,
+			max_weight: Weight,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin)?;
+			Self::do_schedule_task(task, priority, max_weight)
 		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
 	/// Add the task to the queue to be dispatched later
-	fn do_schedule_task(task: T::Task) -> DispatchResult {
+	fn do_schedule_task(task: T::Task, priority: TaskPriority, max_weight: Weight) -> DispatchResult {
+		ensure!(
+			!max_weight.is_zero() && max_weight <= T::MaxTaskWeight::get(),
+			Error::<T>::InvalidTaskWeight
+		);
+
+		ensure!(
+			TaskQueues::<T>::decode_len(priority).unwrap_or_default() < T::MaxQueuedTasks::get() as usize,
+			Error::<T>::QueueFull
+		);
+
 		let id = Self::get_next_task_id()?;
+		TaskQueues::<T>::append(priority, id);
 		Tasks::<T>::insert(id, task);
+		TaskInfos::<T>::insert(
+			id,
+			TaskInfo {
+				priority,
+				max_weight,
+				failures: 0,
+				next_attempt: Zero::zero(),
+			},
+		);
+
+		Self::deposit_event(Event::<T>::TaskScheduled {
+			task_id: id,
+			priority,
+			max_weight,
+		});
 		Ok(())
 	}
 
@@ -115,43 +232,104 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
-	/// Keep dispatching tasks in Storage, until insufficient weight remains.
-	pub fn do_dispatch_tasks(total_weight: Weight) -> Weight {
+	/// The delay before the next attempt of a task that has failed `failures` times.
+	fn retry_delay(failures: u32) -> T::BlockNumber {
+		let multiplier = 2u32.saturating_pow(failures.saturating_sub(1));
+		T::RetryDelay::get().saturating_mul(multiplier.into())
+	}
+
+	/// Keep dispatching tasks in Storage by priority, until insufficient weight remains.
+	pub fn do_dispatch_tasks(now: T::BlockNumber, total_weight: Weight) -> Weight {
 		let mut weight_remaining = total_weight;
 		if weight_remaining <= T::MinimumWeightRemainInBlock::get() {
 			return Zero::zero();
 		}
 
-		let mut completed_tasks: Vec<(Nonce, TaskResult)> = vec![];
+		for priority in TaskPriority::ALL.iter() {
+			let queue = TaskQueues::<T>::get(priority);
+			if queue.is_empty() {
+				continue;
+			}
+			let mut removed: Vec<Nonce> = vec![];
+			let mut out_of_weight = false;
+			weight_remaining = weight_remaining.saturating_sub(T::DbWeight::get().reads_writes(1, 1));
+
+			for id in queue.iter() {
+				// If remaining weight falls below the minimmum, stop dispatching.
+				if weight_remaining <= T::MinimumWeightRemainInBlock::get() {
+					out_of_weight = true;
+					break;
+				}
 
-		for (id, task) in Tasks::<T>::iter() {
-			let result = task.dispatch(weight_remaining);
-			weight_remaining = weight_remaining.saturating_sub(result.used_weight);
-			if result.finished {
-				completed_tasks.push((id, result));
+				let (task, mut info) = match (Tasks::<T>::get(id), TaskInfos::<T>::get(id)) {
+					(Some(task), Some(info)) => (task, info),
+					_ => {
+						removed.push(*id);
+						continue;
+					}
+				};
+				weight_remaining = weight_remaining.saturating_sub(T::DbWeight::get().reads(2));
+				if info.next_attempt > now {
+					continue;
+				}
+
+				let result = task.dispatch(weight_remaining.min(info.max_weight));
+				weight_remaining = weight_remaining.saturating_sub(result.used_weight);
+
+				if result.finished {
+					// a finished task is done with, even if it failed
+					Self::remove_task(*id);
+					removed.push(*id);
+					Self::deposit_event(Event::<T>::TaskDispatched {
+						task_id: *id,
+						result: result.result,
+					});
+					weight_remaining = weight_remaining.saturating_sub(T::DbWeight::get().writes(2));
+				} else if result.result.is_err() {
+					info.failures = info.failures.saturating_add(1);
+					if info.failures > T::MaxRetries::get() {
+						Self::remove_task(*id);
+						removed.push(*id);
+						Self::deposit_event(Event::<T>::TaskDropped {
+							task_id: *id,
+							failures: info.failures,
+						});
+					} else {
+						info.next_attempt = now.saturating_add(Self::retry_delay(info.failures));
+						Self::deposit_event(Event::<T>::TaskRetryScheduled {
+							task_id: *id,
+							failures: info.failures,
+							next_attempt: info.next_attempt,
+						});
+						TaskInfos::<T>::insert(id, info);
+					}
+					weight_remaining = weight_remaining.saturating_sub(T::DbWeight::get().writes(2));
+				}
 			}
 
-			// If remaining weight falls below the minimmum, break from the loop.
-			if weight_remaining <= T::MinimumWeightRemainInBlock::get() {
+			if !removed.is_empty() {
+				TaskQueues::<T>::mutate(priority, |queue| queue.retain(|id| !removed.contains(id)));
+			}
+			if out_of_weight {
 				break;
 			}
 		}
 
-		// Deposit event and remove completed tasks.
-		for (id, result) in completed_tasks {
-			Self::deposit_event(Event::<T>::TaskDispatched {
-				task_id: id,
-				result: result.result,
-			});
-			Tasks::<T>::remove(id);
-		}
-
 		total_weight.saturating_sub(weight_remaining)
 	}
+
+	fn remove_task(id: Nonce) {
+		Tasks::<T>::remove(id);
+		TaskInfos::<T>::remove(id);
+	}
 }
 
 impl<T: Config> IdleScheduler<T::Task> for Pallet<T> {
 	fn schedule(task: T::Task) -> DispatchResult {
-		Self::do_schedule_task(task)
+		Self::do_schedule_task(task, TaskPriority::Normal, T::MaxTaskWeight::get())
+	}
+
+	fn schedule_with(task: T::Task, priority: TaskPriority, max_weight: Weight) -> DispatchResult {
+		Self::do_schedule_task(task, priority, max_weight)
 	}
 }
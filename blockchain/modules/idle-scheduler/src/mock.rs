@@ -26,7 +26,9 @@ use crate as module_idle_scheduler;
 use setheum_primitives::{define_combined_task, task::TaskResult};
 use frame_support::weights::Weight;
 use frame_support::{construct_runtime, parameter_types, traits::Everything};
+use frame_system::EnsureRoot;
 use module_support::DispatchableTask;
+use sp_runtime::DispatchError;
 
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
@@ -66,6 +68,10 @@ impl frame_system::Config for Runtime {
 
 parameter_types!(
 	pub const MinimumWeightRemainInBlock: Weight = 100_000_000_000;
+	pub const MaxTaskWeight: Weight = 10_000_000;
+	pub const MaxQueuedTasks: u32 = 3;
+	pub const MaxRetries: u32 = 2;
+	pub const RetryDelay: u64 = 10;
 );
 
 impl module_idle_scheduler::Config for Runtime {
@@ -73,6 +79,11 @@ impl module_idle_scheduler::Config for Runtime {
 	type WeightInfo = ();
 	type Task = ScheduledTasks;
 	type MinimumWeightRemainInBlock = MinimumWeightRemainInBlock;
+	type MaxTaskWeight = MaxTaskWeight;
+	type MaxQueuedTasks = MaxQueuedTasks;
+	type MaxRetries = MaxRetries;
+	type RetryDelay = RetryDelay;
+	type ScheduleOrigin = EnsureRoot<AccountId>;
 }
 
 // Mock dispatachable tasks
@@ -91,10 +102,28 @@ impl DispatchableTask for BalancesTask {
 	}
 }
 
+#[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo)]
+pub enum FailingTask {
+	#[codec(index = 0)]
+	OnIdle,
+	#[codec(index = 1)]
+	Abort,
+}
+impl DispatchableTask for FailingTask {
+	fn dispatch(self, _weight: Weight) -> TaskResult {
+		TaskResult {
+			result: Err(DispatchError::Other("task failed")),
+			used_weight: BASE_WEIGHT,
+			finished: self == FailingTask::Abort,
+		}
+	}
+}
+
 define_combined_task! {
 	#[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo)]
 	pub enum ScheduledTasks {
 		BalancesTask(BalancesTask),
+		FailingTask(FailingTask),
 	}
 }
 
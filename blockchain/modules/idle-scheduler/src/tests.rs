@@ -24,7 +24,8 @@
 
 use super::*;
 use crate::mock::{IdleScheduler, *};
-use frame_support::assert_ok;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::BadOrigin;
 
 // Can schedule tasks
 #[test]
@@ -93,3 +94,159 @@ fn can_increment_next_task_id() {
 		assert_eq!(NextTaskId::<Runtime>::get(), 1);
 	});
 }
+
+#[test]
+fn dispatches_tasks_by_priority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(IdleScheduler::schedule_task_with(
+			Origin::root(),
+			ScheduledTasks::BalancesTask(BalancesTask::OnIdle),
+			TaskPriority::Low,
+			BASE_WEIGHT
+		));
+		assert_ok!(IdleScheduler::schedule_task(
+			Origin::root(),
+			ScheduledTasks::BalancesTask(BalancesTask::OnIdle)
+		));
+		assert_ok!(IdleScheduler::schedule_task_with(
+			Origin::root(),
+			ScheduledTasks::BalancesTask(BalancesTask::OnIdle),
+			TaskPriority::High,
+			BASE_WEIGHT
+		));
+		assert_eq!(IdleScheduler::task_queues(TaskPriority::Normal), vec![1]);
+
+		// Given enough weights for only 1 task
+		assert_eq!(IdleScheduler::on_idle(1, 100_001_000_000), BASE_WEIGHT);
+		assert_eq!(Tasks::<Runtime>::get(2), None);
+		assert_eq!(TaskInfos::<Runtime>::get(2), None);
+		assert!(IdleScheduler::task_queues(TaskPriority::High).is_empty());
+		assert!(Tasks::<Runtime>::get(0).is_some());
+		assert!(Tasks::<Runtime>::get(1).is_some());
+
+		IdleScheduler::on_idle(1, 100_001_000_000);
+		assert_eq!(Tasks::<Runtime>::get(1), None);
+		assert!(Tasks::<Runtime>::get(0).is_some());
+
+		IdleScheduler::on_idle(1, 100_001_000_000);
+		assert_eq!(Tasks::<Runtime>::get(0), None);
+		assert!(IdleScheduler::task_queues(TaskPriority::Low).is_empty());
+	});
+}
+
+#[test]
+fn tasks_get_at_most_their_max_weight() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			IdleScheduler::schedule_task_with(
+				Origin::root(),
+				ScheduledTasks::BalancesTask(BalancesTask::OnIdle),
+				TaskPriority::Normal,
+				0
+			),
+			Error::<Runtime>::InvalidTaskWeight
+		);
+		assert_noop!(
+			IdleScheduler::schedule_task_with(
+				Origin::root(),
+				ScheduledTasks::BalancesTask(BalancesTask::OnIdle),
+				TaskPriority::Normal,
+				MaxTaskWeight::get() + 1
+			),
+			Error::<Runtime>::InvalidTaskWeight
+		);
+
+		// BalancesTask only finishes when given at least BASE_WEIGHT
+		assert_ok!(IdleScheduler::schedule_task_with(
+			Origin::root(),
+			ScheduledTasks::BalancesTask(BalancesTask::OnIdle),
+			TaskPriority::Normal,
+			BASE_WEIGHT - 1
+		));
+		IdleScheduler::on_idle(1, 100_010_000_000);
+		assert!(Tasks::<Runtime>::get(0).is_some());
+		assert_eq!(IdleScheduler::task_queues(TaskPriority::Normal), vec![0]);
+	});
+}
+
+#[test]
+fn queues_are_bounded() {
+	ExtBuilder::default().build().execute_with(|| {
+		for _ in 0..3 {
+			assert_ok!(IdleScheduler::schedule_task(
+				Origin::root(),
+				ScheduledTasks::BalancesTask(BalancesTask::OnIdle)
+			));
+		}
+		assert_noop!(
+			IdleScheduler::schedule_task(Origin::root(), ScheduledTasks::BalancesTask(BalancesTask::OnIdle)),
+			Error::<Runtime>::QueueFull
+		);
+		assert_ok!(IdleScheduler::schedule_task_with(
+			Origin::root(),
+			ScheduledTasks::BalancesTask(BalancesTask::OnIdle),
+			TaskPriority::High,
+			BASE_WEIGHT
+		));
+		assert_noop!(
+			IdleScheduler::schedule_task(Origin::signed(1), ScheduledTasks::BalancesTask(BalancesTask::OnIdle)),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn failed_tasks_are_retried_then_dropped() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(IdleScheduler::schedule_task(
+			Origin::root(),
+			ScheduledTasks::FailingTask(FailingTask::OnIdle)
+		));
+
+		IdleScheduler::on_idle(1, 100_010_000_000);
+		System::assert_last_event(mock::Event::IdleScheduler(crate::Event::TaskRetryScheduled {
+			task_id: 0,
+			failures: 1,
+			next_attempt: 11,
+		}));
+
+		// not retried before the back-off ends
+		assert_eq!(IdleScheduler::on_idle(10, 100_010_000_000), 0);
+		assert_eq!(IdleScheduler::task_infos(0).unwrap().failures, 1);
+
+		IdleScheduler::on_idle(11, 100_010_000_000);
+		System::assert_last_event(mock::Event::IdleScheduler(crate::Event::TaskRetryScheduled {
+			task_id: 0,
+			failures: 2,
+			next_attempt: 31,
+		}));
+
+		IdleScheduler::on_idle(31, 100_010_000_000);
+		System::assert_last_event(mock::Event::IdleScheduler(crate::Event::TaskDropped {
+			task_id: 0,
+			failures: 3,
+		}));
+		assert_eq!(Tasks::<Runtime>::get(0), None);
+		assert_eq!(TaskInfos::<Runtime>::get(0), None);
+		assert!(IdleScheduler::task_queues(TaskPriority::Normal).is_empty());
+	});
+}
+
+#[test]
+fn finished_failed_tasks_are_not_retried() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(IdleScheduler::schedule_task(
+			Origin::root(),
+			ScheduledTasks::FailingTask(FailingTask::Abort)
+		));
+
+		IdleScheduler::on_idle(1, 100_010_000_000);
+		System::assert_last_event(mock::Event::IdleScheduler(crate::Event::TaskDispatched {
+			task_id: 0,
+			result: Err(DispatchError::Other("task failed")),
+		}));
+		assert_eq!(Tasks::<Runtime>::get(0), None);
+		assert_eq!(TaskInfos::<Runtime>::get(0), None);
+		assert!(IdleScheduler::task_queues(TaskPriority::Normal).is_empty());
+	});
+}
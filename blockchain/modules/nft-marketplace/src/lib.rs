@@ -0,0 +1,613 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # NFT Marketplace Module
+//!
+//! ## Overview
+//!
+//! The NFT marketplace module trades the tokens of `module_nft` for the
+//! multi-currencies of `module_currencies`. It supports fixed-price
+//! listings, offers with escrowed funds and English auctions with a reserve
+//! price, which are extended when bids are placed close to their end.
+//!
+//! Listed and auctioned tokens, as well as offered and bid funds, are held by
+//! the marketplace account until the trade is settled or cancelled. Only
+//! tokens of classes with `ClassProperty::Transferable` can be traded, and the
+//! royalty of the class is paid on every sale.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, require_transactional, traits::Contains, transactional, PalletId};
+use frame_system::pallet_prelude::*;
+use module_nft::{ClassIdOf, ClassProperty, TokenIdOf};
+use orml_traits::{MultiCurrency, NFT};
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	traits::{AccountIdConversion, Saturating, StaticLookup, Zero},
+	DispatchResult, Permill, RuntimeDebug,
+};
+use sp_std::prelude::*;
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// A fixed-price listing of a token.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct Listing<AccountId> {
+	/// The owner of the listed token
+	pub seller: AccountId,
+	/// The currency the token is sold for
+	pub currency_id: CurrencyId,
+	/// The price of the token
+	pub price: Balance,
+}
+
+/// An offer for a token, the offered amount is escrowed.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct Offer {
+	/// The currency of the offer
+	pub currency_id: CurrencyId,
+	/// The offered amount
+	pub amount: Balance,
+}
+
+/// An English auction of a token.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct Auction<AccountId, BlockNumber> {
+	/// The owner of the auctioned token
+	pub seller: AccountId,
+	/// The currency of the bids
+	pub currency_id: CurrencyId,
+	/// The minimum amount of the first bid
+	pub reserve_price: Balance,
+	/// The highest bidder and bid, the bid is escrowed
+	pub bid: Option<(AccountId, Balance)>,
+	/// The block number at which the auction ends
+	pub end: BlockNumber,
+}
+
+pub type TokenOf<T> = (ClassIdOf<T>, TokenIdOf<T>);
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + module_nft::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The marketplace's module id, keeps the escrowed tokens and funds.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The minimum duration of an auction.
+		#[pallet::constant]
+		type MinimumAuctionDuration: Get<Self::BlockNumber>;
+
+		/// The maximum duration of an auction.
+		#[pallet::constant]
+		type MaximumAuctionDuration: Get<Self::BlockNumber>;
+
+		/// A bid placed within this period before the end of an auction
+		/// extends the auction to this period after the bid.
+		#[pallet::constant]
+		type AuctionExtendPeriod: Get<Self::BlockNumber>;
+
+		/// The minimum increment of a bid over the highest bid, as a share of
+		/// the highest bid.
+		#[pallet::constant]
+		type MinimumIncrementSize: Get<Permill>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Token(ClassId, TokenId) not found
+		TokenIdNotFound,
+		/// The operator is not the owner of the token or the seller
+		NoPermission,
+		/// Token is not transferable
+		NonTransferable,
+		/// The price or amount is zero
+		InvalidPrice,
+		/// The token is already listed or auctioned
+		AlreadyOnSale,
+		/// The listing does not exist
+		ListingNotFound,
+		/// Cannot trade a token with yourself
+		CannotTradeWithSelf,
+		/// The caller already has an offer for the token
+		OfferAlreadyExists,
+		/// The offer does not exist
+		OfferNotFound,
+		/// The auction does not exist
+		AuctionNotFound,
+		/// The duration of the auction is out of bounds
+		InvalidDuration,
+		/// The auction has ended
+		AuctionEnded,
+		/// The auction already has bids
+		AuctionHasBids,
+		/// The bid is below the reserve price or the minimum increment
+		BidTooLow,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId", ClassIdOf<T> = "ClassId", TokenIdOf<T> = "TokenId")]
+	pub enum Event<T: Config> {
+		/// Listed a token at a fixed price. \[seller, class_id, token_id,
+		/// currency_id, price\]
+		Listed(T::AccountId, ClassIdOf<T>, TokenIdOf<T>, CurrencyId, Balance),
+		/// Cancelled a listing. \[seller, class_id, token_id\]
+		ListingCancelled(T::AccountId, ClassIdOf<T>, TokenIdOf<T>),
+		/// Made an offer for a token. \[who, class_id, token_id, currency_id,
+		/// amount\]
+		OfferMade(T::AccountId, ClassIdOf<T>, TokenIdOf<T>, CurrencyId, Balance),
+		/// Cancelled an offer. \[who, class_id, token_id\]
+		OfferCancelled(T::AccountId, ClassIdOf<T>, TokenIdOf<T>),
+		/// Created an auction. \[seller, class_id, token_id, currency_id,
+		/// reserve_price, end\]
+		AuctionCreated(
+			T::AccountId,
+			ClassIdOf<T>,
+			TokenIdOf<T>,
+			CurrencyId,
+			Balance,
+			T::BlockNumber,
+		),
+		/// Placed a bid in an auction. \[bidder, class_id, token_id, amount,
+		/// end\]
+		BidPlaced(T::AccountId, ClassIdOf<T>, TokenIdOf<T>, Balance, T::BlockNumber),
+		/// Cancelled an auction without bids. \[seller, class_id, token_id\]
+		AuctionCancelled(T::AccountId, ClassIdOf<T>, TokenIdOf<T>),
+		/// An auction ended without bids and the token is returned to the
+		/// seller. \[seller, class_id, token_id\]
+		AuctionUnsold(T::AccountId, ClassIdOf<T>, TokenIdOf<T>),
+		/// Sold a token by listing, offer or auction. \[seller, buyer,
+		/// class_id, token_id, currency_id, price, royalty\]
+		Sold(
+			T::AccountId,
+			T::AccountId,
+			ClassIdOf<T>,
+			TokenIdOf<T>,
+			CurrencyId,
+			Balance,
+			Balance,
+		),
+	}
+
+	/// The fixed-price listings.
+	///
+	/// Listings: map (ClassId, TokenId) => Option<Listing>
+	#[pallet::storage]
+	#[pallet::getter(fn listings)]
+	pub type Listings<T: Config> = StorageMap<_, Twox64Concat, TokenOf<T>, Listing<T::AccountId>, OptionQuery>;
+
+	/// The offers for tokens.
+	///
+	/// Offers: double_map (ClassId, TokenId), AccountId => Option<Offer>
+	#[pallet::storage]
+	#[pallet::getter(fn offers)]
+	pub type Offers<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, TokenOf<T>, Twox64Concat, T::AccountId, Offer, OptionQuery>;
+
+	/// The ongoing auctions.
+	///
+	/// Auctions: map (ClassId, TokenId) => Option<Auction>
+	#[pallet::storage]
+	#[pallet::getter(fn auctions)]
+	pub type Auctions<T: Config> =
+		StorageMap<_, Twox64Concat, TokenOf<T>, Auction<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+	/// Index auctions by end time.
+	///
+	/// AuctionEndTime: double_map BlockNumber, (ClassId, TokenId) => Option<()>
+	#[pallet::storage]
+	pub type AuctionEndTime<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, TokenOf<T>, (), OptionQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Settle the auctions which end at `now`.
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let tokens = AuctionEndTime::<T>::drain_prefix(now)
+				.map(|(token, _)| token)
+				.collect::<Vec<_>>();
+			for token in tokens.iter() {
+				if Self::settle_auction(*token).is_err() {
+					// refund the bid and keep the token escrowed, the seller can cancel the
+					// auction once the token is transferable again.
+					let _ = Self::refund_bid(*token);
+				}
+			}
+			<T as Config>::WeightInfo::on_initialize(tokens.len() as u32)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// List a token at a fixed price, the token is escrowed until it is
+		/// bought or the listing is cancelled.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `currency_id`: the currency the token is sold for
+		/// - `price`: the price of the token
+		#[pallet::weight(<T as Config>::WeightInfo::list())]
+		#[transactional]
+		pub fn list(
+			origin: OriginFor<T>,
+			token: TokenOf<T>,
+			currency_id: CurrencyId,
+			#[pallet::compact] price: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!price.is_zero(), Error::<T>::InvalidPrice);
+			Self::escrow_token(&who, token)?;
+
+			Listings::<T>::insert(
+				token,
+				Listing {
+					seller: who.clone(),
+					currency_id,
+					price,
+				},
+			);
+			Self::deposit_event(Event::Listed(who, token.0, token.1, currency_id, price));
+			Ok(())
+		}
+
+		/// Cancel a listing and return the token to the seller.
+		///
+		/// - `token`: (class_id, token_id)
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_listing())]
+		#[transactional]
+		pub fn cancel_listing(origin: OriginFor<T>, token: TokenOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let listing = Self::listings(token).ok_or(Error::<T>::ListingNotFound)?;
+			ensure!(who == listing.seller, Error::<T>::NoPermission);
+
+			Listings::<T>::remove(token);
+			Self::release_token(&who, token)?;
+			Self::deposit_event(Event::ListingCancelled(who, token.0, token.1));
+			Ok(())
+		}
+
+		/// Buy a listed token at its price.
+		///
+		/// - `token`: (class_id, token_id)
+		#[pallet::weight(<T as Config>::WeightInfo::buy())]
+		#[transactional]
+		pub fn buy(origin: OriginFor<T>, token: TokenOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let listing = Self::listings(token).ok_or(Error::<T>::ListingNotFound)?;
+			ensure!(who != listing.seller, Error::<T>::CannotTradeWithSelf);
+
+			Listings::<T>::remove(token);
+			let royalty = Self::pay(&who, &listing.seller, token, listing.currency_id, listing.price)?;
+			Self::release_token(&who, token)?;
+			Self::deposit_event(Event::Sold(
+				listing.seller,
+				who,
+				token.0,
+				token.1,
+				listing.currency_id,
+				listing.price,
+				royalty,
+			));
+			Ok(())
+		}
+
+		/// Make an offer for a token, the offered amount is escrowed until
+		/// the offer is accepted or cancelled.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `currency_id`: the currency of the offer
+		/// - `amount`: the offered amount
+		#[pallet::weight(<T as Config>::WeightInfo::make_offer())]
+		#[transactional]
+		pub fn make_offer(
+			origin: OriginFor<T>,
+			token: TokenOf<T>,
+			currency_id: CurrencyId,
+			#[pallet::compact] amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::InvalidPrice);
+			let owner = <module_nft::Pallet<T> as NFT<T::AccountId>>::owner(token).ok_or(Error::<T>::TokenIdNotFound)?;
+			ensure!(who != owner, Error::<T>::CannotTradeWithSelf);
+			ensure!(
+				!Offers::<T>::contains_key(token, &who),
+				Error::<T>::OfferAlreadyExists
+			);
+
+			<T as module_nft::Config>::MultiCurrency::transfer(currency_id, &who, &Self::account_id(), amount)?;
+			Offers::<T>::insert(token, &who, Offer { currency_id, amount });
+			Self::deposit_event(Event::OfferMade(who, token.0, token.1, currency_id, amount));
+			Ok(())
+		}
+
+		/// Cancel an offer and refund the escrowed amount.
+		///
+		/// - `token`: (class_id, token_id)
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_offer())]
+		#[transactional]
+		pub fn cancel_offer(origin: OriginFor<T>, token: TokenOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let offer = Offers::<T>::take(token, &who).ok_or(Error::<T>::OfferNotFound)?;
+
+			<T as module_nft::Config>::MultiCurrency::transfer(offer.currency_id, &Self::account_id(), &who, offer.amount)?;
+			Self::deposit_event(Event::OfferCancelled(who, token.0, token.1));
+			Ok(())
+		}
+
+		/// Accept an offer for a token owned by the caller, the token is
+		/// transferred to the offerer and the escrowed amount is paid to the
+		/// caller.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `who`: the offerer
+		#[pallet::weight(<T as Config>::WeightInfo::accept_offer())]
+		#[transactional]
+		pub fn accept_offer(
+			origin: OriginFor<T>,
+			token: TokenOf<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let offer = Offers::<T>::take(token, &who).ok_or(Error::<T>::OfferNotFound)?;
+			ensure!(
+				<module_nft::Pallet<T> as NFT<T::AccountId>>::owner(token) == Some(owner.clone()),
+				Error::<T>::NoPermission
+			);
+
+			let royalty = Self::pay(&Self::account_id(), &owner, token, offer.currency_id, offer.amount)?;
+			<module_nft::Pallet<T> as NFT<T::AccountId>>::transfer(&owner, &who, token)?;
+			Self::deposit_event(Event::Sold(
+				owner,
+				who,
+				token.0,
+				token.1,
+				offer.currency_id,
+				offer.amount,
+				royalty,
+			));
+			Ok(())
+		}
+
+		/// Create an English auction of a token, the token is escrowed until
+		/// the auction ends or is cancelled.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `currency_id`: the currency of the bids
+		/// - `reserve_price`: the minimum amount of the first bid
+		/// - `duration`: the number of blocks the auction lasts
+		#[pallet::weight(<T as Config>::WeightInfo::create_auction())]
+		#[transactional]
+		pub fn create_auction(
+			origin: OriginFor<T>,
+			token: TokenOf<T>,
+			currency_id: CurrencyId,
+			#[pallet::compact] reserve_price: Balance,
+			duration: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!reserve_price.is_zero(), Error::<T>::InvalidPrice);
+			ensure!(
+				duration >= T::MinimumAuctionDuration::get() && duration <= T::MaximumAuctionDuration::get(),
+				Error::<T>::InvalidDuration
+			);
+			Self::escrow_token(&who, token)?;
+
+			let end = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+			Auctions::<T>::insert(
+				token,
+				Auction {
+					seller: who.clone(),
+					currency_id,
+					reserve_price,
+					bid: None,
+					end,
+				},
+			);
+			AuctionEndTime::<T>::insert(end, token, ());
+			Self::deposit_event(Event::AuctionCreated(
+				who,
+				token.0,
+				token.1,
+				currency_id,
+				reserve_price,
+				end,
+			));
+			Ok(())
+		}
+
+		/// Place a bid in an auction, the bid is escrowed and the previous
+		/// highest bid is refunded. A bid placed within `AuctionExtendPeriod`
+		/// before the end of the auction extends it.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `amount`: the amount of the bid
+		#[pallet::weight(<T as Config>::WeightInfo::bid())]
+		#[transactional]
+		pub fn bid(origin: OriginFor<T>, token: TokenOf<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Auctions::<T>::try_mutate_exists(token, |maybe_auction| -> DispatchResult {
+				let auction = maybe_auction.as_mut().ok_or(Error::<T>::AuctionNotFound)?;
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(now < auction.end, Error::<T>::AuctionEnded);
+				ensure!(who != auction.seller, Error::<T>::CannotTradeWithSelf);
+
+				let minimum_bid = match &auction.bid {
+					Some((_, highest)) => highest
+						.saturating_add(T::MinimumIncrementSize::get().mul_ceil(*highest))
+						.max(highest.saturating_add(1)),
+					None => auction.reserve_price,
+				};
+				ensure!(amount >= minimum_bid, Error::<T>::BidTooLow);
+
+				let account_id = Self::account_id();
+				<T as module_nft::Config>::MultiCurrency::transfer(auction.currency_id, &who, &account_id, amount)?;
+				if let Some((bidder, highest)) = auction.bid.take() {
+					<T as module_nft::Config>::MultiCurrency::transfer(
+						auction.currency_id,
+						&account_id,
+						&bidder,
+						highest,
+					)?;
+				}
+				auction.bid = Some((who.clone(), amount));
+
+				let extended_end = now.saturating_add(T::AuctionExtendPeriod::get());
+				if extended_end > auction.end {
+					AuctionEndTime::<T>::remove(auction.end, token);
+					AuctionEndTime::<T>::insert(extended_end, token, ());
+					auction.end = extended_end;
+				}
+
+				Self::deposit_event(Event::BidPlaced(who.clone(), token.0, token.1, amount, auction.end));
+				Ok(())
+			})
+		}
+
+		/// Cancel an auction without bids and return the token to the
+		/// seller.
+		///
+		/// - `token`: (class_id, token_id)
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_auction())]
+		#[transactional]
+		pub fn cancel_auction(origin: OriginFor<T>, token: TokenOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let auction = Self::auctions(token).ok_or(Error::<T>::AuctionNotFound)?;
+			ensure!(who == auction.seller, Error::<T>::NoPermission);
+			ensure!(auction.bid.is_none(), Error::<T>::AuctionHasBids);
+
+			Auctions::<T>::remove(token);
+			AuctionEndTime::<T>::remove(auction.end, token);
+			Self::release_token(&who, token)?;
+			Self::deposit_event(Event::AuctionCancelled(who, token.0, token.1));
+			Ok(())
+		}
+	}
+}
+
+/// The marketplace account holds the listed and auctioned tokens in custody.
+impl<T: Config> Contains<T::AccountId> for Pallet<T> {
+	fn contains(who: &T::AccountId) -> bool {
+		*who == Self::account_id()
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn account_id() -> T::AccountId {
+		<T as Config>::PalletId::get().into_account()
+	}
+
+	/// Move a token of `who` into the escrow of the marketplace.
+	#[require_transactional]
+	fn escrow_token(who: &T::AccountId, token: TokenOf<T>) -> DispatchResult {
+		let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::TokenIdNotFound)?;
+		ensure!(
+			class_info.data.properties.0.contains(ClassProperty::Transferable),
+			Error::<T>::NonTransferable
+		);
+		let owner = <module_nft::Pallet<T> as NFT<T::AccountId>>::owner(token).ok_or(Error::<T>::TokenIdNotFound)?;
+		ensure!(*who == owner, Error::<T>::NoPermission);
+
+		<module_nft::Pallet<T> as NFT<T::AccountId>>::transfer(who, &Self::account_id(), token)
+	}
+
+	/// Move an escrowed token to `to`.
+	#[require_transactional]
+	fn release_token(to: &T::AccountId, token: TokenOf<T>) -> DispatchResult {
+		<module_nft::Pallet<T> as NFT<T::AccountId>>::transfer(&Self::account_id(), to, token)
+	}
+
+	/// Pay `price` from `payer` to `seller`, with the royalty of the class of
+	/// the token paid to its recipient. Returns the royalty.
+	#[require_transactional]
+	fn pay(
+		payer: &T::AccountId,
+		seller: &T::AccountId,
+		token: TokenOf<T>,
+		currency_id: CurrencyId,
+		price: Balance,
+	) -> Result<Balance, DispatchError> {
+		module_nft::Pallet::<T>::pay_with_royalty(token.0, price, seller, |recipient, amount| {
+			<T as module_nft::Config>::MultiCurrency::transfer(currency_id, payer, recipient, amount)
+		})
+	}
+
+	/// Settle an ended auction, the token goes to the highest bidder and the
+	/// escrowed bid to the seller, or the token returns to the seller if
+	/// there are no bids.
+	#[transactional]
+	fn settle_auction(token: TokenOf<T>) -> DispatchResult {
+		let auction = Auctions::<T>::take(token).ok_or(Error::<T>::AuctionNotFound)?;
+		match auction.bid {
+			Some((bidder, amount)) => {
+				let royalty = Self::pay(&Self::account_id(), &auction.seller, token, auction.currency_id, amount)?;
+				Self::release_token(&bidder, token)?;
+				Self::deposit_event(Event::Sold(
+					auction.seller,
+					bidder,
+					token.0,
+					token.1,
+					auction.currency_id,
+					amount,
+					royalty,
+				));
+			}
+			None => {
+				Self::release_token(&auction.seller, token)?;
+				Self::deposit_event(Event::AuctionUnsold(auction.seller, token.0, token.1));
+			}
+		}
+		Ok(())
+	}
+
+	/// Refund the highest bid of an auction.
+	#[transactional]
+	fn refund_bid(token: TokenOf<T>) -> DispatchResult {
+		Auctions::<T>::try_mutate_exists(token, |maybe_auction| -> DispatchResult {
+			let auction = maybe_auction.as_mut().ok_or(Error::<T>::AuctionNotFound)?;
+			if let Some((bidder, amount)) = auction.bid.take() {
+				<T as module_nft::Config>::MultiCurrency::transfer(
+					auction.currency_id,
+					&Self::account_id(),
+					&bidder,
+					amount,
+				)?;
+			}
+			Ok(())
+		})
+	}
+}
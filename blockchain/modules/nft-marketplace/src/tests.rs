@@ -0,0 +1,394 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the NFT marketplace module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+use mock::{Event, *};
+use module_nft::{Properties, Royalty};
+
+fn free_balance(who: &AccountId) -> Balance {
+	Balances::free_balance(who)
+}
+
+fn class_id_account() -> AccountId {
+	<Runtime as module_nft::Config>::PalletId::get().into_sub_account(CLASS_ID)
+}
+
+fn owner() -> Option<AccountId> {
+	<NFTModule as NFT<AccountId>>::owner(TOKEN)
+}
+
+fn setup_token(properties: Properties) {
+	assert_ok!(NFTModule::create_class(
+		Origin::signed(ALICE),
+		vec![1],
+		properties,
+		Default::default(),
+	));
+	assert_ok!(Balances::deposit_into_existing(&class_id_account(), 1000));
+	assert_ok!(NFTModule::mint(
+		Origin::signed(class_id_account()),
+		BOB,
+		CLASS_ID,
+		vec![1],
+		Default::default(),
+		1
+	));
+}
+
+fn setup_transferable_token() {
+	setup_token(Properties(
+		ClassProperty::Transferable | ClassProperty::ClassPropertiesMutable,
+	));
+}
+
+#[test]
+fn list_and_buy_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_transferable_token();
+
+		assert_ok!(NFTMarketplace::list(Origin::signed(BOB), TOKEN, NATIVE_CURRENCY_ID, 1000));
+		System::assert_last_event(Event::NFTMarketplace(crate::Event::Listed(
+			BOB,
+			CLASS_ID,
+			TOKEN_ID,
+			NATIVE_CURRENCY_ID,
+			1000,
+		)));
+		assert_eq!(
+			NFTMarketplace::listings(TOKEN),
+			Some(Listing {
+				seller: BOB,
+				currency_id: NATIVE_CURRENCY_ID,
+				price: 1000,
+			})
+		);
+		assert_eq!(owner(), Some(NFTMarketplace::account_id()));
+
+		let bob_free = free_balance(&BOB);
+		let charlie_free = free_balance(&CHARLIE);
+		assert_ok!(NFTMarketplace::buy(Origin::signed(CHARLIE), TOKEN));
+		System::assert_last_event(Event::NFTMarketplace(crate::Event::Sold(
+			BOB,
+			CHARLIE,
+			CLASS_ID,
+			TOKEN_ID,
+			NATIVE_CURRENCY_ID,
+			1000,
+			0,
+		)));
+		assert_eq!(NFTMarketplace::listings(TOKEN), None);
+		assert_eq!(owner(), Some(CHARLIE));
+		assert_eq!(free_balance(&BOB), bob_free + 1000);
+		assert_eq!(free_balance(&CHARLIE), charlie_free - 1000);
+	});
+}
+
+#[test]
+fn buy_should_pay_royalty() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_transferable_token();
+		assert_ok!(NFTModule::set_class_royalty(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			Some(Royalty {
+				recipient: ALICE,
+				rate: Permill::from_percent(10),
+			})
+		));
+		assert_ok!(NFTMarketplace::list(Origin::signed(BOB), TOKEN, NATIVE_CURRENCY_ID, 1000));
+
+		let alice_free = free_balance(&ALICE);
+		let bob_free = free_balance(&BOB);
+		assert_ok!(NFTMarketplace::buy(Origin::signed(CHARLIE), TOKEN));
+		System::assert_last_event(Event::NFTMarketplace(crate::Event::Sold(
+			BOB,
+			CHARLIE,
+			CLASS_ID,
+			TOKEN_ID,
+			NATIVE_CURRENCY_ID,
+			1000,
+			100,
+		)));
+		assert_eq!(free_balance(&ALICE), alice_free + 100);
+		assert_eq!(free_balance(&BOB), bob_free + 900);
+	});
+}
+
+#[test]
+fn list_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_token(Properties(ClassProperty::Mintable.into()));
+
+		assert_noop!(
+			NFTMarketplace::list(Origin::signed(BOB), TOKEN, NATIVE_CURRENCY_ID, 1000),
+			Error::<Runtime>::NonTransferable
+		);
+		assert_noop!(
+			NFTMarketplace::list(Origin::signed(BOB), TOKEN, NATIVE_CURRENCY_ID, 0),
+			Error::<Runtime>::InvalidPrice
+		);
+		assert_noop!(
+			NFTMarketplace::list(Origin::signed(BOB), (CLASS_ID + 1, TOKEN_ID), NATIVE_CURRENCY_ID, 1000),
+			Error::<Runtime>::TokenIdNotFound
+		);
+	});
+}
+
+#[test]
+fn cancel_listing_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_transferable_token();
+		assert_ok!(NFTMarketplace::list(Origin::signed(BOB), TOKEN, NATIVE_CURRENCY_ID, 1000));
+
+		assert_noop!(
+			NFTMarketplace::list(Origin::signed(BOB), TOKEN, NATIVE_CURRENCY_ID, 1000),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTMarketplace::cancel_listing(Origin::signed(CHARLIE), TOKEN),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTMarketplace::buy(Origin::signed(BOB), TOKEN),
+			Error::<Runtime>::CannotTradeWithSelf
+		);
+
+		assert_ok!(NFTMarketplace::cancel_listing(Origin::signed(BOB), TOKEN));
+		System::assert_last_event(Event::NFTMarketplace(crate::Event::ListingCancelled(
+			BOB, CLASS_ID, TOKEN_ID,
+		)));
+		assert_eq!(NFTMarketplace::listings(TOKEN), None);
+		assert_eq!(owner(), Some(BOB));
+		assert_noop!(
+			NFTMarketplace::buy(Origin::signed(CHARLIE), TOKEN),
+			Error::<Runtime>::ListingNotFound
+		);
+	});
+}
+
+#[test]
+fn escrowed_token_cannot_be_revoked() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_token(Properties(ClassProperty::Transferable | ClassProperty::Revocable));
+		assert_ok!(NFTMarketplace::list(Origin::signed(BOB), TOKEN, NATIVE_CURRENCY_ID, 1000));
+
+		assert_noop!(
+			NFTModule::revoke(Origin::signed(class_id_account()), TOKEN),
+			module_nft::Error::<Runtime>::TokenInCustody
+		);
+
+		assert_ok!(NFTMarketplace::cancel_listing(Origin::signed(BOB), TOKEN));
+		assert_ok!(NFTModule::revoke(Origin::signed(class_id_account()), TOKEN));
+		assert_eq!(owner(), None);
+	});
+}
+
+#[test]
+fn offer_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_transferable_token();
+		let escrow = NFTMarketplace::account_id();
+
+		assert_noop!(
+			NFTMarketplace::make_offer(Origin::signed(BOB), TOKEN, NATIVE_CURRENCY_ID, 500),
+			Error::<Runtime>::CannotTradeWithSelf
+		);
+		let charlie_free = free_balance(&CHARLIE);
+		assert_ok!(NFTMarketplace::make_offer(
+			Origin::signed(CHARLIE),
+			TOKEN,
+			NATIVE_CURRENCY_ID,
+			500
+		));
+		System::assert_last_event(Event::NFTMarketplace(crate::Event::OfferMade(
+			CHARLIE,
+			CLASS_ID,
+			TOKEN_ID,
+			NATIVE_CURRENCY_ID,
+			500,
+		)));
+		assert_eq!(free_balance(&CHARLIE), charlie_free - 500);
+		assert_eq!(free_balance(&escrow), 500);
+		assert_noop!(
+			NFTMarketplace::make_offer(Origin::signed(CHARLIE), TOKEN, NATIVE_CURRENCY_ID, 600),
+			Error::<Runtime>::OfferAlreadyExists
+		);
+		assert_ok!(NFTMarketplace::make_offer(Origin::signed(ALICE), TOKEN, NATIVE_CURRENCY_ID, 400));
+
+		assert_noop!(
+			NFTMarketplace::accept_offer(Origin::signed(ALICE), TOKEN, CHARLIE),
+			Error::<Runtime>::NoPermission
+		);
+		let bob_free = free_balance(&BOB);
+		assert_ok!(NFTMarketplace::accept_offer(Origin::signed(BOB), TOKEN, CHARLIE));
+		System::assert_last_event(Event::NFTMarketplace(crate::Event::Sold(
+			BOB,
+			CHARLIE,
+			CLASS_ID,
+			TOKEN_ID,
+			NATIVE_CURRENCY_ID,
+			500,
+			0,
+		)));
+		assert_eq!(owner(), Some(CHARLIE));
+		assert_eq!(free_balance(&BOB), bob_free + 500);
+		assert_eq!(NFTMarketplace::offers(TOKEN, CHARLIE), None);
+
+		let alice_free = free_balance(&ALICE);
+		assert_ok!(NFTMarketplace::cancel_offer(Origin::signed(ALICE), TOKEN));
+		System::assert_last_event(Event::NFTMarketplace(crate::Event::OfferCancelled(
+			ALICE, CLASS_ID, TOKEN_ID,
+		)));
+		assert_eq!(free_balance(&ALICE), alice_free + 400);
+		assert_eq!(free_balance(&escrow), 0);
+		assert_noop!(
+			NFTMarketplace::cancel_offer(Origin::signed(ALICE), TOKEN),
+			Error::<Runtime>::OfferNotFound
+		);
+	});
+}
+
+#[test]
+fn auction_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_transferable_token();
+
+		assert_noop!(
+			NFTMarketplace::create_auction(Origin::signed(BOB), TOKEN, NATIVE_CURRENCY_ID, 100, 5),
+			Error::<Runtime>::InvalidDuration
+		);
+		assert_ok!(NFTMarketplace::create_auction(
+			Origin::signed(BOB),
+			TOKEN,
+			NATIVE_CURRENCY_ID,
+			100,
+			20
+		));
+		System::assert_last_event(Event::NFTMarketplace(crate::Event::AuctionCreated(
+			BOB,
+			CLASS_ID,
+			TOKEN_ID,
+			NATIVE_CURRENCY_ID,
+			100,
+			21,
+		)));
+		assert_eq!(owner(), Some(NFTMarketplace::account_id()));
+
+		assert_noop!(
+			NFTMarketplace::bid(Origin::signed(BOB), TOKEN, 100),
+			Error::<Runtime>::CannotTradeWithSelf
+		);
+		assert_noop!(
+			NFTMarketplace::bid(Origin::signed(ALICE), TOKEN, 99),
+			Error::<Runtime>::BidTooLow
+		);
+		let alice_free = free_balance(&ALICE);
+		assert_ok!(NFTMarketplace::bid(Origin::signed(ALICE), TOKEN, 100));
+		assert_eq!(free_balance(&ALICE), alice_free - 100);
+		assert_noop!(
+			NFTMarketplace::cancel_auction(Origin::signed(BOB), TOKEN),
+			Error::<Runtime>::AuctionHasBids
+		);
+
+		assert_noop!(
+			NFTMarketplace::bid(Origin::signed(CHARLIE), TOKEN, 109),
+			Error::<Runtime>::BidTooLow
+		);
+		assert_ok!(NFTMarketplace::bid(Origin::signed(CHARLIE), TOKEN, 110));
+		System::assert_last_event(Event::NFTMarketplace(crate::Event::BidPlaced(
+			CHARLIE, CLASS_ID, TOKEN_ID, 110, 21,
+		)));
+		assert_eq!(free_balance(&ALICE), alice_free);
+
+		// a late bid extends the auction
+		System::set_block_number(18);
+		assert_ok!(NFTMarketplace::bid(Origin::signed(ALICE), TOKEN, 121));
+		System::assert_last_event(Event::NFTMarketplace(crate::Event::BidPlaced(
+			ALICE, CLASS_ID, TOKEN_ID, 121, 23,
+		)));
+
+		NFTMarketplace::on_initialize(21);
+		assert!(NFTMarketplace::auctions(TOKEN).is_some());
+
+		let bob_free = free_balance(&BOB);
+		System::set_block_number(23);
+		assert_noop!(
+			NFTMarketplace::bid(Origin::signed(CHARLIE), TOKEN, 200),
+			Error::<Runtime>::AuctionEnded
+		);
+		NFTMarketplace::on_initialize(23);
+		System::assert_last_event(Event::NFTMarketplace(crate::Event::Sold(
+			BOB,
+			ALICE,
+			CLASS_ID,
+			TOKEN_ID,
+			NATIVE_CURRENCY_ID,
+			121,
+			0,
+		)));
+		assert_eq!(NFTMarketplace::auctions(TOKEN), None);
+		assert_eq!(owner(), Some(ALICE));
+		assert_eq!(free_balance(&BOB), bob_free + 121);
+	});
+}
+
+#[test]
+fn auction_without_bids_should_return_token() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_transferable_token();
+		assert_ok!(NFTMarketplace::create_auction(
+			Origin::signed(BOB),
+			TOKEN,
+			NATIVE_CURRENCY_ID,
+			100,
+			20
+		));
+
+		NFTMarketplace::on_initialize(21);
+		System::assert_last_event(Event::NFTMarketplace(crate::Event::AuctionUnsold(
+			BOB, CLASS_ID, TOKEN_ID,
+		)));
+		assert_eq!(NFTMarketplace::auctions(TOKEN), None);
+		assert_eq!(owner(), Some(BOB));
+
+		assert_ok!(NFTMarketplace::create_auction(
+			Origin::signed(BOB),
+			TOKEN,
+			NATIVE_CURRENCY_ID,
+			100,
+			20
+		));
+		assert_noop!(
+			NFTMarketplace::cancel_auction(Origin::signed(CHARLIE), TOKEN),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTMarketplace::cancel_auction(Origin::signed(BOB), TOKEN));
+		System::assert_last_event(Event::NFTMarketplace(crate::Event::AuctionCancelled(
+			BOB, CLASS_ID, TOKEN_ID,
+		)));
+		assert_eq!(owner(), Some(BOB));
+		assert_eq!(AuctionEndTime::<Runtime>::iter().count(), 0);
+	});
+}
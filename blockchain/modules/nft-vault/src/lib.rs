@@ -0,0 +1,336 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # NFT Vault Module
+//!
+//! ## Overview
+//!
+//! The NFT vault module fractionalizes a token of `module_nft` into a fungible
+//! `CurrencyId::NftShare`, so the token can be co-owned by the holders of the
+//! shares.
+//!
+//! The token is locked in the account of its vault, and the whole supply of
+//! shares is minted to its owner. Anyone can buy the token out by paying the
+//! reserve price for the shares they don't hold, after which the holders
+//! claim the proceeds for their shares. A holder of all the shares can redeem
+//! the token at any time before a buyout.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, traits::Contains, transactional, PalletId};
+use frame_system::pallet_prelude::*;
+use module_nft::{ClassIdOf, ClassProperty, TokenIdOf};
+use orml_traits::{MultiCurrency, NFT};
+use primitives::{Balance, CurrencyId, NftVaultId};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
+	traits::{AccountIdConversion, One, Zero},
+	ArithmeticError, DispatchResult, RuntimeDebug,
+};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// The state of a vault.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub enum VaultState {
+	/// The token is locked in the vault
+	Active,
+	/// The token is bought out, the holders of the shares can claim the
+	/// proceeds
+	BoughtOut,
+}
+
+/// A vault of a fractionalized token.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct Vault<ClassId, TokenId> {
+	/// The fractionalized token
+	pub token: (ClassId, TokenId),
+	/// The total supply of the shares
+	pub supply: Balance,
+	/// The currency of the reserve price
+	pub currency_id: CurrencyId,
+	/// The price to buy the token out
+	pub reserve_price: Balance,
+	/// The state of the vault
+	pub state: VaultState,
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + module_nft::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The vault's module id, the sub-accounts of which keep the locked
+		/// tokens and the proceeds of the buyouts.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The vault does not exist
+		VaultNotFound,
+		/// The supply of the shares or the reserve price is zero
+		InvalidAmount,
+		/// The token of the vault is bought out
+		VaultBoughtOut,
+		/// The token of the vault is not bought out
+		VaultNotBoughtOut,
+		/// The caller does not hold all the shares
+		InsufficientShares,
+		/// The caller holds no shares
+		NoShares,
+		/// The class of the token is revocable
+		RevocableToken,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId", ClassIdOf<T> = "ClassId", TokenIdOf<T> = "TokenId")]
+	pub enum Event<T: Config> {
+		/// Fractionalized a token into shares. \[owner, vault_id, class_id,
+		/// token_id, share_currency_id, supply\]
+		Fractionalized(T::AccountId, NftVaultId, ClassIdOf<T>, TokenIdOf<T>, CurrencyId, Balance),
+		/// Bought the token of a vault out. \[buyer, vault_id, payment\]
+		BoughtOut(T::AccountId, NftVaultId, Balance),
+		/// Redeemed the token of a vault with all the shares. \[who,
+		/// vault_id\]
+		Redeemed(T::AccountId, NftVaultId),
+		/// Claimed the proceeds of a buyout for shares. \[who, vault_id,
+		/// shares, proceeds\]
+		Claimed(T::AccountId, NftVaultId, Balance, Balance),
+	}
+
+	/// The id of the next vault.
+	///
+	/// NextVaultId: NftVaultId
+	#[pallet::storage]
+	#[pallet::getter(fn next_vault_id)]
+	pub type NextVaultId<T: Config> = StorageValue<_, NftVaultId, ValueQuery>;
+
+	/// The vaults.
+	///
+	/// Vaults: map NftVaultId => Option<Vault>
+	#[pallet::storage]
+	#[pallet::getter(fn vaults)]
+	pub type Vaults<T: Config> = StorageMap<_, Twox64Concat, NftVaultId, Vault<ClassIdOf<T>, TokenIdOf<T>>, OptionQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Lock a token in a new vault and mint the whole supply of its shares
+		/// to the caller. Tokens of Revocable classes can't be fractionalized,
+		/// and the class owner can't set a transfer lock on a token while it's
+		/// in a vault, so the token can always be redeemed or bought out.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `supply`: the total supply of the shares
+		/// - `currency_id`: the currency of the reserve price
+		/// - `reserve_price`: the price to buy the token out
+		#[pallet::weight(<T as Config>::WeightInfo::fractionalize())]
+		#[transactional]
+		pub fn fractionalize(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			#[pallet::compact] supply: Balance,
+			currency_id: CurrencyId,
+			#[pallet::compact] reserve_price: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				!supply.is_zero() && !reserve_price.is_zero(),
+				Error::<T>::InvalidAmount
+			);
+			let class_info =
+				orml_nft::Pallet::<T>::classes(token.0).ok_or(module_nft::Error::<T>::ClassIdNotFound)?;
+			ensure!(
+				!class_info.data.properties.0.contains(ClassProperty::Revocable),
+				Error::<T>::RevocableToken
+			);
+
+			let vault_id = NextVaultId::<T>::try_mutate(|id| -> Result<NftVaultId, DispatchError> {
+				let current_id = *id;
+				*id = id.checked_add(One::one()).ok_or(ArithmeticError::Overflow)?;
+				Ok(current_id)
+			})?;
+
+			<module_nft::Pallet<T> as NFT<T::AccountId>>::transfer(&who, &Self::vault_account_id(vault_id), token)?;
+			let share_currency_id = CurrencyId::NftShare(vault_id);
+			<T as module_nft::Config>::MultiCurrency::deposit(share_currency_id, &who, supply)?;
+
+			Vaults::<T>::insert(
+				vault_id,
+				Vault {
+					token,
+					supply,
+					currency_id,
+					reserve_price,
+					state: VaultState::Active,
+				},
+			);
+			Self::deposit_event(Event::Fractionalized(
+				who,
+				vault_id,
+				token.0,
+				token.1,
+				share_currency_id,
+				supply,
+			));
+			Ok(())
+		}
+
+		/// Buy the token of a vault out. The caller pays the reserve price for
+		/// the shares it doesn't hold and its own shares are burned.
+		///
+		/// - `vault_id`: the vault to buy out
+		#[pallet::weight(<T as Config>::WeightInfo::buyout())]
+		#[transactional]
+		pub fn buyout(origin: OriginFor<T>, vault_id: NftVaultId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Vaults::<T>::try_mutate(vault_id, |maybe_vault| -> DispatchResult {
+				let vault = maybe_vault.as_mut().ok_or(Error::<T>::VaultNotFound)?;
+				ensure!(vault.state == VaultState::Active, Error::<T>::VaultBoughtOut);
+
+				let share_currency_id = CurrencyId::NftShare(vault_id);
+				let shares = <T as module_nft::Config>::MultiCurrency::free_balance(share_currency_id, &who);
+				// the shares of the caller are deducted rounded down, so the payment is rounded up
+				let payment = vault
+					.reserve_price
+					.saturating_sub(Self::share_of(vault.reserve_price, shares, vault.supply)?);
+
+				let vault_account_id = Self::vault_account_id(vault_id);
+				<T as module_nft::Config>::MultiCurrency::withdraw(share_currency_id, &who, shares)?;
+				<T as module_nft::Config>::MultiCurrency::transfer(vault.currency_id, &who, &vault_account_id, payment)?;
+				<module_nft::Pallet<T> as NFT<T::AccountId>>::transfer(&vault_account_id, &who, vault.token)?;
+
+				vault.state = VaultState::BoughtOut;
+				vault.supply = vault.supply.saturating_sub(shares);
+				Self::deposit_event(Event::BoughtOut(who.clone(), vault_id, payment));
+				Ok(())
+			})?;
+			Self::try_remove_vault(vault_id);
+			Ok(())
+		}
+
+		/// Redeem the token of a vault by burning all of its shares.
+		///
+		/// - `vault_id`: the vault to redeem
+		#[pallet::weight(<T as Config>::WeightInfo::redeem())]
+		#[transactional]
+		pub fn redeem(origin: OriginFor<T>, vault_id: NftVaultId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let vault = Self::vaults(vault_id).ok_or(Error::<T>::VaultNotFound)?;
+			ensure!(vault.state == VaultState::Active, Error::<T>::VaultBoughtOut);
+
+			let share_currency_id = CurrencyId::NftShare(vault_id);
+			ensure!(
+				<T as module_nft::Config>::MultiCurrency::free_balance(share_currency_id, &who) >= vault.supply,
+				Error::<T>::InsufficientShares
+			);
+
+			<T as module_nft::Config>::MultiCurrency::withdraw(share_currency_id, &who, vault.supply)?;
+			<module_nft::Pallet<T> as NFT<T::AccountId>>::transfer(&Self::vault_account_id(vault_id), &who, vault.token)?;
+
+			Vaults::<T>::remove(vault_id);
+			Self::deposit_event(Event::Redeemed(who, vault_id));
+			Ok(())
+		}
+
+		/// Burn the shares of the caller for their part of the proceeds of a
+		/// buyout.
+		///
+		/// - `vault_id`: the bought out vault
+		#[pallet::weight(<T as Config>::WeightInfo::claim())]
+		#[transactional]
+		pub fn claim(origin: OriginFor<T>, vault_id: NftVaultId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Vaults::<T>::try_mutate(vault_id, |maybe_vault| -> DispatchResult {
+				let vault = maybe_vault.as_mut().ok_or(Error::<T>::VaultNotFound)?;
+				ensure!(vault.state == VaultState::BoughtOut, Error::<T>::VaultNotBoughtOut);
+
+				let share_currency_id = CurrencyId::NftShare(vault_id);
+				let shares = <T as module_nft::Config>::MultiCurrency::free_balance(share_currency_id, &who);
+				ensure!(!shares.is_zero(), Error::<T>::NoShares);
+
+				// `supply` is the number of the unclaimed shares, so the last claim takes the
+				// remaining proceeds including the rounding.
+				let vault_account_id = Self::vault_account_id(vault_id);
+				let remaining =
+					<T as module_nft::Config>::MultiCurrency::free_balance(vault.currency_id, &vault_account_id);
+				let proceeds = Self::share_of(remaining, shares.min(vault.supply), vault.supply)?;
+
+				<T as module_nft::Config>::MultiCurrency::withdraw(share_currency_id, &who, shares)?;
+				<T as module_nft::Config>::MultiCurrency::transfer(vault.currency_id, &vault_account_id, &who, proceeds)?;
+
+				vault.supply = vault.supply.saturating_sub(shares);
+				Self::deposit_event(Event::Claimed(who.clone(), vault_id, shares, proceeds));
+				Ok(())
+			})?;
+			Self::try_remove_vault(vault_id);
+			Ok(())
+		}
+	}
+}
+
+/// The vault accounts hold the fractionalized tokens in custody.
+impl<T: Config> Contains<T::AccountId> for Pallet<T> {
+	fn contains(who: &T::AccountId) -> bool {
+		PalletId::try_from_sub_account::<NftVaultId>(who)
+			.map_or(false, |(id, _)| id == <T as Config>::PalletId::get())
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The account of a vault.
+	pub fn vault_account_id(vault_id: NftVaultId) -> T::AccountId {
+		<T as Config>::PalletId::get().into_sub_account(vault_id)
+	}
+
+	/// The part of `total` for `shares` of `supply`, rounded down.
+	fn share_of(total: Balance, shares: Balance, supply: Balance) -> Result<Balance, DispatchError> {
+		multiply_by_rational(total, shares, supply).map_err(|_| ArithmeticError::Overflow.into())
+	}
+
+	/// Remove a bought out vault once all of its shares are claimed.
+	fn try_remove_vault(vault_id: NftVaultId) {
+		if let Some(vault) = Self::vaults(vault_id) {
+			if vault.state == VaultState::BoughtOut && vault.supply.is_zero() {
+				Vaults::<T>::remove(vault_id);
+			}
+		}
+	}
+}
@@ -0,0 +1,211 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the NFT vault module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use module_nft::{ClassProperty, Properties};
+
+fn class_id_account() -> AccountId {
+	<Runtime as module_nft::Config>::PalletId::get().into_sub_account(CLASS_ID)
+}
+
+fn owner() -> Option<AccountId> {
+	<NFTModule as NFT<AccountId>>::owner(TOKEN)
+}
+
+fn shares(who: &AccountId) -> Balance {
+	Currencies::free_balance(SHARE, who)
+}
+
+fn setup_token_with_properties(properties: Properties) {
+	assert_ok!(NFTModule::create_class(
+		Origin::signed(ALICE),
+		vec![1],
+		properties,
+		Default::default(),
+	));
+	assert_ok!(Balances::deposit_into_existing(&class_id_account(), 1000));
+	assert_ok!(NFTModule::mint(
+		Origin::signed(class_id_account()),
+		BOB,
+		CLASS_ID,
+		vec![1],
+		Default::default(),
+		1
+	));
+}
+
+fn setup_token() {
+	setup_token_with_properties(Properties(ClassProperty::Transferable | ClassProperty::Mintable));
+}
+
+#[test]
+fn fractionalize_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_token();
+
+		assert_noop!(
+			NFTVault::fractionalize(Origin::signed(BOB), TOKEN, 0, SETUSD, 1000),
+			Error::<Runtime>::InvalidAmount
+		);
+		assert_noop!(
+			NFTVault::fractionalize(Origin::signed(BOB), TOKEN, 100, SETUSD, 0),
+			Error::<Runtime>::InvalidAmount
+		);
+		assert_noop!(
+			NFTVault::fractionalize(Origin::signed(CHARLIE), TOKEN, 100, SETUSD, 1000),
+			orml_nft::Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(NFTVault::fractionalize(Origin::signed(BOB), TOKEN, 100, SETUSD, 1000));
+		System::assert_last_event(Event::NFTVault(crate::Event::Fractionalized(
+			BOB, VAULT_ID, CLASS_ID, TOKEN_ID, SHARE, 100,
+		)));
+		assert_eq!(
+			NFTVault::vaults(VAULT_ID),
+			Some(Vault {
+				token: TOKEN,
+				supply: 100,
+				currency_id: SETUSD,
+				reserve_price: 1000,
+				state: VaultState::Active,
+			})
+		);
+		assert_eq!(NFTVault::next_vault_id(), 1);
+		assert_eq!(owner(), Some(NFTVault::vault_account_id(VAULT_ID)));
+		assert_eq!(shares(&BOB), 100);
+	});
+}
+
+#[test]
+fn fractionalize_should_fail_for_revocable_token() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_token_with_properties(Properties(ClassProperty::Transferable | ClassProperty::Revocable));
+
+		assert_noop!(
+			NFTVault::fractionalize(Origin::signed(BOB), TOKEN, 100, SETUSD, 1000),
+			Error::<Runtime>::RevocableToken
+		);
+	});
+}
+
+#[test]
+fn vaulted_token_cannot_be_locked() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_token_with_properties(Properties(
+			ClassProperty::Transferable | ClassProperty::Mintable | ClassProperty::TransferLockable,
+		));
+		assert_ok!(NFTVault::fractionalize(Origin::signed(BOB), TOKEN, 100, SETUSD, 1000));
+
+		assert_noop!(
+			NFTModule::set_token_transfer_lock(Origin::signed(class_id_account()), TOKEN, Some(100)),
+			module_nft::Error::<Runtime>::TokenInCustody
+		);
+		assert_ok!(NFTModule::set_token_transfer_lock(
+			Origin::signed(class_id_account()),
+			TOKEN,
+			None
+		));
+
+		assert_ok!(NFTVault::redeem(Origin::signed(BOB), VAULT_ID));
+		assert_eq!(owner(), Some(BOB));
+	});
+}
+
+#[test]
+fn redeem_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_token();
+		assert_ok!(NFTVault::fractionalize(Origin::signed(BOB), TOKEN, 100, SETUSD, 1000));
+		assert_ok!(Currencies::transfer(Origin::signed(BOB), CHARLIE, SHARE, 40));
+
+		assert_noop!(
+			NFTVault::redeem(Origin::signed(BOB), VAULT_ID),
+			Error::<Runtime>::InsufficientShares
+		);
+		assert_noop!(
+			NFTVault::redeem(Origin::signed(BOB), VAULT_ID + 1),
+			Error::<Runtime>::VaultNotFound
+		);
+
+		assert_ok!(Currencies::transfer(Origin::signed(CHARLIE), BOB, SHARE, 40));
+		assert_ok!(NFTVault::redeem(Origin::signed(BOB), VAULT_ID));
+		System::assert_last_event(Event::NFTVault(crate::Event::Redeemed(BOB, VAULT_ID)));
+		assert_eq!(owner(), Some(BOB));
+		assert_eq!(shares(&BOB), 0);
+		assert_eq!(Currencies::total_issuance(SHARE), 0);
+		assert_eq!(NFTVault::vaults(VAULT_ID), None);
+	});
+}
+
+#[test]
+fn buyout_and_claim_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_token();
+		assert_ok!(NFTVault::fractionalize(Origin::signed(BOB), TOKEN, 100, SETUSD, 1000));
+		assert_ok!(Currencies::transfer(Origin::signed(BOB), CHARLIE, SHARE, 30));
+		assert_ok!(Currencies::transfer(Origin::signed(BOB), ALICE, SHARE, 20));
+
+		assert_noop!(
+			NFTVault::claim(Origin::signed(ALICE), VAULT_ID),
+			Error::<Runtime>::VaultNotBoughtOut
+		);
+
+		// the buyer pays the reserve price for the shares it doesn't hold
+		assert_ok!(NFTVault::buyout(Origin::signed(CHARLIE), VAULT_ID));
+		System::assert_last_event(Event::NFTVault(crate::Event::BoughtOut(CHARLIE, VAULT_ID, 700)));
+		assert_eq!(owner(), Some(CHARLIE));
+		assert_eq!(Currencies::free_balance(SETUSD, &CHARLIE), 100000 - 700);
+		assert_eq!(shares(&CHARLIE), 0);
+		assert_eq!(NFTVault::vaults(VAULT_ID).map(|v| (v.state, v.supply)), Some((VaultState::BoughtOut, 70)));
+
+		assert_noop!(
+			NFTVault::buyout(Origin::signed(ALICE), VAULT_ID),
+			Error::<Runtime>::VaultBoughtOut
+		);
+		assert_noop!(
+			NFTVault::redeem(Origin::signed(BOB), VAULT_ID),
+			Error::<Runtime>::VaultBoughtOut
+		);
+		assert_noop!(
+			NFTVault::claim(Origin::signed(CHARLIE), VAULT_ID),
+			Error::<Runtime>::NoShares
+		);
+
+		assert_ok!(NFTVault::claim(Origin::signed(ALICE), VAULT_ID));
+		System::assert_last_event(Event::NFTVault(crate::Event::Claimed(ALICE, VAULT_ID, 20, 200)));
+		assert_eq!(Currencies::free_balance(SETUSD, &ALICE), 100000 + 200);
+
+		assert_ok!(NFTVault::claim(Origin::signed(BOB), VAULT_ID));
+		System::assert_last_event(Event::NFTVault(crate::Event::Claimed(BOB, VAULT_ID, 50, 500)));
+		assert_eq!(Currencies::free_balance(SETUSD, &BOB), 100000 + 500);
+		assert_eq!(
+			Currencies::free_balance(SETUSD, &NFTVault::vault_account_id(VAULT_ID)),
+			0
+		);
+		assert_eq!(Currencies::total_issuance(SHARE), 0);
+		assert_eq!(NFTVault::vaults(VAULT_ID), None);
+	});
+}
@@ -27,10 +27,14 @@ use sp_std::vec;
 use frame_benchmarking::{account, benchmarks};
 use frame_support::{dispatch::DispatchErrorWithPostInfo, traits::Get, weights::DispatchClass};
 use frame_system::RawOrigin;
-use sp_runtime::traits::{AccountIdConversion, StaticLookup, UniqueSaturatedInto};
+use orml_traits::MultiCurrency;
+use sp_runtime::{
+	traits::{AccountIdConversion, StaticLookup, UniqueSaturatedInto},
+	Permill,
+};
 
 pub use crate::*;
-use primitives::Balance;
+use primitives::{Balance, CurrencyId, TokenSymbol};
 
 pub struct Module<T: Config>(crate::Pallet<T>);
 
@@ -148,6 +152,130 @@ benchmarks! {
 
 		let module_account = create_token_class::<T>(caller)?;
 	}: _(RawOrigin::Signed(module_account), 0u32.into(), Properties(ClassProperty::Transferable.into()))
+
+	set_class_royalty {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+
+		let module_account = create_token_class::<T>(caller)?;
+		let royalty = Royalty {
+			recipient,
+			rate: T::MaxRoyaltyRate::get(),
+		};
+	}: _(RawOrigin::Signed(module_account), 0u32.into(), Some(royalty))
+
+	// transfer NFT token to another account with payment and royalty
+	transfer_with_payment {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let currency_id = CurrencyId::Token(TokenSymbol::SETUSD);
+		let price = dollar(100);
+
+		let module_account = create_token_class::<T>(caller.clone())?;
+		crate::Pallet::<T>::set_class_royalty(
+			RawOrigin::Signed(module_account.clone()).into(),
+			0u32.into(),
+			Some(Royalty {
+				recipient,
+				rate: Permill::from_percent(10),
+			}),
+		)?;
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+
+		T::MultiCurrency::deposit(currency_id, &caller, price)?;
+		crate::Pallet::<T>::set_purchase_approval(
+			RawOrigin::Signed(caller).into(),
+			(0u32.into(), 0u32.into()),
+			Some((currency_id, price)),
+		)?;
+	}: _(RawOrigin::Signed(to), caller_lookup, (0u32.into(), 0u32.into()), currency_id, price)
+
+	// revoke NFT token by the class owner
+	revoke {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to);
+
+		let module_account = create_token_class::<T>(caller)?;
+		crate::Pallet::<T>::update_class_properties(
+			RawOrigin::Signed(module_account.clone()).into(),
+			0u32.into(),
+			Properties(ClassProperty::Mintable | ClassProperty::Revocable),
+		)?;
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account.clone()).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+	}: _(RawOrigin::Signed(module_account), (0u32.into(), 0u32.into()))
+
+	set_token_transfer_lock {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to);
+
+		let module_account = create_token_class::<T>(caller)?;
+		crate::Pallet::<T>::update_class_properties(
+			RawOrigin::Signed(module_account.clone()).into(),
+			0u32.into(),
+			Properties(ClassProperty::Transferable | ClassProperty::Mintable | ClassProperty::TransferLockable),
+		)?;
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account.clone()).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+	}: _(RawOrigin::Signed(module_account), (0u32.into(), 0u32.into()), Some(100u32.into()))
+
+	// approve the purchase of NFT token
+	set_purchase_approval {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to);
+
+		let module_account = create_token_class::<T>(caller.clone())?;
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+	}: _(RawOrigin::Signed(caller), (0u32.into(), 0u32.into()), Some((CurrencyId::Token(TokenSymbol::SETUSD), dollar(100))))
+
+	// set NFT token attribute by the class owner
+	set_token_attribute {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to);
+
+		let module_account = create_token_class::<T>(caller)?;
+		crate::Pallet::<T>::update_class_properties(
+			RawOrigin::Signed(module_account.clone()).into(),
+			0u32.into(),
+			Properties(ClassProperty::TokenAttributesMutable.into()),
+		)?;
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account.clone()).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+	}: _(RawOrigin::Signed(module_account), (0u32.into(), 0u32.into()), vec![0], vec![0; 96])
+
+	// remove NFT token attribute by the class owner
+	remove_token_attribute {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to);
+
+		let module_account = create_token_class::<T>(caller)?;
+		crate::Pallet::<T>::update_class_properties(
+			RawOrigin::Signed(module_account.clone()).into(),
+			0u32.into(),
+			Properties(ClassProperty::TokenAttributesMutable.into()),
+		)?;
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account.clone()).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+	}: _(RawOrigin::Signed(module_account), (0u32.into(), 0u32.into()), vec![0])
+
+	// update NFT token metadata by the class owner
+	update_token_metadata {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to);
+
+		let module_account = create_token_class::<T>(caller)?;
+		crate::Pallet::<T>::update_class_properties(
+			RawOrigin::Signed(module_account.clone()).into(),
+			0u32.into(),
+			Properties(ClassProperty::TokenAttributesMutable.into()),
+		)?;
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account.clone()).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+	}: _(RawOrigin::Signed(module_account), (0u32.into(), 0u32.into()), vec![1; 64])
 }
 
 #[cfg(test)]
@@ -157,17 +285,21 @@ mod mock {
 
 	use codec::{Decode, Encode};
 	use frame_support::{
-		parameter_types,
+		ord_parameter_types, parameter_types,
 		traits::{Contains, InstanceFilter},
 		weights::Weight,
 		PalletId, RuntimeDebug,
 	};
+	use frame_system::EnsureSignedBy;
+	use orml_traits::parameter_type_with_key;
+	use primitives::{Amount, BlockNumber, ReserveIdentifier};
 	use sp_core::{crypto::AccountId32, H256};
 	use sp_runtime::{
 		testing::Header,
 		traits::{BlakeTwo256, IdentityLookup},
 		Perbill,
 	};
+	use support::mocks::MockAddressMapping;
 
 	parameter_types! {
 		pub const BlockHashCount: u64 = 250;
@@ -280,12 +412,69 @@ mod mock {
 		type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	}
 
+	parameter_type_with_key! {
+		pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+			Default::default()
+		};
+	}
+
+	impl orml_tokens::Config for Runtime {
+		type Event = ();
+		type Balance = Balance;
+		type Amount = Amount;
+		type CurrencyId = CurrencyId;
+		type WeightInfo = ();
+		type ExistentialDeposits = ExistentialDeposits;
+		type OnDust = ();
+		type MaxLocks = ();
+		type DustRemovalWhitelist = ();
+	}
+
+	pub type NativeCurrency = module_currencies::BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
+
+	ord_parameter_types! {
+		pub const One: AccountId = AccountId::new([1u8; 32]);
+	}
+
+	parameter_types! {
+		pub const GetNativeCurrencyId: CurrencyId = CurrencyId::Token(TokenSymbol::SEE);
+		pub StableCurrencyIds: Vec<CurrencyId> = vec![
+			CurrencyId::Token(TokenSymbol::SETR),
+			CurrencyId::Token(TokenSymbol::SETUSD),
+		];
+		pub const MaxTransferBatch: u32 = 10;
+		pub const DustHistoryDepth: u32 = 84;
+		pub const Erc20TransferWeight: u64 = 1_000_000;
+		pub const MaxMemoLength: u32 = 32;
+	}
+
+	impl module_currencies::Config for Runtime {
+		type Event = ();
+		type MultiCurrency = Tokens;
+		type NativeCurrency = NativeCurrency;
+		type GetNativeCurrencyId = GetNativeCurrencyId;
+		type StableCurrencyIds = StableCurrencyIds;
+		type SerpTreasury = crate::mock::MockSerpTreasury;
+		type WeightInfo = ();
+		type AddressMapping = MockAddressMapping;
+		type EVMBridge = ();
+		type SweepOrigin = EnsureSignedBy<One, AccountId>;
+		type OnDust = ();
+		type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+		type CurrentEra = ();
+		type DustHistoryDepth = DustHistoryDepth;
+		type MaxTransferBatch = MaxTransferBatch;
+		type Erc20TransferWeight = Erc20TransferWeight;
+		type MaxMemoLength = MaxMemoLength;
+	}
+
 	parameter_types! {
 		pub const CreateClassDeposit: Balance = 200;
 		pub const CreateTokenDeposit: Balance = 100;
 		pub const DataDepositPerByte: Balance = 10;
 		pub const NftPalletId: PalletId = PalletId(*b"set/sNFT");
 		pub MaxAttributesBytes: u32 = 2048;
+		pub MaxRoyaltyRate: Permill = Permill::from_percent(50);
 	}
 
 	impl crate::Config for Runtime {
@@ -296,6 +485,8 @@ mod mock {
 		type DataDepositPerByte = DataDepositPerByte;
 		type PalletId = NftPalletId;
 		type MaxAttributesBytes = MaxAttributesBytes;
+		type MultiCurrency = Currencies;
+		type MaxRoyaltyRate = MaxRoyaltyRate;
 		type WeightInfo = ();
 	}
 
@@ -326,6 +517,8 @@ mod mock {
 			Utility: pallet_utility::{Pallet, Call, Event},
 			Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 			Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>},
+			Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
+			Currencies: module_currencies::{Pallet, Call, Event<T>},
 			OrmlNFT: orml_nft::{Pallet, Storage, Config<T>},
 			NFT: nft::{Pallet, Call, Event<T>},
 		}
@@ -28,22 +28,23 @@ use frame_support::{
 	pallet_prelude::*,
 	require_transactional,
 	traits::{
-		Currency,
+		Contains, Currency,
 		ExistenceRequirement::{AllowDeath, KeepAlive},
 		NamedReservableCurrency,
 	},
 	transactional, PalletId,
 };
 use frame_system::pallet_prelude::*;
-use orml_traits::NFT;
-use primitives::{NFTBalance, ReserveIdentifier};
+use orml_traits::{MultiCurrency, NFT};
+use primitives::{Balance, CurrencyId, NFTBalance, ReserveIdentifier};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_runtime::{
 	traits::{AccountIdConversion, Hash, Saturating, StaticLookup, Zero},
-	DispatchResult, RuntimeDebug,
+	DispatchResult, Permill, RuntimeDebug,
 };
-use sp_std::{collections::btree_map::BTreeMap, prelude::*};
+use sp_std::{collections::btree_map::BTreeMap, convert::TryInto, prelude::*};
+use support::NFTRoyalty;
 
 pub mod benchmarking;
 mod mock;
@@ -67,6 +68,14 @@ pub enum ClassProperty {
 	Mintable = 0b00000100,
 	/// Is class properties mutable
 	ClassPropertiesMutable = 0b00001000,
+	/// Is token revocable by the class owner
+	Revocable = 0b00010000,
+	/// Is token attributes and metadata mutable by the class owner
+	TokenAttributesMutable = 0b00100000,
+	/// Is token attributes and metadata mutable by the token owner
+	TokenAttributesMutableByOwner = 0b01000000,
+	/// Is token transfer lockable by the class owner
+	TransferLockable = 0b10000000,
 }
 
 #[derive(Clone, Copy, PartialEq, Default, RuntimeDebug)]
@@ -108,6 +117,15 @@ pub struct TokenData<Balance> {
 	pub attributes: Attributes,
 }
 
+/// The royalty of the sales of the tokens of a class.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct Royalty<AccountId> {
+	/// The account which receives the royalty
+	pub recipient: AccountId,
+	/// The share of the sale price paid as royalty
+	pub rate: Permill,
+}
+
 pub type TokenIdOf<T> = <T as orml_nft::Config>::TokenId;
 pub type ClassIdOf<T> = <T as orml_nft::Config>::ClassId;
 pub type BalanceOf<T> =
@@ -154,6 +172,17 @@ pub mod module {
 		#[pallet::constant]
 		type MaxAttributesBytes: Get<u32>;
 
+		/// The multi-currencies the sales of tokens are paid in.
+		type MultiCurrency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// The maximum royalty rate of a class
+		#[pallet::constant]
+		type MaxRoyaltyRate: Get<Permill>;
+
+		/// The accounts holding tokens in custody, e.g. the escrow of a
+		/// marketplace or the vaults, whose tokens can't be revoked.
+		type CustodyAccounts: Contains<Self::AccountId>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -181,6 +210,21 @@ pub mod module {
 		Immutable,
 		/// Attributes too large
 		AttributesTooLarge,
+		/// The royalty rate exceeds `MaxRoyaltyRate`
+		RoyaltyRateTooHigh,
+		/// Property of class don't support revoke
+		NonRevocable,
+		/// Token is locked for transfer
+		TransferLocked,
+		/// Property of class don't support transfer lock
+		NonTransferLockable,
+		/// The token attribute does not exist
+		AttributeNotFound,
+		/// The payer hasn't approved the purchase of the token in the
+		/// currency at the price
+		PurchaseNotApproved,
+		/// The token is held in custody and can't be revoked or locked
+		TokenInCustody,
 	}
 
 	#[pallet::event]
@@ -199,8 +243,69 @@ pub mod module {
 		BurnedTokenWithRemark(T::AccountId, ClassIdOf<T>, TokenIdOf<T>, T::Hash),
 		/// Destroyed NFT class. \[owner, class_id\]
 		DestroyedClass(T::AccountId, ClassIdOf<T>),
+		/// Updated the royalty of NFT class. \[class_id, royalty\]
+		UpdatedClassRoyalty(ClassIdOf<T>, Option<Royalty<T::AccountId>>),
+		/// Transferred NFT token with payment. \[from, to, class_id, token_id,
+		/// currency_id, price, royalty\]
+		TransferredTokenWithPayment(
+			T::AccountId,
+			T::AccountId,
+			ClassIdOf<T>,
+			TokenIdOf<T>,
+			CurrencyId,
+			Balance,
+			Balance,
+		),
+		/// Revoked NFT token by the class owner. \[class_owner, owner,
+		/// class_id, token_id\]
+		RevokedToken(T::AccountId, T::AccountId, ClassIdOf<T>, TokenIdOf<T>),
+		/// Updated the transfer lock of NFT token. \[class_id, token_id,
+		/// until\]
+		UpdatedTokenTransferLock(ClassIdOf<T>, TokenIdOf<T>, Option<T::BlockNumber>),
+		/// Set an attribute of NFT token. \[class_id, token_id, key, value\]
+		SetTokenAttribute(ClassIdOf<T>, TokenIdOf<T>, Vec<u8>, Vec<u8>),
+		/// Removed an attribute of NFT token. \[class_id, token_id, key\]
+		RemovedTokenAttribute(ClassIdOf<T>, TokenIdOf<T>, Vec<u8>),
+		/// Updated the metadata of NFT token. \[class_id, token_id, metadata\]
+		UpdatedTokenMetadata(ClassIdOf<T>, TokenIdOf<T>, CID),
+		/// Updated the purchase approval of NFT token. \[buyer, class_id,
+		/// token_id, approval\]
+		UpdatedPurchaseApproval(T::AccountId, ClassIdOf<T>, TokenIdOf<T>, Option<(CurrencyId, Balance)>),
 	}
 
+	/// The royalties of the classes.
+	///
+	/// ClassRoyalties: map ClassId => Option<Royalty>
+	#[pallet::storage]
+	#[pallet::getter(fn class_royalties)]
+	pub type ClassRoyalties<T: Config> = StorageMap<_, Twox64Concat, ClassIdOf<T>, Royalty<T::AccountId>, OptionQuery>;
+
+	/// The block numbers until which the tokens can't be transferred.
+	///
+	/// TokenTransferLocks: double_map ClassId, TokenId => Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn token_transfer_locks)]
+	pub type TokenTransferLocks<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ClassIdOf<T>, Twox64Concat, TokenIdOf<T>, T::BlockNumber, OptionQuery>;
+
+	/// The sellers, the currencies and the maximum prices the buyers approved
+	/// to pay for the tokens with `transfer_with_payment`. The seller is the
+	/// owner of the token at the approval.
+	///
+	/// PurchaseApprovals: double_map (ClassId, TokenId), AccountId =>
+	/// Option<(AccountId, CurrencyId, Balance)>
+	#[pallet::storage]
+	#[pallet::getter(fn purchase_approvals)]
+	pub type PurchaseApprovals<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		(ClassIdOf<T>, TokenIdOf<T>),
+		Twox64Concat,
+		T::AccountId,
+		(T::AccountId, CurrencyId, Balance),
+		OptionQuery,
+	>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -338,6 +443,7 @@ pub mod module {
 			<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, &who, data.deposit);
 
 			orml_nft::Pallet::<T>::destroy_class(&who, class_id)?;
+			ClassRoyalties::<T>::remove(class_id);
 
 			// this should unresere proxy deposit
 			pallet_proxy::Pallet::<T>::remove_proxy_delegate(&who, dest.clone(), Default::default(), Zero::zero())?;
@@ -381,6 +487,253 @@ pub mod module {
 				Ok(())
 			})
 		}
+
+		/// Set the royalty of the sales of the tokens of NFT class. The royalty
+		/// can only be changed after it is set if the class properties
+		/// contains ClassPropertiesMutable.
+		///
+		/// - `class_id`: The class ID to update
+		/// - `royalty`: The new royalty, `None` to remove it
+		#[pallet::weight(<T as Config>::WeightInfo::set_class_royalty())]
+		#[transactional]
+		pub fn set_class_royalty(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			royalty: Option<Royalty<T::AccountId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let class_info = orml_nft::Pallet::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(who == class_info.owner, Error::<T>::NoPermission);
+			ensure!(
+				!ClassRoyalties::<T>::contains_key(class_id)
+					|| class_info
+						.data
+						.properties
+						.0
+						.contains(ClassProperty::ClassPropertiesMutable),
+				Error::<T>::Immutable
+			);
+			if let Some(royalty) = &royalty {
+				ensure!(
+					royalty.rate <= T::MaxRoyaltyRate::get(),
+					Error::<T>::RoyaltyRateTooHigh
+				);
+			}
+
+			ClassRoyalties::<T>::set(class_id, royalty.clone());
+			Self::deposit_event(Event::UpdatedClassRoyalty(class_id, royalty));
+			Ok(())
+		}
+
+		/// Transfer NFT token to another account who pays `price` for it,
+		/// the royalty of the class is paid to its recipient and the rest to
+		/// the caller. `to` must have approved the purchase of the token from
+		/// the caller in `currency_id` for at least `price` by
+		/// `set_purchase_approval`, the approval is consumed.
+		///
+		/// - `to`: the token owner's account, the payer
+		/// - `token`: (class_id, token_id)
+		/// - `currency_id`: the currency of the payment
+		/// - `price`: the amount of the payment
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_with_payment())]
+		#[transactional]
+		pub fn transfer_with_payment(
+			origin: OriginFor<T>,
+			to: <T::Lookup as StaticLookup>::Source,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			currency_id: CurrencyId,
+			#[pallet::compact] price: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(to)?;
+
+			let (seller, approved_currency_id, max_price) =
+				PurchaseApprovals::<T>::take(token, &to).ok_or(Error::<T>::PurchaseNotApproved)?;
+			ensure!(
+				seller == who && approved_currency_id == currency_id && price <= max_price,
+				Error::<T>::PurchaseNotApproved
+			);
+
+			let royalty = Self::pay_with_royalty(token.0, price, &who, |recipient, amount| {
+				T::MultiCurrency::transfer(currency_id, &to, recipient, amount)
+			})?;
+			Self::do_transfer(&who, &to, token)?;
+
+			Self::deposit_event(Event::TransferredTokenWithPayment(
+				who,
+				to,
+				token.0,
+				token.1,
+				currency_id,
+				price,
+				royalty,
+			));
+			Ok(())
+		}
+
+		/// Approve to pay at most `max_price` of `currency_id` for NFT token
+		/// when its current owner transfers it to the caller by
+		/// `transfer_with_payment`. The approval doesn't apply to the later
+		/// owners of the token.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `approval`: The currency and the maximum price, `None` to cancel
+		///   the approval
+		#[pallet::weight(<T as Config>::WeightInfo::set_purchase_approval())]
+		#[transactional]
+		pub fn set_purchase_approval(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			approval: Option<(CurrencyId, Balance)>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			match approval {
+				Some((currency_id, max_price)) => {
+					let token_info =
+						orml_nft::Pallet::<T>::tokens(token.0, token.1).ok_or(Error::<T>::TokenIdNotFound)?;
+					PurchaseApprovals::<T>::insert(token, &who, (token_info.owner, currency_id, max_price));
+				}
+				None => PurchaseApprovals::<T>::remove(token, &who),
+			}
+			Self::deposit_event(Event::UpdatedPurchaseApproval(who, token.0, token.1, approval));
+			Ok(())
+		}
+
+		/// Revoke NFT token from its owner and burn it. The class properties
+		/// must contains Revocable, and the token must not be held by one of
+		/// `CustodyAccounts`.
+		///
+		/// - `token`: (class_id, token_id)
+		#[pallet::weight(<T as Config>::WeightInfo::revoke())]
+		#[transactional]
+		pub fn revoke(origin: OriginFor<T>, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(who == class_info.owner, Error::<T>::NoPermission);
+			ensure!(
+				class_info.data.properties.0.contains(ClassProperty::Revocable),
+				Error::<T>::NonRevocable
+			);
+
+			let token_info = orml_nft::Pallet::<T>::tokens(token.0, token.1).ok_or(Error::<T>::TokenIdNotFound)?;
+			ensure!(
+				!T::CustodyAccounts::contains(&token_info.owner),
+				Error::<T>::TokenInCustody
+			);
+			orml_nft::Pallet::<T>::burn(&token_info.owner, token)?;
+			TokenTransferLocks::<T>::remove(token.0, token.1);
+
+			<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, &token_info.owner, token_info.data.deposit);
+
+			Self::deposit_event(Event::RevokedToken(who, token_info.owner, token.0, token.1));
+			Ok(())
+		}
+
+		/// Lock NFT token for transfer until a block number. The class
+		/// properties must contains TransferLockable to lock the token, and a
+		/// token held by one of `CustodyAccounts` can only be unlocked.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `until`: The block number from which the token can be
+		///   transferred, `None` to unlock it
+		#[pallet::weight(<T as Config>::WeightInfo::set_token_transfer_lock())]
+		#[transactional]
+		pub fn set_token_transfer_lock(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			until: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(who == class_info.owner, Error::<T>::NoPermission);
+			ensure!(
+				until.is_none() || class_info.data.properties.0.contains(ClassProperty::TransferLockable),
+				Error::<T>::NonTransferLockable
+			);
+			let token_info = orml_nft::Pallet::<T>::tokens(token.0, token.1).ok_or(Error::<T>::TokenIdNotFound)?;
+			ensure!(
+				until.is_none() || !T::CustodyAccounts::contains(&token_info.owner),
+				Error::<T>::TokenInCustody
+			);
+
+			TokenTransferLocks::<T>::set(token.0, token.1, until);
+			Self::deposit_event(Event::UpdatedTokenTransferLock(token.0, token.1, until));
+			Ok(())
+		}
+
+		/// Set an attribute of NFT token. The class properties must contains
+		/// TokenAttributesMutable for the class owner, or
+		/// TokenAttributesMutableByOwner for the token owner. The data deposit
+		/// of the token is adjusted.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `key`: The key of the attribute
+		/// - `value`: The value of the attribute
+		#[pallet::weight(<T as Config>::WeightInfo::set_token_attribute())]
+		#[transactional]
+		pub fn set_token_attribute(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			key: Vec<u8>,
+			value: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_update_token(&who, token, |_, attributes| {
+				attributes.insert(key.clone(), value.clone());
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::SetTokenAttribute(token.0, token.1, key, value));
+			Ok(())
+		}
+
+		/// Remove an attribute of NFT token. The class properties must
+		/// contains TokenAttributesMutable for the class owner, or
+		/// TokenAttributesMutableByOwner for the token owner. The data deposit
+		/// of the token is adjusted.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `key`: The key of the attribute
+		#[pallet::weight(<T as Config>::WeightInfo::remove_token_attribute())]
+		#[transactional]
+		pub fn remove_token_attribute(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			key: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_update_token(&who, token, |_, attributes| {
+				attributes.remove(&key).ok_or(Error::<T>::AttributeNotFound)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::RemovedTokenAttribute(token.0, token.1, key));
+			Ok(())
+		}
+
+		/// Update the metadata of NFT token. The class properties must contains
+		/// TokenAttributesMutable for the class owner, or
+		/// TokenAttributesMutableByOwner for the token owner. The data deposit
+		/// of the token is adjusted.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `metadata`: The new external metadata
+		#[pallet::weight(<T as Config>::WeightInfo::update_token_metadata())]
+		#[transactional]
+		pub fn update_token_metadata(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			metadata: CID,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_update_token(&who, token, |token_metadata, _| {
+				*token_metadata = metadata.clone();
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::UpdatedTokenMetadata(token.0, token.1, metadata));
+			Ok(())
+		}
 	}
 }
 
@@ -393,6 +746,12 @@ impl<T: Config> Pallet<T> {
 			data.properties.0.contains(ClassProperty::Transferable),
 			Error::<T>::NonTransferable
 		);
+		if let Some(until) = Self::token_transfer_locks(token.0, token.1) {
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= until,
+				Error::<T>::TransferLocked
+			);
+		}
 
 		let token_info = orml_nft::Pallet::<T>::tokens(token.0, token.1).ok_or(Error::<T>::TokenIdNotFound)?;
 
@@ -442,6 +801,55 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Update the metadata and attributes of a token by `f`, and adjust its
+	/// data deposit. The extra deposit is paid by `who` and reserved on the
+	/// token owner, the released deposit is unreserved to the token owner.
+	#[require_transactional]
+	fn do_update_token(
+		who: &T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		f: impl FnOnce(&mut CID, &mut Attributes) -> DispatchResult,
+	) -> DispatchResult {
+		let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::ClassIdNotFound)?;
+		let properties = class_info.data.properties.0;
+		ensure!(
+			properties.intersects(ClassProperty::TokenAttributesMutable | ClassProperty::TokenAttributesMutableByOwner),
+			Error::<T>::Immutable
+		);
+
+		orml_nft::Tokens::<T>::try_mutate(token.0, token.1, |maybe_token_info| -> DispatchResult {
+			let token_info = maybe_token_info.as_mut().ok_or(Error::<T>::TokenIdNotFound)?;
+			ensure!(
+				(*who == class_info.owner && properties.contains(ClassProperty::TokenAttributesMutable))
+					|| (*who == token_info.owner && properties.contains(ClassProperty::TokenAttributesMutableByOwner)),
+				Error::<T>::NoPermission
+			);
+
+			let mut metadata = token_info.metadata.to_vec();
+			let mut attributes = token_info.data.attributes.clone();
+			f(&mut metadata, &mut attributes)?;
+
+			let deposit = T::CreateTokenDeposit::get().saturating_add(Self::data_deposit(&metadata, &attributes)?);
+			let owner = &token_info.owner;
+			if deposit > token_info.data.deposit {
+				let extra = deposit.saturating_sub(token_info.data.deposit);
+				if who != owner {
+					<T as module::Config>::Currency::transfer(who, owner, extra, KeepAlive)?;
+				}
+				<T as module::Config>::Currency::reserve_named(&RESERVE_ID, owner, extra)?;
+			} else {
+				let released = token_info.data.deposit.saturating_sub(deposit);
+				<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, owner, released);
+			}
+
+			token_info.metadata = metadata
+				.try_into()
+				.map_err(|_| orml_nft::Error::<T>::MaxMetadataExceeded)?;
+			token_info.data = TokenData { deposit, attributes };
+			Ok(())
+		})
+	}
+
 	fn do_burn(who: T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>), remark: Option<Vec<u8>>) -> DispatchResult {
 		let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::ClassIdNotFound)?;
 		let data = class_info.data;
@@ -454,6 +862,7 @@ impl<T: Config> Pallet<T> {
 		ensure!(who == token_info.owner, Error::<T>::NoPermission);
 
 		orml_nft::Pallet::<T>::burn(&who, token)?;
+		TokenTransferLocks::<T>::remove(token.0, token.1);
 
 		<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, &who, token_info.data.deposit);
 
@@ -467,6 +876,30 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Split `price` of a sale of a token of `class_id` into the royalty and
+	/// the proceeds of `seller`, and pay them by `pay(recipient, amount)`.
+	/// Returns the royalty.
+	#[require_transactional]
+	pub fn pay_with_royalty(
+		class_id: ClassIdOf<T>,
+		price: Balance,
+		seller: &T::AccountId,
+		pay: impl Fn(&T::AccountId, Balance) -> DispatchResult,
+	) -> Result<Balance, DispatchError> {
+		let royalty = match Self::royalty_info(class_id, price) {
+			Some((recipient, royalty)) if recipient != *seller && !royalty.is_zero() => {
+				pay(&recipient, royalty)?;
+				royalty
+			}
+			_ => Zero::zero(),
+		};
+		let proceeds = price.saturating_sub(royalty);
+		if !proceeds.is_zero() {
+			pay(seller, proceeds)?;
+		}
+		Ok(royalty)
+	}
+
 	fn data_deposit(metadata: &[u8], attributes: &Attributes) -> Result<BalanceOf<T>, DispatchError> {
 		// Addition can't overflow because we will be out of memory before that
 		let attributes_len = attributes.iter().fold(0, |acc, (k, v)| {
@@ -500,3 +933,9 @@ impl<T: Config> NFT<T::AccountId> for Pallet<T> {
 		Self::do_transfer(from, to, token)
 	}
 }
+
+impl<T: Config> NFTRoyalty<T::AccountId, ClassIdOf<T>, Balance> for Pallet<T> {
+	fn royalty_info(class_id: ClassIdOf<T>, sale_price: Balance) -> Option<(T::AccountId, Balance)> {
+		ClassRoyalties::<T>::get(class_id).map(|royalty| (royalty.recipient, royalty.rate.mul_floor(sale_price)))
+	}
+}
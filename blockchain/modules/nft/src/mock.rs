@@ -26,7 +26,7 @@ use crate as nft;
 use codec::{Decode, Encode};
 use frame_support::{
 	construct_runtime, ord_parameter_types, parameter_types,
-	traits::{Contains, InstanceFilter},
+	traits::{Contains, InstanceFilter, Nothing},
 	RuntimeDebug,
 };
 use frame_system::EnsureSignedBy;
@@ -340,6 +340,13 @@ parameter_types! {
 	];
 }
 
+parameter_types! {
+	pub const MaxTransferBatch: u32 = 10;
+	pub const DustHistoryDepth: u32 = 84;
+	pub const Erc20TransferWeight: u64 = 1_000_000;
+	pub const MaxMemoLength: u32 = 32;
+}
+
 impl module_currencies::Config for Runtime {
 	type Event = Event;
 	type MultiCurrency = Tokens;
@@ -352,6 +359,12 @@ impl module_currencies::Config for Runtime {
 	type EVMBridge = ();
 	type SweepOrigin = EnsureSignedBy<One, AccountId>;
 	type OnDust = ();
+	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+	type CurrentEra = ();
+	type DustHistoryDepth = DustHistoryDepth;
+	type MaxTransferBatch = MaxTransferBatch;
+	type Erc20TransferWeight = Erc20TransferWeight;
+	type MaxMemoLength = MaxMemoLength;
 }
 
 parameter_types! {
@@ -360,6 +373,7 @@ parameter_types! {
 	pub const DataDepositPerByte: Balance = 10;
 	pub const NftPalletId: PalletId = PalletId(*b"set/sNFT");
 	pub MaxAttributesBytes: u32 = 10;
+	pub MaxRoyaltyRate: Permill = Permill::from_percent(50);
 }
 impl Config for Runtime {
 	type Event = Event;
@@ -369,6 +383,9 @@ impl Config for Runtime {
 	type DataDepositPerByte = DataDepositPerByte;
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = MaxAttributesBytes;
+	type MultiCurrency = Currency;
+	type MaxRoyaltyRate = MaxRoyaltyRate;
+	type CustodyAccounts = Nothing;
 	type WeightInfo = ();
 }
 
@@ -398,7 +415,7 @@ construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		NFTModule: nft::{Pallet, Call, Event<T>},
+		NFTModule: nft::{Pallet, Call, Storage, Event<T>},
 		OrmlNFT: orml_nft::{Pallet, Storage, Config<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>},
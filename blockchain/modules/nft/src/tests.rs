@@ -711,3 +711,692 @@ fn update_class_properties_should_work() {
 		);
 	});
 }
+
+#[test]
+fn set_class_royalty_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			vec![1],
+			Properties(ClassProperty::Transferable | ClassProperty::ClassPropertiesMutable),
+			Default::default(),
+		));
+
+		let royalty = Royalty {
+			recipient: ALICE,
+			rate: Permill::from_percent(10),
+		};
+		assert_ok!(NFTModule::set_class_royalty(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			Some(royalty.clone())
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::UpdatedClassRoyalty(
+			CLASS_ID,
+			Some(royalty.clone()),
+		)));
+		assert_eq!(NFTModule::class_royalties(CLASS_ID), Some(royalty));
+		assert_eq!(
+			<NFTModule as NFTRoyalty<_, _, _>>::royalty_info(CLASS_ID, 1000),
+			Some((ALICE, 100))
+		);
+
+		assert_ok!(NFTModule::set_class_royalty(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			None
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::UpdatedClassRoyalty(CLASS_ID, None)));
+		assert_eq!(NFTModule::class_royalties(CLASS_ID), None);
+		assert_eq!(
+			<NFTModule as NFTRoyalty<_, _, _>>::royalty_info(CLASS_ID, 1000),
+			None
+		);
+	});
+}
+
+#[test]
+fn set_class_royalty_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			vec![1],
+			Properties(ClassProperty::Transferable.into()),
+			Default::default(),
+		));
+
+		let royalty = Royalty {
+			recipient: ALICE,
+			rate: Permill::from_percent(10),
+		};
+		assert_noop!(
+			NFTModule::set_class_royalty(Origin::signed(class_id_account()), CLASS_ID_NOT_EXIST, Some(royalty.clone())),
+			Error::<Runtime>::ClassIdNotFound
+		);
+		assert_noop!(
+			NFTModule::set_class_royalty(Origin::signed(BOB), CLASS_ID, Some(royalty.clone())),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::set_class_royalty(
+				Origin::signed(class_id_account()),
+				CLASS_ID,
+				Some(Royalty {
+					recipient: ALICE,
+					rate: Permill::from_percent(51),
+				})
+			),
+			Error::<Runtime>::RoyaltyRateTooHigh
+		);
+
+		assert_ok!(NFTModule::set_class_royalty(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			Some(royalty)
+		));
+		assert_noop!(
+			NFTModule::set_class_royalty(Origin::signed(class_id_account()), CLASS_ID, None),
+			Error::<Runtime>::Immutable
+		);
+	});
+}
+
+#[test]
+fn transfer_with_payment_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			metadata.clone(),
+			Properties(ClassProperty::Transferable | ClassProperty::Mintable),
+			Default::default(),
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			CreateTokenDeposit::get() + DataDepositPerByte::get()
+		));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			metadata,
+			Default::default(),
+			1
+		));
+		let royalty = Royalty {
+			recipient: class_id_account(),
+			rate: Permill::from_percent(10),
+		};
+		assert_ok!(NFTModule::set_class_royalty(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			Some(royalty)
+		));
+
+		assert_noop!(
+			NFTModule::transfer_with_payment(
+				Origin::signed(BOB),
+				ALICE,
+				(CLASS_ID, TOKEN_ID),
+				NATIVE_CURRENCY_ID,
+				1000
+			),
+			Error::<Runtime>::PurchaseNotApproved
+		);
+
+		// an allowance is no consent to buy the token
+		assert_ok!(module_currencies::Pallet::<Runtime>::approve(
+			Origin::signed(ALICE),
+			BOB,
+			NATIVE_CURRENCY_ID,
+			1000
+		));
+		assert_noop!(
+			NFTModule::transfer_with_payment(
+				Origin::signed(BOB),
+				ALICE,
+				(CLASS_ID, TOKEN_ID),
+				NATIVE_CURRENCY_ID,
+				1000
+			),
+			Error::<Runtime>::PurchaseNotApproved
+		);
+
+		assert_noop!(
+			NFTModule::set_purchase_approval(
+				Origin::signed(ALICE),
+				(CLASS_ID, TOKEN_ID + 1),
+				Some((NATIVE_CURRENCY_ID, 1000))
+			),
+			Error::<Runtime>::TokenIdNotFound
+		);
+		assert_ok!(NFTModule::set_purchase_approval(
+			Origin::signed(ALICE),
+			(CLASS_ID, TOKEN_ID),
+			Some((NATIVE_CURRENCY_ID, 1000))
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::UpdatedPurchaseApproval(
+			ALICE,
+			CLASS_ID,
+			TOKEN_ID,
+			Some((NATIVE_CURRENCY_ID, 1000)),
+		)));
+
+		// above the approved price
+		assert_noop!(
+			NFTModule::transfer_with_payment(
+				Origin::signed(BOB),
+				ALICE,
+				(CLASS_ID, TOKEN_ID),
+				NATIVE_CURRENCY_ID,
+				1001
+			),
+			Error::<Runtime>::PurchaseNotApproved
+		);
+		let alice_free = free_balance(&ALICE);
+		let bob_free = free_balance(&BOB);
+		let recipient_free = free_balance(&class_id_account());
+
+		assert_ok!(NFTModule::transfer_with_payment(
+			Origin::signed(BOB),
+			ALICE,
+			(CLASS_ID, TOKEN_ID),
+			NATIVE_CURRENCY_ID,
+			1000
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::TransferredTokenWithPayment(
+			BOB,
+			ALICE,
+			CLASS_ID,
+			TOKEN_ID,
+			NATIVE_CURRENCY_ID,
+			1000,
+			100,
+		)));
+		assert_eq!(
+			orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, TOKEN_ID).map(|t| t.owner),
+			Some(ALICE)
+		);
+		assert_eq!(free_balance(&ALICE), alice_free - 1000);
+		assert_eq!(free_balance(&BOB), bob_free + 900);
+		assert_eq!(free_balance(&class_id_account()), recipient_free + 100);
+		assert_eq!(NFTModule::purchase_approvals((CLASS_ID, TOKEN_ID), ALICE), None);
+		// the allowance is untouched
+		assert_eq!(
+			module_currencies::Pallet::<Runtime>::allowances(ALICE, (BOB, NATIVE_CURRENCY_ID)),
+			1000
+		);
+	});
+}
+
+#[test]
+fn purchase_approval_is_bound_to_seller() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			metadata.clone(),
+			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+			Default::default(),
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			CreateTokenDeposit::get() + DataDepositPerByte::get()
+		));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			metadata,
+			Default::default(),
+			1
+		));
+
+		assert_ok!(NFTModule::set_purchase_approval(
+			Origin::signed(ALICE),
+			(CLASS_ID, TOKEN_ID),
+			Some((NATIVE_CURRENCY_ID, 1000))
+		));
+		assert_eq!(
+			NFTModule::purchase_approvals((CLASS_ID, TOKEN_ID), ALICE),
+			Some((BOB, NATIVE_CURRENCY_ID, 1000))
+		);
+
+		// the next owner can't use the approval given for BOB's token
+		assert_ok!(NFTModule::transfer(
+			Origin::signed(BOB),
+			class_id_account(),
+			(CLASS_ID, TOKEN_ID)
+		));
+		assert_noop!(
+			NFTModule::transfer_with_payment(
+				Origin::signed(class_id_account()),
+				ALICE,
+				(CLASS_ID, TOKEN_ID),
+				NATIVE_CURRENCY_ID,
+				1000
+			),
+			Error::<Runtime>::PurchaseNotApproved
+		);
+
+		// the approval can be cancelled after the token is burned
+		assert_ok!(NFTModule::burn(Origin::signed(class_id_account()), (CLASS_ID, TOKEN_ID)));
+		assert_ok!(NFTModule::set_purchase_approval(
+			Origin::signed(ALICE),
+			(CLASS_ID, TOKEN_ID),
+			None
+		));
+		assert_eq!(NFTModule::purchase_approvals((CLASS_ID, TOKEN_ID), ALICE), None);
+	});
+}
+
+#[test]
+fn revoke_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			metadata.clone(),
+			Properties(ClassProperty::Mintable | ClassProperty::Revocable),
+			Default::default(),
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			CreateTokenDeposit::get() + DataDepositPerByte::get()
+		));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			metadata,
+			Default::default(),
+			1
+		));
+		assert_eq!(
+			reserved_balance(&BOB),
+			CreateTokenDeposit::get() + DataDepositPerByte::get()
+		);
+
+		// soulbound: not transferable, but revocable by the class owner
+		assert_noop!(
+			NFTModule::transfer(Origin::signed(BOB), ALICE, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NonTransferable
+		);
+		assert_noop!(
+			NFTModule::revoke(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::revoke(Origin::signed(class_id_account()), (CLASS_ID, TOKEN_ID_NOT_EXIST)),
+			Error::<Runtime>::TokenIdNotFound
+		);
+
+		assert_ok!(NFTModule::revoke(Origin::signed(class_id_account()), (CLASS_ID, TOKEN_ID)));
+		System::assert_last_event(Event::NFTModule(crate::Event::RevokedToken(
+			class_id_account(),
+			BOB,
+			CLASS_ID,
+			TOKEN_ID,
+		)));
+		assert_eq!(reserved_balance(&BOB), 0);
+		assert!(orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, TOKEN_ID).is_none());
+	});
+}
+
+#[test]
+fn revoke_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			metadata.clone(),
+			Properties(ClassProperty::Transferable | ClassProperty::Mintable),
+			Default::default(),
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			CreateTokenDeposit::get() + DataDepositPerByte::get()
+		));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			metadata,
+			Default::default(),
+			1
+		));
+
+		assert_noop!(
+			NFTModule::revoke(Origin::signed(class_id_account()), (CLASS_ID_NOT_EXIST, TOKEN_ID)),
+			Error::<Runtime>::ClassIdNotFound
+		);
+		assert_noop!(
+			NFTModule::revoke(Origin::signed(class_id_account()), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NonRevocable
+		);
+	});
+}
+
+#[test]
+fn token_transfer_lock_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			metadata.clone(),
+			Properties(
+				ClassProperty::Transferable
+					| ClassProperty::Burnable
+					| ClassProperty::Mintable
+					| ClassProperty::TransferLockable
+			),
+			Default::default(),
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			CreateTokenDeposit::get() + DataDepositPerByte::get()
+		));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			metadata,
+			Default::default(),
+			1
+		));
+
+		assert_noop!(
+			NFTModule::set_token_transfer_lock(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), Some(10)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::set_token_transfer_lock(
+				Origin::signed(class_id_account()),
+				(CLASS_ID, TOKEN_ID_NOT_EXIST),
+				Some(10)
+			),
+			Error::<Runtime>::TokenIdNotFound
+		);
+
+		assert_ok!(NFTModule::set_token_transfer_lock(
+			Origin::signed(class_id_account()),
+			(CLASS_ID, TOKEN_ID),
+			Some(10)
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::UpdatedTokenTransferLock(
+			CLASS_ID,
+			TOKEN_ID,
+			Some(10),
+		)));
+		assert_eq!(NFTModule::token_transfer_locks(CLASS_ID, TOKEN_ID), Some(10));
+
+		assert_noop!(
+			NFTModule::transfer(Origin::signed(BOB), ALICE, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::TransferLocked
+		);
+
+		System::set_block_number(10);
+		assert_ok!(NFTModule::transfer(Origin::signed(BOB), ALICE, (CLASS_ID, TOKEN_ID)));
+
+		assert_ok!(NFTModule::set_token_transfer_lock(
+			Origin::signed(class_id_account()),
+			(CLASS_ID, TOKEN_ID),
+			Some(20)
+		));
+		assert_noop!(
+			NFTModule::transfer(Origin::signed(ALICE), BOB, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::TransferLocked
+		);
+		assert_ok!(NFTModule::set_token_transfer_lock(
+			Origin::signed(class_id_account()),
+			(CLASS_ID, TOKEN_ID),
+			None
+		));
+		assert_eq!(NFTModule::token_transfer_locks(CLASS_ID, TOKEN_ID), None);
+		assert_ok!(NFTModule::transfer(Origin::signed(ALICE), BOB, (CLASS_ID, TOKEN_ID)));
+
+		assert_ok!(NFTModule::set_token_transfer_lock(
+			Origin::signed(class_id_account()),
+			(CLASS_ID, TOKEN_ID),
+			Some(20)
+		));
+		assert_ok!(NFTModule::burn(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)));
+		assert_eq!(NFTModule::token_transfer_locks(CLASS_ID, TOKEN_ID), None);
+	});
+}
+
+#[test]
+fn token_transfer_lock_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			metadata.clone(),
+			Properties(ClassProperty::Transferable | ClassProperty::Mintable),
+			Default::default(),
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			CreateTokenDeposit::get() + DataDepositPerByte::get()
+		));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			metadata,
+			Default::default(),
+			1
+		));
+
+		assert_noop!(
+			NFTModule::set_token_transfer_lock(Origin::signed(class_id_account()), (CLASS_ID, TOKEN_ID), Some(10)),
+			Error::<Runtime>::NonTransferLockable
+		);
+		// unlocking is always allowed
+		assert_ok!(NFTModule::set_token_transfer_lock(
+			Origin::signed(class_id_account()),
+			(CLASS_ID, TOKEN_ID),
+			None
+		));
+		assert_ok!(NFTModule::transfer(Origin::signed(BOB), ALICE, (CLASS_ID, TOKEN_ID)));
+	});
+}
+
+#[test]
+fn set_token_attribute_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			metadata.clone(),
+			Properties(ClassProperty::Mintable | ClassProperty::TokenAttributesMutable),
+			Default::default(),
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			CreateTokenDeposit::get() + DataDepositPerByte::get() + 1000
+		));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			metadata,
+			Default::default(),
+			1
+		));
+		let deposit = CreateTokenDeposit::get() + DataDepositPerByte::get();
+		assert_eq!(reserved_balance(&BOB), deposit);
+
+		assert_noop!(
+			NFTModule::set_token_attribute(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), vec![1], vec![2]),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::set_token_attribute(
+				Origin::signed(class_id_account()),
+				(CLASS_ID, TOKEN_ID_NOT_EXIST),
+				vec![1],
+				vec![2]
+			),
+			Error::<Runtime>::TokenIdNotFound
+		);
+		assert_noop!(
+			NFTModule::set_token_attribute(
+				Origin::signed(class_id_account()),
+				(CLASS_ID, TOKEN_ID),
+				vec![1],
+				vec![0; 10]
+			),
+			Error::<Runtime>::AttributesTooLarge
+		);
+
+		assert_ok!(NFTModule::set_token_attribute(
+			Origin::signed(class_id_account()),
+			(CLASS_ID, TOKEN_ID),
+			vec![1],
+			vec![2]
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::SetTokenAttribute(
+			CLASS_ID,
+			TOKEN_ID,
+			vec![1],
+			vec![2],
+		)));
+		let token_info = orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, TOKEN_ID).unwrap();
+		assert_eq!(token_info.data.attributes.get(&vec![1]), Some(&vec![2]));
+		assert_eq!(token_info.data.deposit, deposit + 2 * DataDepositPerByte::get());
+		assert_eq!(reserved_balance(&BOB), deposit + 2 * DataDepositPerByte::get());
+
+		assert_ok!(NFTModule::remove_token_attribute(
+			Origin::signed(class_id_account()),
+			(CLASS_ID, TOKEN_ID),
+			vec![1]
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::RemovedTokenAttribute(
+			CLASS_ID,
+			TOKEN_ID,
+			vec![1],
+		)));
+		let token_info = orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, TOKEN_ID).unwrap();
+		assert!(token_info.data.attributes.is_empty());
+		assert_eq!(token_info.data.deposit, deposit);
+		assert_eq!(reserved_balance(&BOB), deposit);
+		assert_eq!(free_balance(&BOB), 2 * DataDepositPerByte::get());
+
+		assert_noop!(
+			NFTModule::remove_token_attribute(Origin::signed(class_id_account()), (CLASS_ID, TOKEN_ID), vec![1]),
+			Error::<Runtime>::AttributeNotFound
+		);
+
+		assert_ok!(NFTModule::update_token_metadata(
+			Origin::signed(class_id_account()),
+			(CLASS_ID, TOKEN_ID),
+			vec![1, 2, 3]
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::UpdatedTokenMetadata(
+			CLASS_ID,
+			TOKEN_ID,
+			vec![1, 2, 3],
+		)));
+		let token_info = orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, TOKEN_ID).unwrap();
+		assert_eq!(token_info.metadata.to_vec(), vec![1, 2, 3]);
+		assert_eq!(token_info.data.deposit, deposit + 2 * DataDepositPerByte::get());
+		assert_eq!(reserved_balance(&BOB), deposit + 2 * DataDepositPerByte::get());
+	});
+}
+
+#[test]
+fn set_token_attribute_by_owner_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			metadata.clone(),
+			Properties(ClassProperty::Mintable | ClassProperty::TokenAttributesMutableByOwner),
+			Default::default(),
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			CreateTokenDeposit::get() + DataDepositPerByte::get()
+		));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			metadata,
+			Default::default(),
+			1
+		));
+		assert_ok!(Balances::deposit_into_existing(&BOB, 100));
+
+		assert_noop!(
+			NFTModule::set_token_attribute(
+				Origin::signed(class_id_account()),
+				(CLASS_ID, TOKEN_ID),
+				vec![1],
+				vec![2]
+			),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(NFTModule::set_token_attribute(
+			Origin::signed(BOB),
+			(CLASS_ID, TOKEN_ID),
+			vec![1],
+			vec![2]
+		));
+		assert_eq!(
+			reserved_balance(&BOB),
+			CreateTokenDeposit::get() + 3 * DataDepositPerByte::get()
+		);
+		assert_eq!(free_balance(&BOB), 100 - 2 * DataDepositPerByte::get());
+	});
+}
+
+#[test]
+fn set_token_attribute_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			metadata.clone(),
+			Properties(ClassProperty::Mintable.into()),
+			Default::default(),
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			CreateTokenDeposit::get() + DataDepositPerByte::get()
+		));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			metadata,
+			Default::default(),
+			1
+		));
+
+		assert_noop!(
+			NFTModule::set_token_attribute(
+				Origin::signed(class_id_account()),
+				(CLASS_ID_NOT_EXIST, TOKEN_ID),
+				vec![1],
+				vec![2]
+			),
+			Error::<Runtime>::ClassIdNotFound
+		);
+		assert_noop!(
+			NFTModule::set_token_attribute(
+				Origin::signed(class_id_account()),
+				(CLASS_ID, TOKEN_ID),
+				vec![1],
+				vec![2]
+			),
+			Error::<Runtime>::Immutable
+		);
+		assert_noop!(
+			NFTModule::update_token_metadata(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), vec![2]),
+			Error::<Runtime>::Immutable
+		);
+	});
+}
@@ -0,0 +1,376 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! # Oracle Feeder Module
+//!
+//! ## Overview
+//!
+//! The oracle feeder module runs an offchain worker on the nodes of the
+//! oracle operators, which fetches the prices of the feed currencies from
+//! local HTTP/JSON sources and feeds them to `SetheumOracle` by a signed
+//! `feed_values`.
+//!
+//! The sources are a comma separated list of URLs in the persistent offchain
+//! local storage under `SOURCES_KEY`, which can be set by the
+//! `offchain_localStorageSet` RPC. Every source responds a JSON object which
+//! maps the token symbols to their prices in USD, e.g. `{"SEE": 1.25}`, and
+//! the median of the prices from all the sources is fed. The transaction is
+//! signed by the key of `KEY_TYPE` in the keystore of the node, the account of
+//! which must be a member of the oracle operators.
+//!
+//! The feed currencies are set by governance.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::{
+	offchain::{AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer},
+	pallet_prelude::*,
+};
+use lite_json::{json::NumberValue, JsonValue};
+use primitives::{CurrencyId, TokenInfo};
+use sp_runtime::{
+	offchain::{
+		http,
+		storage_lock::{StorageLock, Time},
+		Duration, StorageKind,
+	},
+	traits::Zero,
+	FixedPointNumber,
+};
+use sp_std::prelude::*;
+use support::Price;
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+pub const OFFCHAIN_WORKER_LOCK: &[u8] = b"setheum/oracle-feeder/lock/";
+pub const SOURCES_KEY: &[u8] = b"setheum/oracle-feeder/sources/";
+pub const LOCK_DURATION: u64 = 100;
+
+/// The key type of the oracle operators in the keystore.
+pub const KEY_TYPE: sp_runtime::KeyTypeId = sp_runtime::KeyTypeId(*b"orac");
+
+/// The crypto of the keys of the oracle operators.
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_core::sr25519::Signature as Sr25519Signature;
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		traits::Verify,
+		MultiSignature, MultiSigner,
+	};
+	app_crypto!(sr25519, KEY_TYPE);
+
+	pub struct OracleAuthId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for OracleAuthId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+
+	// implemented for mock runtime in test
+	impl frame_system::offchain::AppCrypto<<Sr25519Signature as Verify>::Signer, Sr25519Signature> for OracleAuthId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+}
+
+/// The oracle instance fed by the module.
+pub type OracleInstance = orml_oracle::Instance1;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config
+		+ CreateSignedTransaction<orml_oracle::Call<Self, OracleInstance>>
+		+ orml_oracle::Config<OracleInstance, OracleKey = CurrencyId, OracleValue = Price>
+	{
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The identifier type for the offchain worker.
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+		/// The origin which may update the feed currencies.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The maximum number of the feed currencies.
+		#[pallet::constant]
+		type MaxFeedCurrencies: Get<u32>;
+
+		/// The offchain worker feeds the prices every `FeedPeriod` blocks.
+		#[pallet::constant]
+		type FeedPeriod: Get<Self::BlockNumber>;
+
+		/// The timeout of the HTTP requests to the sources, in milliseconds.
+		#[pallet::constant]
+		type HttpRequestTimeout: Get<u64>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The feed currencies exceed `MaxFeedCurrencies`
+		TooManyCurrencies,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Updated the feed currencies. \[currency_ids\]
+		FeedCurrenciesUpdated(Vec<CurrencyId>),
+	}
+
+	/// The currencies of which the offchain worker feeds the prices.
+	///
+	/// FeedCurrencies: Vec<CurrencyId>
+	#[pallet::storage]
+	#[pallet::getter(fn feed_currencies)]
+	pub type FeedCurrencies<T: Config> = StorageValue<_, Vec<CurrencyId>, ValueQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Runs after every block.
+		fn offchain_worker(now: T::BlockNumber) {
+			if let Err(e) = Self::_offchain_worker(now) {
+				log::info!(
+					target: "oracle-feeder offchain worker",
+					"cannot run offchain worker at {:?}: {:?}",
+					now,
+					e,
+				);
+			} else {
+				log::debug!(
+					target: "oracle-feeder offchain worker",
+					"offchain worker start at block: {:?} already done!",
+					now,
+				);
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the currencies of which the offchain worker feeds the prices.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_ids`: the feed currencies.
+		#[pallet::weight((<T as Config>::WeightInfo::set_feed_currencies(currency_ids.len() as u32), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_feed_currencies(origin: OriginFor<T>, mut currency_ids: Vec<CurrencyId>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			currency_ids.sort();
+			currency_ids.dedup();
+			ensure!(
+				currency_ids.len() as u32 <= T::MaxFeedCurrencies::get(),
+				Error::<T>::TooManyCurrencies
+			);
+
+			FeedCurrencies::<T>::put(currency_ids.clone());
+			Self::deposit_event(Event::FeedCurrenciesUpdated(currency_ids));
+			Ok(())
+		}
+	}
+}
+
+/// Error which may occur while executing the off-chain code.
+enum OffchainErr {
+	OffchainLock,
+	NoSigningKey,
+	NoSources,
+	SubmitTransaction,
+}
+
+impl sp_std::fmt::Debug for OffchainErr {
+	fn fmt(&self, fmt: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		match *self {
+			OffchainErr::OffchainLock => write!(fmt, "Failed to get or extend lock"),
+			OffchainErr::NoSigningKey => write!(fmt, "No oracle key in the keystore"),
+			OffchainErr::NoSources => write!(fmt, "No price sources in the local storage"),
+			OffchainErr::SubmitTransaction => write!(fmt, "Failed to submit transaction"),
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn _offchain_worker(now: T::BlockNumber) -> Result<(), OffchainErr> {
+		let feed_period = T::FeedPeriod::get();
+		if feed_period.is_zero() || !(now % feed_period).is_zero() {
+			return Ok(());
+		}
+
+		let currency_ids = Self::feed_currencies();
+		if currency_ids.is_empty() {
+			return Ok(());
+		}
+
+		let signer = Signer::<T, T::AuthorityId>::any_account();
+		if !signer.can_sign() {
+			return Err(OffchainErr::NoSigningKey);
+		}
+
+		// acquire offchain worker lock
+		let mut lock = StorageLock::<'_, Time>::with_deadline(
+			OFFCHAIN_WORKER_LOCK,
+			Duration::from_millis(LOCK_DURATION),
+		);
+		let _guard = lock.try_lock().map_err(|_| OffchainErr::OffchainLock)?;
+
+		let sources = Self::sources().ok_or(OffchainErr::NoSources)?;
+		let quotes = sources
+			.iter()
+			.filter_map(|url| match Self::fetch_prices(url) {
+				Ok(prices) => Some(prices),
+				Err(e) => {
+					log::warn!(
+						target: "oracle-feeder offchain worker",
+						"failed to fetch prices from {:?}: {:?}",
+						url,
+						e,
+					);
+					None
+				}
+			})
+			.collect::<Vec<_>>();
+
+		let values = currency_ids
+			.into_iter()
+			.filter_map(|currency_id| {
+				let symbol = currency_id.symbol()?;
+				let prices = quotes
+					.iter()
+					.filter_map(|prices| {
+						prices
+							.iter()
+							.find(|(key, _)| key[..] == *symbol.as_bytes())
+							.map(|(_, price)| *price)
+					})
+					.collect::<Vec<_>>();
+				Self::median(prices).map(|price| (currency_id, price))
+			})
+			.collect::<Vec<_>>();
+		if values.is_empty() {
+			return Ok(());
+		}
+
+		match signer.send_signed_transaction(|_| orml_oracle::Call::feed_values(values.clone())) {
+			Some((_, Ok(()))) => Ok(()),
+			_ => Err(OffchainErr::SubmitTransaction),
+		}
+	}
+
+	/// The URLs of the price sources in the offchain local storage.
+	fn sources() -> Option<Vec<Vec<u8>>> {
+		let sources = sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, SOURCES_KEY)?;
+		let sources = sources
+			.split(|c| *c == b',')
+			.map(|url| url.iter().copied().filter(|c| !c.is_ascii_whitespace()).collect::<Vec<u8>>())
+			.filter(|url| !url.is_empty())
+			.collect::<Vec<_>>();
+		if sources.is_empty() {
+			None
+		} else {
+			Some(sources)
+		}
+	}
+
+	/// Fetch the prices from the source at `url`.
+	fn fetch_prices(url: &[u8]) -> Result<Vec<(Vec<u8>, Price)>, http::Error> {
+		let url = sp_std::str::from_utf8(url).map_err(|_| http::Error::Unknown)?;
+		let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(T::HttpRequestTimeout::get()));
+		let pending = http::Request::get(url)
+			.deadline(deadline)
+			.send()
+			.map_err(|_| http::Error::IoError)?;
+		let response = pending.try_wait(deadline).map_err(|_| http::Error::DeadlineReached)??;
+		if response.code != 200 {
+			return Err(http::Error::Unknown);
+		}
+
+		let body = response.body().collect::<Vec<u8>>();
+		Self::parse_prices(&body).ok_or(http::Error::Unknown)
+	}
+
+	/// Parse the prices from a JSON object which maps the token symbols to
+	/// their prices, the non-positive prices are ignored.
+	pub fn parse_prices(body: &[u8]) -> Option<Vec<(Vec<u8>, Price)>> {
+		let body = sp_std::str::from_utf8(body).ok()?;
+		match lite_json::parse_json(body).ok()? {
+			JsonValue::Object(object) => Some(
+				object
+					.into_iter()
+					.filter(|(key, _)| key.iter().all(|c| c.is_ascii()))
+					.filter_map(|(key, value)| match value {
+						JsonValue::Number(number) => Self::number_to_price(number)
+							.filter(|price| !price.is_zero())
+							.map(|price| (key.into_iter().map(|c| c as u8).collect(), price)),
+						_ => None,
+					})
+					.collect(),
+			),
+			_ => None,
+		}
+	}
+
+	fn number_to_price(number: NumberValue) -> Option<Price> {
+		if number.negative {
+			return None;
+		}
+
+		let decimals: u32 = 18;
+		let integer = (number.integer as u128).checked_mul(Price::accuracy())?;
+		let fraction = if number.fraction_length <= decimals {
+			(number.fraction as u128).checked_mul(10u128.checked_pow(decimals - number.fraction_length)?)?
+		} else {
+			(number.fraction as u128) / 10u128.checked_pow(number.fraction_length - decimals)?
+		};
+		let inner = integer.checked_add(fraction)?;
+		let inner = if number.exponent >= 0 {
+			inner.checked_mul(10u128.checked_pow(number.exponent as u32)?)?
+		} else {
+			inner / 10u128.checked_pow(number.exponent.checked_neg()? as u32)?
+		};
+		Some(Price::from_inner(inner))
+	}
+
+	/// The median of the prices, as `orml_oracle::DefaultCombineData` does.
+	fn median(mut prices: Vec<Price>) -> Option<Price> {
+		if prices.is_empty() {
+			return None;
+		}
+		prices.sort();
+		Some(prices[prices.len() / 2])
+	}
+}
@@ -0,0 +1,213 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Unit tests for the oracle feeder module.
+
+#![cfg(test)]
+
+use super::*;
+use codec::Decode;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use parking_lot::RwLock;
+use primitives::TokenSymbol;
+use sp_core::offchain::{
+	testing::{OffchainState, PendingRequest, PoolState, TestOffchainExt, TestTransactionPoolExt},
+	OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+};
+use sp_keystore::{testing::KeyStore, KeystoreExt, SyncCryptoStore};
+use sp_runtime::{traits::BadOrigin, RuntimeAppPublic};
+use std::sync::Arc;
+
+const PHRASE: &str = "news slush supreme milk chapter athlete soap sausage put clutch what kitten";
+
+fn offchain_ext() -> (sp_io::TestExternalities, Arc<RwLock<OffchainState>>, Arc<RwLock<PoolState>>) {
+	let (offchain, offchain_state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let keystore = KeyStore::new();
+	SyncCryptoStore::sr25519_generate_new(&keystore, crypto::Public::ID, Some(&format!("{}/oracle", PHRASE))).unwrap();
+
+	let mut ext = ExtBuilder::default().build();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(OffchainDbExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(KeystoreExt(Arc::new(keystore)));
+	(ext, offchain_state, pool_state)
+}
+
+fn expect_request(state: &Arc<RwLock<OffchainState>>, uri: &str, response: &[u8]) {
+	state.write().expect_request(PendingRequest {
+		method: "GET".into(),
+		uri: uri.into(),
+		response: Some(response.to_vec()),
+		sent: true,
+		..Default::default()
+	});
+}
+
+fn set_sources(sources: &[u8]) {
+	sp_io::offchain::local_storage_set(StorageKind::PERSISTENT, SOURCES_KEY, sources);
+}
+
+#[test]
+fn set_feed_currencies_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			OracleFeederModule::set_feed_currencies(Origin::signed(RootOperatorAccountId::get()), vec![SEE]),
+			BadOrigin
+		);
+		assert_noop!(
+			OracleFeederModule::set_feed_currencies(
+				Origin::root(),
+				vec![SEE, SERP, DNAR, CurrencyId::Token(TokenSymbol::SETUSD)]
+			),
+			Error::<Runtime>::TooManyCurrencies
+		);
+
+		assert_ok!(OracleFeederModule::set_feed_currencies(Origin::root(), vec![SEE, SEE]));
+		System::assert_last_event(Event::OracleFeederModule(crate::Event::FeedCurrenciesUpdated(vec![SEE])));
+		assert_eq!(OracleFeederModule::feed_currencies(), vec![SEE]);
+
+		assert_ok!(OracleFeederModule::set_feed_currencies(Origin::root(), vec![]));
+		assert_eq!(OracleFeederModule::feed_currencies(), vec![]);
+	});
+}
+
+#[test]
+fn parse_prices_work() {
+	assert_eq!(
+		OracleFeederModule::parse_prices(
+			br#"{"SEE": 1.5, "SERP": 20, "DNAR": "3", "SETM": -1, "SETR": 2.5e-1, "SETUSD": 0}"#
+		),
+		Some(vec![
+			(b"SEE".to_vec(), Price::saturating_from_rational(3, 2)),
+			(b"SERP".to_vec(), Price::saturating_from_integer(20)),
+			(b"SETR".to_vec(), Price::saturating_from_rational(1, 4)),
+		])
+	);
+	assert_eq!(
+		OracleFeederModule::parse_prices(br#"{"SEE": 0.000000000000000000001}"#),
+		Some(vec![])
+	);
+	assert_eq!(OracleFeederModule::parse_prices(br#"[1.5]"#), None);
+	assert_eq!(OracleFeederModule::parse_prices(b"oops"), None);
+}
+
+#[test]
+fn offchain_worker_feeds_median_prices() {
+	let (mut ext, offchain_state, pool_state) = offchain_ext();
+	ext.execute_with(|| {
+		assert_ok!(OracleFeederModule::set_feed_currencies(Origin::root(), vec![SEE, SERP, DNAR]));
+		set_sources(b"http://localhost:8080/prices, http://localhost:8081/prices,http://localhost:8082/prices");
+		expect_request(&offchain_state, "http://localhost:8080/prices", br#"{"SEE": 1.0, "SERP": 10}"#);
+		expect_request(&offchain_state, "http://localhost:8081/prices", br#"{"SEE": 1.2, "SERP": 12}"#);
+		expect_request(&offchain_state, "http://localhost:8082/prices", br#"{"SEE": 1.1, "#);
+
+		OracleFeederModule::offchain_worker(5);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		assert!(pool_state.read().transactions.is_empty());
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(tx.signature, Some((0, ())));
+		match tx.call {
+			Call::SetheumOracle(orml_oracle::Call::feed_values(mut values)) => {
+				values.sort();
+				let mut expected = vec![
+					(SEE, Price::saturating_from_rational(6, 5)),
+					(SERP, Price::saturating_from_integer(12)),
+				];
+				expected.sort();
+				assert_eq!(values, expected);
+			}
+			_ => panic!("unexpected call"),
+		}
+	});
+}
+
+#[test]
+fn offchain_worker_aggregates_median_of_all_sources() {
+	let (mut ext, offchain_state, pool_state) = offchain_ext();
+	ext.execute_with(|| {
+		assert_ok!(OracleFeederModule::set_feed_currencies(Origin::root(), vec![SEE]));
+		set_sources(b"http://localhost:8080/prices,http://localhost:8081/prices,http://localhost:8082/prices");
+		expect_request(&offchain_state, "http://localhost:8080/prices", br#"{"SEE": 1.0}"#);
+		expect_request(&offchain_state, "http://localhost:8081/prices", br#"{"SEE": 1.2}"#);
+		expect_request(&offchain_state, "http://localhost:8082/prices", br#"{"SEE": 1.1}"#);
+
+		OracleFeederModule::offchain_worker(10);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(
+			tx.call,
+			Call::SetheumOracle(orml_oracle::Call::feed_values(vec![(
+				SEE,
+				Price::saturating_from_rational(11, 10)
+			)]))
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_skips_without_config() {
+	let (mut ext, offchain_state, pool_state) = offchain_ext();
+	ext.execute_with(|| {
+		// no feed currencies
+		set_sources(b"http://localhost:8080/prices");
+		OracleFeederModule::offchain_worker(5);
+		assert!(pool_state.read().transactions.is_empty());
+
+		// not the feed period
+		assert_ok!(OracleFeederModule::set_feed_currencies(Origin::root(), vec![SEE]));
+		OracleFeederModule::offchain_worker(6);
+		assert!(pool_state.read().transactions.is_empty());
+
+		// no prices of the feed currencies
+		expect_request(&offchain_state, "http://localhost:8080/prices", br#"{"SERP": 10}"#);
+		OracleFeederModule::offchain_worker(10);
+		assert!(pool_state.read().transactions.is_empty());
+
+		// no sources
+		set_sources(b" , ");
+		OracleFeederModule::offchain_worker(15);
+		assert!(pool_state.read().transactions.is_empty());
+	});
+}
+
+#[test]
+fn offchain_worker_requires_oracle_key() {
+	let (offchain, offchain_state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut ext = ExtBuilder::default().build();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(OffchainDbExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(KeystoreExt(Arc::new(KeyStore::new())));
+
+	ext.execute_with(|| {
+		assert_ok!(OracleFeederModule::set_feed_currencies(Origin::root(), vec![SEE]));
+		set_sources(b"http://localhost:8080/prices");
+		assert!(matches!(
+			OracleFeederModule::_offchain_worker(5),
+			Err(OffchainErr::NoSigningKey)
+		));
+		assert!(pool_state.read().transactions.is_empty());
+		assert!(offchain_state.read().requests.is_empty());
+	});
+}
@@ -0,0 +1,56 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Weights for module_oracle_feeder
+//!
+//! The weights are hand estimates, not measured: the module has no benchmarks
+//! yet. `set_feed_currencies` is charged per feed currency written.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for module_oracle_feeder.
+pub trait WeightInfo {
+	fn set_feed_currencies(c: u32, ) -> Weight;
+}
+
+/// Weights for module_oracle_feeder using the Setheum node and recommended hardware.
+pub struct SetheumWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SetheumWeight<T> {
+	fn set_feed_currencies(c: u32, ) -> Weight {
+		(6_283_000 as Weight)
+			.saturating_add((37_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn set_feed_currencies(c: u32, ) -> Weight {
+		(6_283_000 as Weight)
+			.saturating_add((37_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
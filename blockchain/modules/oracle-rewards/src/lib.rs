@@ -0,0 +1,310 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! # Oracle Rewards Module
+//!
+//! ## Overview
+//!
+//! The oracle rewards module pays and measures the oracle operators. It is
+//! hooked into the `OnNewData` of the oracle, and records the deviation of
+//! every value fed by an operator from the combined value of the oracle at the
+//! time, in a round of `RoundPeriod` blocks.
+//!
+//! At the end of a round, the deviations of every operator are averaged. The
+//! operators whose average deviation is within
+//! `MaxRewardDeviation` are paid `RewardPerRound` from the treasury, and the
+//! deviation scores of all the submitting operators are updated. The members
+//! who fed nothing in the round are recorded as missing it.
+//!
+//! Governance can eject the members whose deviation score exceeds
+//! `EjectDeviationScore`, or who missed `EjectMissedRounds` rounds in a row,
+//! from the operator membership.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Currency, ExistenceRequirement::KeepAlive, SortedMembers},
+	transactional,
+};
+use frame_system::pallet_prelude::*;
+use orml_traits::{DataProvider, OnNewData};
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	traits::{Bounded, Saturating, Zero},
+	DispatchResult, FixedPointNumber, Permill, RuntimeDebug,
+};
+use sp_std::{collections::btree_map::BTreeMap, prelude::*};
+use support::{Price, Ratio};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// The record of an oracle operator.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, Default)]
+pub struct OperatorInfo {
+	/// The number of the values fed
+	pub submissions: u32,
+	/// The number of the rounds rewarded
+	pub rewarded_rounds: u32,
+	/// The number of the rounds missed in a row
+	pub missed_rounds: u32,
+	/// The moving average of the deviation of the fed values from the
+	/// combined values
+	pub deviation_score: Ratio,
+}
+
+/// The submissions of an operator in the current round.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, Default)]
+pub struct RoundSubmission {
+	/// The number of the values fed
+	pub submissions: u32,
+	/// The number of the values compared to a combined value
+	pub compared: u32,
+	/// The sum of the deviations of the compared values
+	pub total_deviation: Ratio,
+}
+
+/// Remove a member from the oracle operators.
+pub trait RemoveOperator<AccountId> {
+	fn remove_operator(who: &AccountId) -> DispatchResult;
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The currency of the rewards.
+		type Currency: Currency<Self::AccountId, Balance = Balance>;
+
+		/// The combined values of the oracle.
+		type Source: DataProvider<CurrencyId, Price>;
+
+		/// The oracle operators.
+		type Members: SortedMembers<Self::AccountId>;
+
+		/// Remove the ejected operators from the membership.
+		type RemoveOperator: RemoveOperator<Self::AccountId>;
+
+		/// The treasury account which pays the rewards.
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// The number of blocks of a round.
+		#[pallet::constant]
+		type RoundPeriod: Get<Self::BlockNumber>;
+
+		/// The reward of an operator for a round.
+		#[pallet::constant]
+		type RewardPerRound: Get<Balance>;
+
+		/// The maximum average deviation of the values of an operator in a
+		/// round to be rewarded.
+		#[pallet::constant]
+		type MaxRewardDeviation: Get<Ratio>;
+
+		/// The weight of the deviation of a round in the deviation score.
+		#[pallet::constant]
+		type DeviationScoreSmoothing: Get<Permill>;
+
+		/// The deviation score above which an operator can be ejected.
+		#[pallet::constant]
+		type EjectDeviationScore: Get<Ratio>;
+
+		/// The number of the rounds missed in a row at which an operator can
+		/// be ejected.
+		#[pallet::constant]
+		type EjectMissedRounds: Get<u32>;
+
+		/// The origin which may eject the operators.
+		type EjectOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is not an operator
+		NotOperator,
+		/// The operator is performing well
+		OperatorPerformingWell,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId")]
+	pub enum Event<T: Config> {
+		/// The operator is rewarded for the round. \[operator, amount,
+		/// deviation\]
+		OperatorRewarded(T::AccountId, Balance, Ratio),
+		/// The operator fed no values in the round. \[operator,
+		/// missed_rounds\]
+		OperatorMissedRound(T::AccountId, u32),
+		/// The operator is ejected. \[operator\]
+		OperatorEjected(T::AccountId),
+	}
+
+	/// The submissions of the operators in the current round.
+	///
+	/// RoundSubmissions: map AccountId => Option<RoundSubmission>
+	#[pallet::storage]
+	#[pallet::getter(fn round_submissions)]
+	pub type RoundSubmissions<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, RoundSubmission, OptionQuery>;
+
+	/// The records of the operators.
+	///
+	/// Operators: map AccountId => OperatorInfo
+	#[pallet::storage]
+	#[pallet::getter(fn operators)]
+	pub type Operators<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, OperatorInfo, ValueQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Close the round at the end of every `RoundPeriod`.
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let round_period = T::RoundPeriod::get();
+			if round_period.is_zero() || !(now % round_period).is_zero() {
+				return 0;
+			}
+			let count = Self::close_round();
+			<T as Config>::WeightInfo::on_initialize(count)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Eject a poorly-performing operator from the membership.
+		///
+		/// The dispatch origin of this call must be `EjectOrigin`.
+		///
+		/// - `who`: the operator, whose deviation score exceeds
+		///   `EjectDeviationScore`, or who missed `EjectMissedRounds` rounds
+		///   in a row.
+		#[pallet::weight(<T as Config>::WeightInfo::eject_operator())]
+		#[transactional]
+		pub fn eject_operator(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::EjectOrigin::ensure_origin(origin)?;
+			ensure!(T::Members::contains(&who), Error::<T>::NotOperator);
+
+			let info = Self::operators(&who);
+			ensure!(
+				info.deviation_score > T::EjectDeviationScore::get()
+					|| info.missed_rounds >= T::EjectMissedRounds::get(),
+				Error::<T>::OperatorPerformingWell
+			);
+
+			T::RemoveOperator::remove_operator(&who)?;
+			Operators::<T>::remove(&who);
+			Self::deposit_event(Event::OperatorEjected(who));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Average the deviations of the round, reward the operators and update
+	/// their records. Returns the number of the submitting operators and the
+	/// members processed.
+	fn close_round() -> u32 {
+		let round: BTreeMap<T::AccountId, RoundSubmission> = RoundSubmissions::<T>::drain().collect();
+		let mut count = round.len() as u32;
+
+		let reward = T::RewardPerRound::get();
+		let smoothing = T::DeviationScoreSmoothing::get();
+		for who in T::Members::sorted_members() {
+			count += 1;
+			Operators::<T>::mutate(&who, |info| match round.get(&who) {
+				Some(submission) => {
+					info.submissions = info.submissions.saturating_add(submission.submissions);
+					info.missed_rounds = 0;
+					if submission.compared.is_zero() {
+						return;
+					}
+
+					let deviation = submission.total_deviation / Ratio::saturating_from_integer(submission.compared);
+					info.deviation_score = Ratio::from_inner(
+						smoothing
+							.mul_floor(deviation.into_inner())
+							.saturating_add(smoothing.left_from_one().mul_floor(info.deviation_score.into_inner())),
+					);
+
+					if deviation <= T::MaxRewardDeviation::get() && !reward.is_zero() {
+						match T::Currency::transfer(&T::TreasuryAccount::get(), &who, reward, KeepAlive) {
+							Ok(()) => {
+								info.rewarded_rounds = info.rewarded_rounds.saturating_add(1);
+								Self::deposit_event(Event::OperatorRewarded(who.clone(), reward, deviation));
+							}
+							Err(e) => log::warn!(
+								target: "oracle-rewards",
+								"failed to reward operator {:?}: {:?}",
+								who,
+								e,
+							),
+						}
+					}
+				}
+				None => {
+					info.missed_rounds = info.missed_rounds.saturating_add(1);
+					Self::deposit_event(Event::OperatorMissedRound(who.clone(), info.missed_rounds));
+				}
+			});
+		}
+		count
+	}
+}
+
+impl<T: Config> OnNewData<T::AccountId, CurrencyId, Price> for Pallet<T> {
+	/// Record the deviation of `value` from the current combined value of
+	/// `key`. Every submission of the round counts, not only the last one of
+	/// each currency.
+	fn on_new_data(who: &T::AccountId, key: &CurrencyId, value: &Price) {
+		let value = *value;
+		let deviation = T::Source::get(key)
+			.filter(|combined| !combined.is_zero())
+			.map(|combined| {
+				Ratio::checked_from_rational(
+					value.max(combined).saturating_sub(value.min(combined)).into_inner(),
+					combined.into_inner(),
+				)
+				.unwrap_or_else(Ratio::max_value)
+			});
+
+		RoundSubmissions::<T>::mutate(who, |maybe_submission| {
+			let submission = maybe_submission.get_or_insert_with(Default::default);
+			submission.submissions = submission.submissions.saturating_add(1);
+			if let Some(deviation) = deviation {
+				submission.compared = submission.compared.saturating_add(1);
+				submission.total_deviation = submission.total_deviation.saturating_add(deviation);
+			}
+		});
+	}
+}
@@ -0,0 +1,205 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Mocks for the oracle rewards module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{construct_runtime, ord_parameter_types, parameter_types};
+use frame_system::EnsureSignedBy;
+use primitives::{ReserveIdentifier, TokenSymbol};
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup};
+use sp_std::cell::RefCell;
+
+pub type AccountId = u128;
+pub type BlockNumber = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+pub const TREASURY: AccountId = 10;
+pub const COUNCIL: AccountId = 100;
+
+pub const SEE: CurrencyId = CurrencyId::Token(TokenSymbol::SEE);
+pub const SERP: CurrencyId = CurrencyId::Token(TokenSymbol::SERP);
+pub const DNAR: CurrencyId = CurrencyId::Token(TokenSymbol::DNAR);
+
+mod oracle_rewards {
+	pub use super::super::*;
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Runtime {
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+	pub const MaxReserves: u32 = 50;
+}
+
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = frame_system::Pallet<Runtime>;
+	type MaxLocks = ();
+	type MaxReserves = MaxReserves;
+	type ReserveIdentifier = ReserveIdentifier;
+	type WeightInfo = ();
+}
+
+thread_local! {
+	static OPERATORS: RefCell<Vec<AccountId>> = RefCell::new(vec![ALICE, BOB, CHARLIE]);
+}
+
+pub struct MockSource;
+impl DataProvider<CurrencyId, Price> for MockSource {
+	fn get(currency_id: &CurrencyId) -> Option<Price> {
+		match *currency_id {
+			SEE => Some(Price::saturating_from_integer(10)),
+			DNAR => Some(Price::zero()),
+			_ => None,
+		}
+	}
+}
+
+pub struct MockMembers;
+impl SortedMembers<AccountId> for MockMembers {
+	fn sorted_members() -> Vec<AccountId> {
+		OPERATORS.with(|v| v.borrow().clone())
+	}
+}
+
+pub struct MockRemoveOperator;
+impl RemoveOperator<AccountId> for MockRemoveOperator {
+	fn remove_operator(who: &AccountId) -> DispatchResult {
+		OPERATORS.with(|v| v.borrow_mut().retain(|x| x != who));
+		Ok(())
+	}
+}
+
+ord_parameter_types! {
+	pub const Council: AccountId = COUNCIL;
+}
+
+parameter_types! {
+	pub const TreasuryAccount: AccountId = TREASURY;
+	pub const RoundPeriod: BlockNumber = 10;
+	pub const RewardPerRound: Balance = 100;
+	pub MaxRewardDeviation: Ratio = Ratio::saturating_from_rational(5, 100);
+	pub const DeviationScoreSmoothing: Permill = Permill::from_percent(50);
+	pub EjectDeviationScore: Ratio = Ratio::saturating_from_rational(10, 100);
+	pub const EjectMissedRounds: u32 = 2;
+}
+
+impl Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type Source = MockSource;
+	type Members = MockMembers;
+	type RemoveOperator = MockRemoveOperator;
+	type TreasuryAccount = TreasuryAccount;
+	type RoundPeriod = RoundPeriod;
+	type RewardPerRound = RewardPerRound;
+	type MaxRewardDeviation = MaxRewardDeviation;
+	type DeviationScoreSmoothing = DeviationScoreSmoothing;
+	type EjectDeviationScore = EjectDeviationScore;
+	type EjectMissedRounds = EjectMissedRounds;
+	type EjectOrigin = EnsureSignedBy<Council, AccountId>;
+	type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		OracleRewardsModule: oracle_rewards::{Pallet, Storage, Call, Event<T>},
+	}
+);
+
+pub struct ExtBuilder {
+	balances: Vec<(AccountId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			balances: vec![(TREASURY, 1_000)],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
+		self.balances = balances;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		pallet_balances::GenesisConfig::<Runtime> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
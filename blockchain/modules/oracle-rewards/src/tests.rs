@@ -0,0 +1,185 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Unit tests for the oracle rewards module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use sp_runtime::traits::BadOrigin;
+
+fn feed(who: AccountId, currency_id: CurrencyId, price: Price) {
+	<OracleRewardsModule as OnNewData<AccountId, CurrencyId, Price>>::on_new_data(&who, &currency_id, &price);
+}
+
+#[test]
+fn on_new_data_records_submissions() {
+	ExtBuilder::default().build().execute_with(|| {
+		feed(ALICE, SEE, Price::saturating_from_integer(10));
+		feed(ALICE, SEE, Price::saturating_from_integer(11));
+		feed(ALICE, SERP, Price::saturating_from_integer(11));
+		assert_eq!(
+			OracleRewardsModule::round_submissions(ALICE),
+			Some(RoundSubmission {
+				submissions: 3,
+				compared: 2,
+				total_deviation: Ratio::saturating_from_rational(10, 100),
+			})
+		);
+		assert_eq!(OracleRewardsModule::round_submissions(BOB), None);
+	});
+}
+
+#[test]
+fn close_round_averages_every_submission() {
+	ExtBuilder::default().build().execute_with(|| {
+		// the deviating value is not hidden by a later accurate one
+		feed(ALICE, SEE, Price::saturating_from_integer(13));
+		feed(ALICE, SEE, Price::saturating_from_integer(10));
+		OracleRewardsModule::on_initialize(10);
+		assert_eq!(
+			OracleRewardsModule::operators(ALICE),
+			OperatorInfo {
+				submissions: 2,
+				rewarded_rounds: 0,
+				missed_rounds: 0,
+				deviation_score: Ratio::saturating_from_rational(75, 1000),
+			}
+		);
+		assert_eq!(Balances::free_balance(ALICE), 0);
+	});
+}
+
+#[test]
+fn close_round_rewards_operators() {
+	ExtBuilder::default().build().execute_with(|| {
+		feed(ALICE, SEE, Price::saturating_from_rational(102, 10));
+		feed(ALICE, SERP, Price::saturating_from_integer(100));
+		feed(ALICE, DNAR, Price::saturating_from_integer(100));
+		feed(BOB, SEE, Price::saturating_from_integer(12));
+
+		OracleRewardsModule::on_initialize(9);
+		assert_eq!(OracleRewardsModule::operators(ALICE), Default::default());
+
+		OracleRewardsModule::on_initialize(10);
+		let rewarded_event = Event::OracleRewardsModule(crate::Event::OperatorRewarded(
+			ALICE,
+			100,
+			Ratio::saturating_from_rational(2, 100),
+		));
+		assert!(System::events().iter().any(|record| record.event == rewarded_event));
+		System::assert_last_event(Event::OracleRewardsModule(crate::Event::OperatorMissedRound(
+			CHARLIE, 1,
+		)));
+		assert_eq!(Balances::free_balance(ALICE), 100);
+		assert_eq!(Balances::free_balance(BOB), 0);
+		assert_eq!(Balances::free_balance(TREASURY), 900);
+
+		assert_eq!(
+			OracleRewardsModule::operators(ALICE),
+			OperatorInfo {
+				submissions: 3,
+				rewarded_rounds: 1,
+				missed_rounds: 0,
+				deviation_score: Ratio::saturating_from_rational(1, 100),
+			}
+		);
+		assert_eq!(
+			OracleRewardsModule::operators(BOB),
+			OperatorInfo {
+				submissions: 1,
+				rewarded_rounds: 0,
+				missed_rounds: 0,
+				deviation_score: Ratio::saturating_from_rational(10, 100),
+			}
+		);
+		assert_eq!(
+			OracleRewardsModule::operators(CHARLIE),
+			OperatorInfo {
+				submissions: 0,
+				rewarded_rounds: 0,
+				missed_rounds: 1,
+				deviation_score: Ratio::zero(),
+			}
+		);
+		assert_eq!(RoundSubmissions::<Runtime>::iter().count(), 0);
+
+		// the deviation score is the moving average of the rounds
+		feed(BOB, SEE, Price::saturating_from_integer(12));
+		feed(CHARLIE, SEE, Price::saturating_from_integer(10));
+		OracleRewardsModule::on_initialize(20);
+		assert_eq!(
+			OracleRewardsModule::operators(BOB).deviation_score,
+			Ratio::saturating_from_rational(15, 100)
+		);
+		assert_eq!(OracleRewardsModule::operators(ALICE).missed_rounds, 1);
+		assert_eq!(OracleRewardsModule::operators(CHARLIE).missed_rounds, 0);
+		assert_eq!(Balances::free_balance(CHARLIE), 100);
+	});
+}
+
+#[test]
+fn close_round_without_treasury_funds() {
+	ExtBuilder::default()
+		.balances(vec![(TREASURY, 50)])
+		.build()
+		.execute_with(|| {
+			feed(ALICE, SEE, Price::saturating_from_integer(10));
+			OracleRewardsModule::on_initialize(10);
+			assert_eq!(Balances::free_balance(ALICE), 0);
+			assert_eq!(Balances::free_balance(TREASURY), 50);
+			assert_eq!(OracleRewardsModule::operators(ALICE).rewarded_rounds, 0);
+			assert_eq!(OracleRewardsModule::operators(ALICE).submissions, 1);
+		});
+}
+
+#[test]
+fn eject_operator_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(OracleRewardsModule::eject_operator(Origin::signed(ALICE), BOB), BadOrigin);
+		assert_noop!(
+			OracleRewardsModule::eject_operator(Origin::signed(COUNCIL), 99),
+			Error::<Runtime>::NotOperator
+		);
+		assert_noop!(
+			OracleRewardsModule::eject_operator(Origin::signed(COUNCIL), BOB),
+			Error::<Runtime>::OperatorPerformingWell
+		);
+
+		// BOB deviates by 20% and CHARLIE misses the rounds
+		for round in 1..=2 {
+			feed(ALICE, SEE, Price::saturating_from_integer(10));
+			feed(BOB, SEE, Price::saturating_from_integer(12));
+			OracleRewardsModule::on_initialize(round * 10);
+		}
+		assert_noop!(
+			OracleRewardsModule::eject_operator(Origin::signed(COUNCIL), ALICE),
+			Error::<Runtime>::OperatorPerformingWell
+		);
+
+		assert_ok!(OracleRewardsModule::eject_operator(Origin::signed(COUNCIL), BOB));
+		System::assert_last_event(Event::OracleRewardsModule(crate::Event::OperatorEjected(BOB)));
+		assert_ok!(OracleRewardsModule::eject_operator(Origin::signed(COUNCIL), CHARLIE));
+		assert_eq!(MockMembers::sorted_members(), vec![ALICE]);
+		assert_eq!(OracleRewardsModule::operators(BOB), Default::default());
+		assert_eq!(OracleRewardsModule::operators(CHARLIE), Default::default());
+	});
+}
@@ -0,0 +1,44 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for querying the current and recent prices of a
+//! currency.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use primitives::CurrencyId;
+use sp_std::prelude::*;
+
+pub use primitives::prices::PriceRecord;
+
+sp_api::decl_runtime_apis! {
+	pub trait PricesApi<BlockNumber> where
+		BlockNumber: Codec,
+	{
+		/// The current raw oracle price and system price of `currency_id`.
+		fn get_price(currency_id: CurrencyId) -> PriceRecord<BlockNumber>;
+
+		/// The recorded prices of `currency_id`, the oldest first.
+		fn get_price_history(currency_id: CurrencyId) -> Vec<PriceRecord<BlockNumber>>;
+	}
+}
@@ -0,0 +1,120 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for querying the current and recent prices of a currency.
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use primitives::CurrencyId;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use std::sync::Arc;
+
+pub use module_prices_rpc_runtime_api::{PriceRecord, PricesApi as PricesRuntimeApi};
+
+#[rpc]
+pub trait PricesApi<BlockHash, BlockNumber> {
+	/// The raw oracle price and the system price of `currency_id` at the
+	/// block, the best block if not supplied.
+	#[rpc(name = "prices_getPrice")]
+	fn get_price(&self, currency_id: CurrencyId, at: Option<BlockHash>) -> Result<PriceRecord<BlockNumber>>;
+
+	/// The recorded prices of `currency_id` at the block, the oldest first.
+	#[rpc(name = "prices_getPriceHistory")]
+	fn get_price_history(
+		&self,
+		currency_id: CurrencyId,
+		at: Option<BlockHash>,
+	) -> Result<Vec<PriceRecord<BlockNumber>>>;
+}
+
+/// A struct that implements the [`PricesApi`].
+pub struct Prices<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Prices<C, B> {
+	/// Create new `Prices` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block, BlockNumber> PricesApi<<Block as BlockT>::Hash, BlockNumber> for Prices<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: PricesRuntimeApi<Block, BlockNumber>,
+	BlockNumber: Codec,
+{
+	fn get_price(
+		&self,
+		currency_id: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<PriceRecord<BlockNumber>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		api.get_price(&at, currency_id).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to get price.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+
+	fn get_price_history(
+		&self,
+		currency_id: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<PriceRecord<BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		api.get_price_history(&at, currency_id).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to get price history.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+}
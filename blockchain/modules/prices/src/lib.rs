@@ -24,21 +24,32 @@
 //!
 //! The data from Oracle cannot be used in business, prices module will do some
 //! process and feed prices for Setheum. Process include:
-//!   - specify a fixed price for stable currency
+//!   - specify a fixed price or a basket of currencies the stable currencies
+//!     are pegged to
 //!   - feed price in USD or related price bewteen two currencies
 //!   - lock/unlock the price data get from oracle
+//!   - lock the last good price automatically when the oracle price deviates
+//!     too much or goes stale
+//!   - record the recent prices of the tracked currencies
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
-use frame_support::{pallet_prelude::*, transactional};
+use codec::{Decode, Encode};
+use frame_support::{pallet_prelude::*, traits::Time, transactional};
 use frame_system::pallet_prelude::*;
-use orml_traits::{DataFeeder, DataProvider, MultiCurrency};
-use primitives::{Balance, CurrencyId};
+use orml_oracle::TimestampedValue;
+use orml_traits::{DataFeeder, DataProvider, DataProviderExtended, MultiCurrency};
+use primitives::{prices::PriceRecord, Balance, CurrencyId, Moment};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_core::U256;
-use sp_runtime::FixedPointNumber;
-use sp_std::{convert::TryInto, marker::PhantomData};
-use support::{CurrencyIdMapping, DEXManager, LockablePrice, Price, PriceProvider};
+use sp_runtime::{
+	traits::{Bounded, CheckedAdd, CheckedMul, One, Saturating, UniqueSaturatedInto, Zero},
+	FixedPointNumber, RuntimeDebug,
+};
+use sp_std::{convert::TryInto, marker::PhantomData, prelude::*};
+use support::{CurrencyIdMapping, DEXManager, LockablePrice, Price, PriceProvider, Ratio};
 use integer_sqrt::*;
 
 mod mock;
@@ -48,6 +59,28 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The limits of the oracle price of a currency, beyond which the last good
+/// price is locked.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub struct CircuitBreaker {
+	/// The maximum deviation of the price from the last good price, per
+	/// block passed since it
+	pub max_deviation_per_block: Ratio,
+	/// The maximum age of the price, in milliseconds
+	pub max_staleness: Moment,
+}
+
+/// The definition of the price of a pegged currency.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub enum PegDefinition {
+	/// Pegged to a fixed price in USD
+	Fixed(Price),
+	/// Pegged to a basket of currencies, the price is the sum of the prices of
+	/// the currencies multiplied by their weights
+	Basket(Vec<(CurrencyId, Ratio)>),
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -57,26 +90,36 @@ pub mod module {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
 		/// The data source, such as Oracle.
-		type Source: DataProvider<CurrencyId, Price> + DataFeeder<CurrencyId, Price, Self::AccountId>;
+		type Source: DataProvider<CurrencyId, Price>
+			+ DataProviderExtended<CurrencyId, TimestampedValue<Price, Moment>>
+			+ DataFeeder<CurrencyId, Price, Self::AccountId>;
 
-		/// The stable currency id, it should be SETUSD in Setheum.
+		/// Time used to check the staleness of the prices of the data source,
+		/// and to timestamp the price history.
+		type Time: Time<Moment = Moment>;
+
+		/// The maximum number of the currencies in a peg basket.
 		#[pallet::constant]
-		type GetSetUSDId: Get<CurrencyId>;
+		type MaxBasketSize: Get<u32>;
 
-		/// The stable currency id, it should be SETR in Setheum.
+		/// The origin which may lock and unlock prices feed to system.
+		type LockOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The number of the recent prices recorded for each tracked currency.
 		#[pallet::constant]
-		type SetterCurrencyId: Get<CurrencyId>;
+		type PriceHistoryLength: Get<u32>;
 
-		/// The fixed prices of stable currency SETUSD, it should be 1 USD in Setheum.
+		/// The maximum number of the currencies of which the prices are
+		/// recorded.
 		#[pallet::constant]
-		type SetUSDFixedPrice: Get<Price>;
+		type MaxHistoryCurrencies: Get<u32>;
 
-		/// The fixed prices of stable currency SETR, it should be 0.1 USD (10 cents) in Setheum.
+		/// The maximum number of the currencies with circuit breakers.
 		#[pallet::constant]
-		type SetterFixedPrice: Get<Price>;
+		type MaxCircuitBreakers: Get<u32>;
 
-		/// The origin which may lock and unlock prices feed to system.
-		type LockOrigin: EnsureOrigin<Self::Origin>;
+		/// The origin which may update the pegs and the tracked currencies.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
 
 		/// DEX provide liquidity info.
 		type DEX: DEXManager<Self::AccountId, CurrencyId, Balance>;
@@ -97,6 +140,12 @@ pub mod module {
 		AccessPriceFailed,
 		/// There's no locked price
 		NoLockedPrice,
+		/// The peg definition is invalid
+		InvalidPeg,
+		/// Too many currencies to record the prices of
+		TooManyHistoryCurrencies,
+		/// Too many currencies with circuit breakers
+		TooManyCircuitBreakers,
 	}
 
 	#[pallet::event]
@@ -110,6 +159,20 @@ pub mod module {
 		FetchPrice(CurrencyId, Option<Price>),
 		/// Unlock price. \[relative_price\]
 		RelativePrice(CurrencyId, CurrencyId, Option<Price>),
+		/// The circuit breaker of the price is updated. \[currency_id,
+		/// circuit_breaker\]
+		CircuitBreakerUpdated(CurrencyId, Option<CircuitBreaker>),
+		/// The price deviated too much, the last good price is locked.
+		/// \[currency_id, locked_price\]
+		PriceDeviationExceeded(CurrencyId, Price),
+		/// The price is stale, the last good price is locked. \[currency_id,
+		/// locked_price\]
+		PriceStale(CurrencyId, Price),
+		/// The peg of the currency is updated. \[currency_id, peg\]
+		PegUpdated(CurrencyId, Option<PegDefinition>),
+		/// The currencies of which the prices are recorded are updated.
+		/// \[currency_ids\]
+		HistoryCurrenciesUpdated(Vec<CurrencyId>),
 	}
 
 	/// Mapping from currency id to it's locked price
@@ -119,11 +182,116 @@ pub mod module {
 	#[pallet::getter(fn locked_price)]
 	pub type LockedPrice<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Price, OptionQuery>;
 
+	/// Mapping from currency id to the circuit breaker of its price
+	///
+	/// CircuitBreakers: map CurrencyId => Option<CircuitBreaker>
+	#[pallet::storage]
+	#[pallet::getter(fn circuit_breakers)]
+	pub type CircuitBreakers<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, CircuitBreaker, OptionQuery>;
+
+	/// The number of the currencies with circuit breakers, at most
+	/// `MaxCircuitBreakers`
+	///
+	/// CircuitBreakerCount: u32
+	#[pallet::storage]
+	#[pallet::getter(fn circuit_breaker_count)]
+	pub type CircuitBreakerCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Mapping from currency id to the last good price checked by its circuit
+	/// breaker, and the block number at which it was recorded
+	///
+	/// LastGoodPrice: map CurrencyId => Option<(Price, BlockNumber)>
+	#[pallet::storage]
+	#[pallet::getter(fn last_good_price)]
+	pub type LastGoodPrice<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, (Price, T::BlockNumber), OptionQuery>;
+
+	/// Mapping from currency id to the definition of its pegged price
+	///
+	/// Pegs: map CurrencyId => Option<PegDefinition>
+	#[pallet::storage]
+	#[pallet::getter(fn pegs)]
+	pub type Pegs<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, PegDefinition, OptionQuery>;
+
+	/// The currencies of which the prices are recorded every block
+	///
+	/// HistoryCurrencies: Vec<CurrencyId>
+	#[pallet::storage]
+	#[pallet::getter(fn history_currencies)]
+	pub type HistoryCurrencies<T: Config> = StorageValue<_, Vec<CurrencyId>, ValueQuery>;
+
+	/// Mapping from currency id to the ring buffer of its recent prices, at
+	/// most `PriceHistoryLength` records
+	///
+	/// PriceHistory: map CurrencyId => Vec<PriceRecord>
+	#[pallet::storage]
+	pub type PriceHistory<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, Vec<PriceRecord<T::BlockNumber>>, ValueQuery>;
+
+	/// Mapping from currency id to the position of the oldest record in its
+	/// full price history, which is overwritten by the next record
+	///
+	/// PriceHistoryCursor: map CurrencyId => u32
+	#[pallet::storage]
+	pub type PriceHistoryCursor<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, u32, ValueQuery>;
+
+	/// True if the fixed prices of the runtime were migrated to pegs, or the
+	/// pegs were set at genesis.
+	///
+	/// UpgradedToPegs: bool
+	#[pallet::storage]
+	pub(crate) type UpgradedToPegs<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig {
+		pub pegs: Vec<(CurrencyId, PegDefinition)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl Default for GenesisConfig {
+		fn default() -> Self {
+			GenesisConfig { pegs: vec![] }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig {
+		fn build(&self) {
+			for (currency_id, peg) in &self.pegs {
+				Pallet::<T>::ensure_valid_peg(*currency_id, peg).expect("invalid peg in genesis");
+				Pegs::<T>::insert(currency_id, peg);
+			}
+			UpgradedToPegs::<T>::put(true);
+		}
+	}
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Check the prices of the currencies with circuit breakers, and lock
+		/// the last good price of those deviated too much or stale.
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let mut count: u32 = 0;
+			for (currency_id, circuit_breaker) in CircuitBreakers::<T>::iter() {
+				count += 1;
+				Self::check_circuit_breaker(now, currency_id, circuit_breaker);
+			}
+			T::WeightInfo::on_initialize(count)
+				.saturating_add(T::WeightInfo::record_price_history(
+					Self::history_currencies().len() as u32,
+				))
+		}
+
+		/// Record the prices of the tracked currencies at the end of the
+		/// block, after the oracle feeds of the block.
+		fn on_finalize(_now: T::BlockNumber) {
+			for currency_id in Self::history_currencies() {
+				Self::record_price(currency_id);
+			}
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -152,6 +320,97 @@ pub mod module {
 			<Pallet<T> as LockablePrice<CurrencyId>>::unlock_price(currency_id)?;
 			Ok(())
 		}
+
+		/// Set the circuit breaker of the price of a currency. While the price
+		/// is locked, it is not checked until `unlock_price`. At most
+		/// `MaxCircuitBreakers` currencies can have circuit breakers.
+		///
+		/// The dispatch origin of this call must be `LockOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		/// - `circuit_breaker`: the limits of the price, `None` to remove.
+		#[pallet::weight((T::WeightInfo::set_circuit_breaker(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_circuit_breaker(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			circuit_breaker: Option<CircuitBreaker>,
+		) -> DispatchResult {
+			T::LockOrigin::ensure_origin(origin)?;
+			match (CircuitBreakers::<T>::contains_key(currency_id), circuit_breaker.is_some()) {
+				(false, true) => {
+					let count = Self::circuit_breaker_count();
+					ensure!(
+						count < T::MaxCircuitBreakers::get(),
+						Error::<T>::TooManyCircuitBreakers
+					);
+					CircuitBreakerCount::<T>::put(count + 1);
+				}
+				(true, false) => CircuitBreakerCount::<T>::mutate(|count| *count = count.saturating_sub(1)),
+				_ => {}
+			}
+			CircuitBreakers::<T>::set(currency_id, circuit_breaker);
+			LastGoodPrice::<T>::remove(currency_id);
+			Self::deposit_event(Event::CircuitBreakerUpdated(currency_id, circuit_breaker));
+			Ok(())
+		}
+
+		/// Set the peg of a currency, the price of which is then the fixed
+		/// price or the price of the basket, rather than the oracle price.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		/// - `peg`: the peg definition, `None` to remove.
+		#[pallet::weight((
+			T::WeightInfo::set_peg(match peg {
+				// oversized baskets are rejected by the call
+				Some(PegDefinition::Basket(basket)) => (basket.len() as u32).min(T::MaxBasketSize::get()),
+				_ => 0,
+			}),
+			DispatchClass::Operational
+		))]
+		#[transactional]
+		pub fn set_peg(origin: OriginFor<T>, currency_id: CurrencyId, peg: Option<PegDefinition>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			if let Some(peg) = &peg {
+				Self::ensure_valid_peg(currency_id, peg)?;
+			}
+			Pegs::<T>::set(currency_id, peg.clone());
+			Self::deposit_event(Event::PegUpdated(currency_id, peg));
+			Ok(())
+		}
+
+		/// Set the currencies of which the prices are recorded every block.
+		/// The history of the currencies no longer tracked is removed.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_ids`: the currencies to track.
+		#[pallet::weight((
+			T::WeightInfo::set_history_currencies(T::MaxHistoryCurrencies::get()),
+			DispatchClass::Operational
+		))]
+		#[transactional]
+		pub fn set_history_currencies(origin: OriginFor<T>, mut currency_ids: Vec<CurrencyId>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			currency_ids.sort();
+			currency_ids.dedup();
+			ensure!(
+				currency_ids.len() as u32 <= T::MaxHistoryCurrencies::get(),
+				Error::<T>::TooManyHistoryCurrencies
+			);
+
+			for currency_id in Self::history_currencies() {
+				if currency_ids.binary_search(&currency_id).is_err() {
+					PriceHistory::<T>::remove(currency_id);
+					PriceHistoryCursor::<T>::remove(currency_id);
+				}
+			}
+			HistoryCurrencies::<T>::put(currency_ids.clone());
+			Self::deposit_event(Event::HistoryCurrenciesUpdated(currency_ids));
+			Ok(())
+		}
 	}
 }
 
@@ -161,12 +420,12 @@ impl<T: Config> Pallet<T> {
 	///
 	/// Note: this returns the price for 1 basic unit
 	fn access_price(currency_id: CurrencyId) -> Option<Price> {
-		let maybe_price = if currency_id == T::GetSetUSDId::get() {
-			// if is SETUSD, use fixed price
-			Some(T::SetUSDFixedPrice::get())
-		} else if currency_id == T::SetterCurrencyId::get() {
-			// if is SETR, return Setter fixed price (currently $0.1)
-			Some(T::SetterFixedPrice::get())
+		let maybe_price = if let Some(peg) = Self::pegs(currency_id) {
+			// if is pegged, use the fixed price or the price of the basket
+			match peg {
+				PegDefinition::Fixed(price) => Some(price),
+				PegDefinition::Basket(basket) => Self::basket_price(&basket),
+			}
 		} else if let CurrencyId::DexShare(symbol_0, symbol_1) = currency_id {
 			let token_0: CurrencyId = symbol_0.into();
 			let token_1: CurrencyId = symbol_1.into();
@@ -197,6 +456,158 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// The current price of `currency_id`, both the raw oracle price and the
+	/// price used by the system.
+	pub fn price_record(currency_id: CurrencyId) -> PriceRecord<T::BlockNumber> {
+		let locked_price = Self::locked_price(currency_id);
+		PriceRecord {
+			block_number: frame_system::Pallet::<T>::block_number(),
+			timestamp: T::Time::now(),
+			oracle_price: T::Source::get(&currency_id),
+			price: locked_price.or_else(|| Self::access_price(currency_id)),
+			locked: locked_price.is_some(),
+		}
+	}
+
+	/// The recorded prices of `currency_id`, the oldest first.
+	pub fn price_history(currency_id: CurrencyId) -> Vec<PriceRecord<T::BlockNumber>> {
+		let mut history = PriceHistory::<T>::get(currency_id);
+		if !history.is_empty() {
+			let cursor = PriceHistoryCursor::<T>::get(currency_id) as usize % history.len();
+			history.rotate_left(cursor);
+		}
+		history
+	}
+
+	/// Append the current price of `currency_id` to its price history,
+	/// overwriting the oldest record if the history is full.
+	fn record_price(currency_id: CurrencyId) {
+		let record = Self::price_record(currency_id);
+		let max_len = T::PriceHistoryLength::get() as usize;
+		PriceHistory::<T>::mutate(currency_id, |history| {
+			if history.len() < max_len {
+				history.push(record);
+			} else if !history.is_empty() {
+				let cursor = PriceHistoryCursor::<T>::get(currency_id) as usize % history.len();
+				history[cursor] = record;
+				PriceHistoryCursor::<T>::insert(currency_id, ((cursor + 1) % history.len()) as u32);
+			}
+		});
+	}
+
+	/// The price of a basket, in USD for 1 unit of the currency. The
+	/// currencies of the basket use their fixed pegged price or the oracle
+	/// price.
+	fn basket_price(basket: &[(CurrencyId, Ratio)]) -> Option<Price> {
+		basket.iter().try_fold(Price::zero(), |total, (currency_id, weight)| {
+			let price = match Self::pegs(currency_id) {
+				Some(PegDefinition::Fixed(price)) => price,
+				Some(PegDefinition::Basket(_)) => return None,
+				None => T::Source::get(currency_id)?,
+			};
+			total.checked_add(&price.checked_mul(weight)?)
+		})
+	}
+
+	/// Check the peg of `currency_id`. A fixed price must not be zero. A
+	/// basket must not be empty or exceed `MaxBasketSize`, and its currencies
+	/// must have non-zero weights, be neither dex shares nor pegged to
+	/// baskets, and not include `currency_id`.
+	fn ensure_valid_peg(currency_id: CurrencyId, peg: &PegDefinition) -> DispatchResult {
+		match peg {
+			PegDefinition::Fixed(price) => ensure!(!price.is_zero(), Error::<T>::InvalidPeg),
+			PegDefinition::Basket(basket) => {
+				ensure!(
+					!basket.is_empty() && basket.len() as u32 <= T::MaxBasketSize::get(),
+					Error::<T>::InvalidPeg
+				);
+				ensure!(
+					!matches!(currency_id, CurrencyId::DexShare(..)),
+					Error::<T>::InvalidPeg
+				);
+				for (member, weight) in basket {
+					ensure!(
+						*member != currency_id
+							&& !weight.is_zero() && !matches!(member, CurrencyId::DexShare(..))
+							&& !matches!(Self::pegs(member), Some(PegDefinition::Basket(_))),
+						Error::<T>::InvalidPeg
+					);
+				}
+			}
+		}
+		Ok(())
+	}
+
+	/// Migrate the fixed prices of the currencies, formerly configured in the
+	/// runtime, to fixed pegs. Runs once, the pegs already set are kept.
+	pub fn migrate_fixed_prices_to_pegs(fixed_prices: Vec<(CurrencyId, Price)>) -> Weight {
+		if UpgradedToPegs::<T>::get() {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let mut writes: Weight = 1;
+		for (currency_id, price) in fixed_prices.iter() {
+			if !Pegs::<T>::contains_key(currency_id) {
+				Pegs::<T>::insert(currency_id, PegDefinition::Fixed(*price));
+				writes += 1;
+			}
+		}
+		UpgradedToPegs::<T>::put(true);
+
+		T::DbWeight::get().reads_writes(1 + fixed_prices.len() as Weight, writes)
+	}
+
+	/// Check the real-time price of `currency_id` against its circuit
+	/// breaker. Lock the last good price if the price deviated from it more
+	/// than allowed, or the price is stale, otherwise record the price as the
+	/// last good price. An unchanged price is recorded too, so the allowed
+	/// deviation only grows with the blocks since the last check.
+	fn check_circuit_breaker(now: T::BlockNumber, currency_id: CurrencyId, circuit_breaker: CircuitBreaker) {
+		if LockedPrice::<T>::contains_key(currency_id) {
+			return;
+		}
+		let maybe_price = Self::access_price(currency_id);
+		let last_good_price = Self::last_good_price(currency_id);
+
+		let is_stale = T::Source::get_no_op(&currency_id).map_or(false, |value| {
+			T::Time::now().saturating_sub(value.timestamp) > circuit_breaker.max_staleness
+		});
+		if is_stale {
+			if let Some(locked_price) = last_good_price.map(|(price, _)| price).or(maybe_price) {
+				LockedPrice::<T>::insert(currency_id, locked_price);
+				Self::deposit_event(Event::PriceStale(currency_id, locked_price));
+			}
+			return;
+		}
+
+		match (maybe_price, last_good_price) {
+			(Some(price), Some((last_price, at))) => {
+				let blocks: u128 = now.saturating_sub(at).max(One::one()).unique_saturated_into();
+				let max_deviation = circuit_breaker
+					.max_deviation_per_block
+					.saturating_mul(Ratio::saturating_from_integer(blocks));
+				let deviation = Ratio::checked_from_rational(
+					price.max(last_price).saturating_sub(price.min(last_price)).into_inner(),
+					last_price.into_inner(),
+				)
+				.unwrap_or_else(Ratio::max_value);
+
+				if deviation > max_deviation {
+					LockedPrice::<T>::insert(currency_id, last_price);
+					Self::deposit_event(Event::PriceDeviationExceeded(currency_id, last_price));
+				} else {
+					LastGoodPrice::<T>::insert(currency_id, (price, now));
+				}
+			}
+			(Some(price), None) if !price.is_zero() => {
+				LastGoodPrice::<T>::insert(currency_id, (price, now));
+			}
+			_ => {}
+		}
+	}
+}
+
 impl<T: Config> LockablePrice<CurrencyId> for Pallet<T> {
 	/// Record the real-time price from oracle as the locked price
 	fn lock_price(currency_id: CurrencyId) -> DispatchResult {
@@ -209,6 +620,7 @@ impl<T: Config> LockablePrice<CurrencyId> for Pallet<T> {
 	/// Unlock the locked price
 	fn unlock_price(currency_id: CurrencyId) -> DispatchResult {
 		let _ = LockedPrice::<T>::take(currency_id).ok_or(Error::<T>::NoLockedPrice)?;
+		LastGoodPrice::<T>::remove(currency_id);
 		Pallet::<T>::deposit_event(Event::UnlockPrice(currency_id));
 		Ok(())
 	}
@@ -23,9 +23,9 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{construct_runtime, ord_parameter_types, parameter_types};
+use frame_support::{construct_runtime, ord_parameter_types, parameter_types, traits::Time};
 use frame_system::EnsureSignedBy;
-use orml_traits::{parameter_type_with_key, DataFeeder};
+use orml_traits::{parameter_type_with_key, DataFeeder, DataProviderExtended};
 use primitives::{currency::DexShare, Amount, TokenSymbol};
 use sp_core::{H160, H256};
 use sp_runtime::{
@@ -83,12 +83,26 @@ impl frame_system::Config for Runtime {
 
 thread_local! {
 	static CHANGED: RefCell<bool> = RefCell::new(false);
+	static NOW: RefCell<Moment> = RefCell::new(0);
 }
 
 pub fn mock_oracle_update() {
 	CHANGED.with(|v| *v.borrow_mut() = true)
 }
 
+pub fn set_now(now: Moment) {
+	NOW.with(|v| *v.borrow_mut() = now)
+}
+
+pub struct MockTime;
+impl Time for MockTime {
+	type Moment = Moment;
+
+	fn now() -> Moment {
+		NOW.with(|v| *v.borrow())
+	}
+}
+
 pub struct MockDataProvider;
 impl DataProvider<CurrencyId, Price> for MockDataProvider {
 	fn get(currency_id: &CurrencyId) -> Option<Price> {
@@ -112,6 +126,17 @@ impl DataProvider<CurrencyId, Price> for MockDataProvider {
 	}
 }
 
+// the prices are fed at 0
+impl DataProviderExtended<CurrencyId, TimestampedValue<Price, Moment>> for MockDataProvider {
+	fn get_no_op(currency_id: &CurrencyId) -> Option<TimestampedValue<Price, Moment>> {
+		Self::get(currency_id).map(|value| TimestampedValue { value, timestamp: 0 })
+	}
+
+	fn get_all_values() -> Vec<(CurrencyId, Option<TimestampedValue<Price, Moment>>)> {
+		vec![]
+	}
+}
+
 impl DataFeeder<CurrencyId, Price, AccountId> for MockDataProvider {
 	fn feed_value(_: AccountId, _: CurrencyId, _: Price) -> sp_runtime::DispatchResult {
 		Ok(())
@@ -215,20 +240,22 @@ ord_parameter_types! {
 }
 
 parameter_types! {
-	pub const GetSetUSDId: CurrencyId = SETUSD;
-	pub const SetterCurrencyId: CurrencyId = SETR;
-	pub SetUSDFixedPrice: Price = Price::one();
-	pub SetterFixedPrice: Price = Price::saturating_from_rational(1, 4); // $0.25
+	pub const MaxBasketSize: u32 = 3;
+	pub const PriceHistoryLength: u32 = 3;
+	pub const MaxHistoryCurrencies: u32 = 2;
+	pub const MaxCircuitBreakers: u32 = 2;
 }
 
 impl Config for Runtime {
 	type Event = Event;
 	type Source = MockDataProvider;
-	type GetSetUSDId = GetSetUSDId;
-	type SetterCurrencyId = SetterCurrencyId;
-	type SetUSDFixedPrice = SetUSDFixedPrice;
-	type SetterFixedPrice = SetterFixedPrice;
+	type Time = MockTime;
+	type MaxBasketSize = MaxBasketSize;
 	type LockOrigin = EnsureSignedBy<One, AccountId>;
+	type PriceHistoryLength = PriceHistoryLength;
+	type MaxHistoryCurrencies = MaxHistoryCurrencies;
+	type MaxCircuitBreakers = MaxCircuitBreakers;
+	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type DEX = MockDEX;
 	type Currency = Tokens;
 	type CurrencyIdMapping = MockCurrencyIdMapping;
@@ -245,7 +272,7 @@ construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		PricesModule: prices::{Pallet, Storage, Call, Event<T>},
+		PricesModule: prices::{Pallet, Storage, Call, Config, Event<T>},
 		Tokens: orml_tokens::{Pallet, Call, Storage, Event<T>},
 	}
 );
@@ -260,10 +287,21 @@ impl Default for ExtBuilder {
 
 impl ExtBuilder {
 	pub fn build(self) -> sp_io::TestExternalities {
-		let t = frame_system::GenesisConfig::default()
+		let mut t = frame_system::GenesisConfig::default()
 			.build_storage::<Runtime>()
 			.unwrap();
 
+		GenesisBuild::<Runtime>::assimilate_storage(
+			&prices::GenesisConfig {
+				pegs: vec![
+					(SETUSD, PegDefinition::Fixed(Price::one())),
+					(SETR, PegDefinition::Fixed(Price::saturating_from_rational(1, 4))), // $0.25
+				],
+			},
+			&mut t,
+		)
+		.unwrap();
+
 		t.into()
 	}
 }
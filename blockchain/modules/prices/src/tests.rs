@@ -440,3 +440,413 @@ fn price_providers_work() {
 		assert_eq!(LockedPriceProvider::<Runtime>::get_relative_price(SERP, SETR), None);
 	});
 }
+
+#[test]
+fn set_circuit_breaker_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let circuit_breaker = CircuitBreaker {
+			max_deviation_per_block: Ratio::saturating_from_rational(1, 10),
+			max_staleness: 1000,
+		};
+
+		assert_noop!(
+			PricesModule::set_circuit_breaker(Origin::signed(5), DNAR, Some(circuit_breaker)),
+			BadOrigin
+		);
+
+		assert_ok!(PricesModule::set_circuit_breaker(
+			Origin::signed(1),
+			DNAR,
+			Some(circuit_breaker)
+		));
+		System::assert_last_event(Event::PricesModule(crate::Event::CircuitBreakerUpdated(
+			DNAR,
+			Some(circuit_breaker),
+		)));
+		assert_eq!(PricesModule::circuit_breakers(DNAR), Some(circuit_breaker));
+
+		PricesModule::on_initialize(1);
+		assert_eq!(
+			PricesModule::last_good_price(DNAR),
+			Some((PricesModule::access_price(DNAR).unwrap(), 1))
+		);
+
+		assert_ok!(PricesModule::set_circuit_breaker(Origin::signed(1), DNAR, None));
+		System::assert_last_event(Event::PricesModule(crate::Event::CircuitBreakerUpdated(DNAR, None)));
+		assert_eq!(PricesModule::circuit_breakers(DNAR), None);
+		assert_eq!(PricesModule::last_good_price(DNAR), None);
+	});
+}
+
+#[test]
+fn set_circuit_breaker_should_fail_when_too_many() {
+	ExtBuilder::default().build().execute_with(|| {
+		let circuit_breaker = CircuitBreaker {
+			max_deviation_per_block: Ratio::saturating_from_rational(1, 10),
+			max_staleness: 1000,
+		};
+
+		assert_ok!(PricesModule::set_circuit_breaker(
+			Origin::signed(1),
+			DNAR,
+			Some(circuit_breaker)
+		));
+		assert_ok!(PricesModule::set_circuit_breaker(
+			Origin::signed(1),
+			SERP,
+			Some(circuit_breaker)
+		));
+		assert_eq!(PricesModule::circuit_breaker_count(), 2);
+		assert_noop!(
+			PricesModule::set_circuit_breaker(Origin::signed(1), SETR, Some(circuit_breaker)),
+			Error::<Runtime>::TooManyCircuitBreakers
+		);
+
+		// updating an existing circuit breaker is still allowed
+		assert_ok!(PricesModule::set_circuit_breaker(
+			Origin::signed(1),
+			DNAR,
+			Some(circuit_breaker)
+		));
+		assert_eq!(PricesModule::circuit_breaker_count(), 2);
+
+		// removing one makes room for another
+		assert_ok!(PricesModule::set_circuit_breaker(Origin::signed(1), DNAR, None));
+		assert_ok!(PricesModule::set_circuit_breaker(Origin::signed(1), DNAR, None));
+		assert_eq!(PricesModule::circuit_breaker_count(), 1);
+		assert_ok!(PricesModule::set_circuit_breaker(
+			Origin::signed(1),
+			SETR,
+			Some(circuit_breaker)
+		));
+		assert_eq!(PricesModule::circuit_breaker_count(), 2);
+	});
+}
+
+#[test]
+fn circuit_breaker_locks_deviated_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PricesModule::set_circuit_breaker(
+			Origin::signed(1),
+			DNAR,
+			Some(CircuitBreaker {
+				max_deviation_per_block: Ratio::saturating_from_rational(1, 10),
+				max_staleness: 1000,
+			})
+		));
+
+		let good_price = PricesModule::access_price(DNAR).unwrap();
+		PricesModule::on_initialize(1);
+		assert_eq!(PricesModule::last_good_price(DNAR), Some((good_price, 1)));
+		assert_eq!(PricesModule::locked_price(DNAR), None);
+
+		// DNAR drops by 90%
+		mock_oracle_update();
+		let new_price = PricesModule::access_price(DNAR).unwrap();
+		PricesModule::on_initialize(2);
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceDeviationExceeded(
+			DNAR, good_price,
+		)));
+		assert_eq!(PricesModule::locked_price(DNAR), Some(good_price));
+		assert_eq!(
+			PriorityLockedPriceProvider::<Runtime>::get_price(DNAR),
+			Some(good_price)
+		);
+
+		// not checked while locked
+		PricesModule::on_initialize(3);
+		assert_eq!(PricesModule::last_good_price(DNAR), Some((good_price, 1)));
+
+		// resumed by LockOrigin
+		assert_ok!(PricesModule::unlock_price(Origin::signed(1), DNAR));
+		assert_eq!(PricesModule::last_good_price(DNAR), None);
+		PricesModule::on_initialize(4);
+		assert_eq!(PricesModule::last_good_price(DNAR), Some((new_price, 4)));
+		assert_eq!(PricesModule::locked_price(DNAR), None);
+	});
+}
+
+#[test]
+fn circuit_breaker_allows_deviation_per_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PricesModule::set_circuit_breaker(
+			Origin::signed(1),
+			SERP,
+			Some(CircuitBreaker {
+				max_deviation_per_block: Ratio::saturating_from_rational(1, 10),
+				max_staleness: 1000,
+			})
+		));
+
+		PricesModule::on_initialize(1);
+
+		// SERP drops by 20% in 2 blocks
+		mock_oracle_update();
+		let new_price = PricesModule::access_price(SERP).unwrap();
+		PricesModule::on_initialize(3);
+		assert_eq!(PricesModule::locked_price(SERP), None);
+		assert_eq!(PricesModule::last_good_price(SERP), Some((new_price, 3)));
+	});
+}
+
+#[test]
+fn circuit_breaker_tolerance_does_not_grow_while_price_is_flat() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PricesModule::set_circuit_breaker(
+			Origin::signed(1),
+			SERP,
+			Some(CircuitBreaker {
+				max_deviation_per_block: Ratio::saturating_from_rational(1, 10),
+				max_staleness: 1000,
+			})
+		));
+
+		let good_price = PricesModule::access_price(SERP).unwrap();
+		for n in 1..=100 {
+			PricesModule::on_initialize(n);
+		}
+		assert_eq!(PricesModule::last_good_price(SERP), Some((good_price, 100)));
+
+		// SERP drops by 20% in 1 block after 100 flat blocks
+		mock_oracle_update();
+		PricesModule::on_initialize(101);
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceDeviationExceeded(
+			SERP, good_price,
+		)));
+		assert_eq!(PricesModule::locked_price(SERP), Some(good_price));
+	});
+}
+
+#[test]
+fn circuit_breaker_locks_stale_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PricesModule::set_circuit_breaker(
+			Origin::signed(1),
+			SERP,
+			Some(CircuitBreaker {
+				max_deviation_per_block: Ratio::saturating_from_rational(1, 10),
+				max_staleness: 1000,
+			})
+		));
+
+		let good_price = PricesModule::access_price(SERP).unwrap();
+		set_now(1000);
+		PricesModule::on_initialize(1);
+		assert_eq!(PricesModule::locked_price(SERP), None);
+		assert_eq!(PricesModule::last_good_price(SERP), Some((good_price, 1)));
+
+		set_now(1001);
+		PricesModule::on_initialize(2);
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceStale(SERP, good_price)));
+		assert_eq!(PricesModule::locked_price(SERP), Some(good_price));
+	});
+}
+
+#[test]
+fn migrate_fixed_prices_to_pegs_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// the pegs of genesis are never migrated
+		assert!(UpgradedToPegs::<Runtime>::get());
+		PricesModule::migrate_fixed_prices_to_pegs(vec![(DNAR, Price::one())]);
+		assert_eq!(PricesModule::pegs(DNAR), None);
+
+		// a chain upgraded from the fixed prices
+		UpgradedToPegs::<Runtime>::kill();
+		Pegs::<Runtime>::remove(SETUSD);
+		let setr_peg = PricesModule::pegs(SETR);
+		PricesModule::migrate_fixed_prices_to_pegs(vec![
+			(SETUSD, Price::one()),
+			(SETR, Price::saturating_from_rational(1, 2)),
+		]);
+		assert_eq!(PricesModule::pegs(SETUSD), Some(PegDefinition::Fixed(Price::one())));
+		assert_eq!(PricesModule::pegs(SETR), setr_peg);
+		assert!(UpgradedToPegs::<Runtime>::get());
+
+		// runs once
+		Pegs::<Runtime>::remove(SETUSD);
+		PricesModule::migrate_fixed_prices_to_pegs(vec![(SETUSD, Price::one())]);
+		assert_eq!(PricesModule::pegs(SETUSD), None);
+	});
+}
+
+#[test]
+fn set_peg_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let peg = PegDefinition::Fixed(Price::saturating_from_rational(1, 2));
+
+		assert_noop!(
+			PricesModule::set_peg(Origin::signed(5), SERP, Some(peg.clone())),
+			BadOrigin,
+		);
+
+		assert_eq!(PricesModule::pegs(SERP), None);
+		assert_ok!(PricesModule::set_peg(Origin::signed(1), SERP, Some(peg.clone())));
+		System::assert_last_event(Event::PricesModule(crate::Event::PegUpdated(SERP, Some(peg.clone()))));
+		assert_eq!(PricesModule::pegs(SERP), Some(peg));
+		assert_eq!(PricesModule::access_price(SERP), Some(Price::saturating_from_rational(1, 2)));
+
+		assert_ok!(PricesModule::set_peg(Origin::signed(1), SERP, None));
+		System::assert_last_event(Event::PricesModule(crate::Event::PegUpdated(SERP, None)));
+		assert_eq!(PricesModule::pegs(SERP), None);
+		assert_eq!(PricesModule::access_price(SERP), Some(Price::saturating_from_integer(50000)));
+	});
+}
+
+#[test]
+fn set_peg_rejects_invalid_peg() {
+	ExtBuilder::default().build().execute_with(|| {
+		let half = Ratio::saturating_from_rational(1, 2);
+		let invalid_pegs = vec![
+			PegDefinition::Fixed(Price::zero()),
+			PegDefinition::Basket(vec![]),
+			PegDefinition::Basket(vec![(SETUSD, half), (DNAR, half), (SERP, half), (SETM, half)]),
+			PegDefinition::Basket(vec![(SETUSD, half), (DNAR, Ratio::zero())]),
+			PegDefinition::Basket(vec![(SETUSD, half), (LP_SETUSD_DNAR, half)]),
+			PegDefinition::Basket(vec![(SETUSD, half), (SETM, half)]),
+		];
+		for peg in invalid_pegs {
+			assert_noop!(
+				PricesModule::set_peg(Origin::signed(1), SETM, Some(peg)),
+				Error::<Runtime>::InvalidPeg,
+			);
+		}
+
+		// the currencies of a basket must not be pegged to a basket
+		assert_ok!(PricesModule::set_peg(
+			Origin::signed(1),
+			SERP,
+			Some(PegDefinition::Basket(vec![(SETUSD, half)]))
+		));
+		assert_noop!(
+			PricesModule::set_peg(Origin::signed(1), SETM, Some(PegDefinition::Basket(vec![(SERP, half)]))),
+			Error::<Runtime>::InvalidPeg,
+		);
+	});
+}
+
+#[test]
+fn access_price_of_basket_pegged_currency() {
+	ExtBuilder::default().build().execute_with(|| {
+		// 1/2 SETUSD + 1/100 DNAR
+		assert_ok!(PricesModule::set_peg(
+			Origin::signed(1),
+			SETM,
+			Some(PegDefinition::Basket(vec![
+				(SETUSD, Ratio::saturating_from_rational(1, 2)),
+				(DNAR, Ratio::saturating_from_rational(1, 100)),
+			]))
+		));
+		assert_eq!(
+			PricesModule::access_price(SETM),
+			Some(Price::saturating_from_rational(3, 2))
+		);
+
+		mock_oracle_update();
+		assert_eq!(
+			PricesModule::access_price(SETM),
+			Some(Price::saturating_from_rational(6, 10))
+		);
+
+		// no price if the price of a currency of the basket is missing
+		assert_ok!(PricesModule::set_peg(Origin::signed(1), SETUSD, None));
+		assert_eq!(PricesModule::access_price(SETUSD), None);
+		assert_eq!(PricesModule::access_price(SETM), None);
+	});
+}
+
+#[test]
+fn set_history_currencies_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			PricesModule::set_history_currencies(Origin::signed(5), vec![SERP]),
+			BadOrigin,
+		);
+		assert_noop!(
+			PricesModule::set_history_currencies(Origin::signed(1), vec![SERP, DNAR, SETM]),
+			Error::<Runtime>::TooManyHistoryCurrencies,
+		);
+
+		assert_ok!(PricesModule::set_history_currencies(
+			Origin::signed(1),
+			vec![SERP, DNAR, SERP]
+		));
+		System::assert_last_event(Event::PricesModule(crate::Event::HistoryCurrenciesUpdated(vec![SERP, DNAR])));
+		assert_eq!(PricesModule::history_currencies(), vec![SERP, DNAR]);
+
+		PricesModule::on_finalize(1);
+		assert_eq!(PricesModule::price_history(SERP).len(), 1);
+		assert_eq!(PricesModule::price_history(DNAR).len(), 1);
+
+		// the history of the currencies no longer tracked is removed
+		assert_ok!(PricesModule::set_history_currencies(Origin::signed(1), vec![SERP]));
+		assert_eq!(PricesModule::price_history(SERP).len(), 1);
+		assert_eq!(PricesModule::price_history(DNAR), vec![]);
+	});
+}
+
+#[test]
+fn price_history_is_ring_buffer() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(PricesModule::set_history_currencies(Origin::signed(1), vec![DNAR]));
+		let price = PricesModule::access_price(DNAR);
+
+		for block_number in 1..=5 {
+			System::set_block_number(block_number);
+			set_now(block_number * 1000);
+			if block_number == 4 {
+				mock_oracle_update();
+			}
+			PricesModule::on_finalize(block_number);
+		}
+
+		let history = PricesModule::price_history(DNAR);
+		assert_eq!(
+			history.iter().map(|record| record.block_number).collect::<Vec<_>>(),
+			vec![3, 4, 5]
+		);
+		assert_eq!(
+			history[0],
+			PriceRecord {
+				block_number: 3,
+				timestamp: 3000,
+				oracle_price: Some(Price::saturating_from_integer(100)),
+				price,
+				locked: false,
+			}
+		);
+		assert_eq!(history[1].oracle_price, Some(Price::saturating_from_integer(10)));
+		assert_eq!(history[2].timestamp, 5000);
+	});
+}
+
+#[test]
+fn price_record_includes_locked_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		let oracle_price = Price::saturating_from_integer(100);
+		let price = PricesModule::access_price(DNAR);
+		assert_eq!(
+			PricesModule::price_record(DNAR),
+			PriceRecord {
+				block_number: 0,
+				timestamp: 0,
+				oracle_price: Some(oracle_price),
+				price,
+				locked: false,
+			}
+		);
+
+		assert_ok!(PricesModule::lock_price(Origin::signed(1), DNAR));
+		mock_oracle_update();
+		let record = PricesModule::price_record(DNAR);
+		assert_eq!(record.oracle_price, Some(Price::saturating_from_integer(10)));
+		assert_eq!(record.price, price);
+		assert!(record.locked);
+	});
+}
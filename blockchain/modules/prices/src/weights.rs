@@ -24,6 +24,10 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 3.0.0
 //! DATE: 2021-02-27, STEPS: [50, ], REPEAT: 20, LOW RANGE: [], HIGH RANGE: []
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
+//!
+//! `set_circuit_breaker`, `on_initialize`, `set_peg`, `set_history_currencies`
+//! and `record_price_history` are hand estimates until they are regenerated
+//! from the benchmarks in `runtime/src/benchmarking/prices.rs`.
 
 // Executed Command:
 // target/release/setheum-node
@@ -52,6 +56,11 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn lock_price() -> Weight;
 	fn unlock_price() -> Weight;
+	fn set_circuit_breaker() -> Weight;
+	fn on_initialize(c: u32, ) -> Weight;
+	fn set_peg(c: u32, ) -> Weight;
+	fn set_history_currencies(c: u32, ) -> Weight;
+	fn record_price_history(c: u32, ) -> Weight;
 }
 
 /// Weights for module_prices using the Setheum node and recommended hardware.
@@ -66,6 +75,39 @@ impl<T: frame_system::Config> WeightInfo for SetheumWeight<T> {
 		(12_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_circuit_breaker() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn on_initialize(c: u32, ) -> Weight {
+		(2_105_000 as Weight)
+			.saturating_add((21_384_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+	}
+	fn set_peg(c: u32, ) -> Weight {
+		(16_418_000 as Weight)
+			.saturating_add((3_102_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_history_currencies(c: u32, ) -> Weight {
+		(15_276_000 as Weight)
+			.saturating_add((4_833_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn record_price_history(c: u32, ) -> Weight {
+		(1_204_000 as Weight)
+			.saturating_add((28_617_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads((8 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -79,4 +121,37 @@ impl WeightInfo for () {
 		(12_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn set_circuit_breaker() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn on_initialize(c: u32, ) -> Weight {
+		(2_105_000 as Weight)
+			.saturating_add((21_384_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads((5 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+	}
+	fn set_peg(c: u32, ) -> Weight {
+		(16_418_000 as Weight)
+			.saturating_add((3_102_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_history_currencies(c: u32, ) -> Weight {
+		(15_276_000 as Weight)
+			.saturating_add((4_833_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn record_price_history(c: u32, ) -> Weight {
+		(1_204_000 as Weight)
+			.saturating_add((28_617_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads((8 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+	}
 }
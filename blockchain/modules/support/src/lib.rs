@@ -25,9 +25,9 @@ use codec::{Decode, Encode, FullCodec};
 use frame_support::pallet_prelude::{DispatchClass, Pays, Weight};
 use primitives::{
 	Balance as AsBalance,
-	CampaignId, CurrencyId,
+	CampaignId, CurrencyId, ForeignAssetId,
 	evm::{CallInfo, EvmAddress},
-	task::TaskResult
+	task::{TaskPriority, TaskResult},
 };
 use scale_info::TypeInfo;
 use sp_core::H160;
@@ -552,7 +552,11 @@ pub trait DispatchableTask {
 
 /// Idle scheduler trait
 pub trait IdleScheduler<Task> {
+	/// Schedule `task` with the normal priority and the default max weight.
 	fn schedule(task: Task) -> DispatchResult;
+	/// Schedule `task` with `priority`, giving it at most `max_weight` each
+	/// time it is dispatched.
+	fn schedule_with(task: Task, priority: TaskPriority, max_weight: Weight) -> DispatchResult;
 }
 
 #[cfg(feature = "std")]
@@ -567,6 +571,90 @@ impl<Task> IdleScheduler<Task> for () {
 	fn schedule(_task: Task) -> DispatchResult {
 		unimplemented!()
 	}
+
+	fn schedule_with(_task: Task, _priority: TaskPriority, _max_weight: Weight) -> DispatchResult {
+		unimplemented!()
+	}
+}
+
+/// An abstraction of vesting for modules that distribute locked funds.
+pub trait VestedTransfer<AccountId, CurrencyId, BlockNumber, Balance> {
+	/// Transfer `per_period * period_count` of `currency_id` from `from` to `to`
+	/// and lock it under a new graded vesting schedule.
+	fn vested_transfer(
+		currency_id: CurrencyId,
+		from: &AccountId,
+		to: &AccountId,
+		start: BlockNumber,
+		period: BlockNumber,
+		period_count: u32,
+		per_period: Balance,
+	) -> DispatchResult;
+}
+
+/// ERC20-style allowances of multi-currencies.
+pub trait MultiCurrencyAllowance<AccountId, CurrencyId, Balance> {
+	/// The amount of `currency_id` that `spender` is allowed to transfer
+	/// from `owner`.
+	fn allowance(currency_id: CurrencyId, owner: &AccountId, spender: &AccountId) -> Balance;
+
+	/// Set the allowance of `spender` over the `currency_id` of `owner`.
+	fn approve(currency_id: CurrencyId, owner: &AccountId, spender: &AccountId, amount: Balance) -> DispatchResult;
+
+	/// Transfer `amount` of `currency_id` from `from` to `to` by `spender`,
+	/// deducted from the allowance.
+	fn transfer_from(
+		currency_id: CurrencyId,
+		spender: &AccountId,
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+	) -> DispatchResult;
+}
+
+/// Transfers of multi-currencies carrying a memo, e.g. the payment reference
+/// of a deposit.
+pub trait MultiCurrencyMemo<AccountId, CurrencyId, Balance> {
+	/// Transfer `amount` of `currency_id` from `from` to `to` with `memo`.
+	fn transfer_with_memo(
+		currency_id: CurrencyId,
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+		memo: Vec<u8>,
+	) -> DispatchResult;
+}
+
+/// NFT royalties, see EIP-2981.
+pub trait NFTRoyalty<AccountId, ClassId, Balance> {
+	/// Returns the royalty recipient and the royalty amount of a sale of a
+	/// token of `class_id` for `sale_price`.
+	fn royalty_info(class_id: ClassId, sale_price: Balance) -> Option<(AccountId, Balance)>;
+}
+
+/// The metadata of a foreign asset.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AssetMetadata<Balance> {
+	/// The name of the asset
+	pub name: Vec<u8>,
+	/// The symbol of the asset
+	pub symbol: Vec<u8>,
+	/// The decimals of the asset
+	pub decimals: u8,
+	/// The existential deposit of the asset
+	pub minimal_balance: Balance,
+}
+
+/// A registry of foreign assets which are not known at compile time.
+pub trait ForeignAssetRegistry<Balance> {
+	/// Returns the metadata of a registered foreign asset.
+	fn metadata(foreign_asset_id: ForeignAssetId) -> Option<AssetMetadata<Balance>>;
+}
+
+impl<Balance> ForeignAssetRegistry<Balance> for () {
+	fn metadata(_foreign_asset_id: ForeignAssetId) -> Option<AssetMetadata<Balance>> {
+		None
+	}
 }
 
 pub trait EmergencyShutdown {
@@ -578,6 +666,18 @@ pub trait PrecompileCallerFilter {
 	fn is_allowed(caller: H160) -> bool;
 }
 
+/// Return true if the EVM call to the contract or precompile with the input
+/// is paused.
+pub trait EvmCallPaused {
+	fn is_paused(contract: H160, input: &[u8]) -> bool;
+}
+
+impl EvmCallPaused for () {
+	fn is_paused(_contract: H160, _input: &[u8]) -> bool {
+		false
+	}
+}
+
 /// An abstraction of EVM for EVMBridge
 pub trait EVM<AccountId> {
 	type Balance: AtLeast32BitUnsigned + Copy + MaybeSerializeDeserialize + Default;
@@ -21,6 +21,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
+use codec::{Decode, Encode};
 use frame_support::{
 	dispatch::{CallMetadata, GetCallMetadata},
 	pallet_prelude::*,
@@ -28,8 +29,10 @@ use frame_support::{
 	transactional,
 };
 use frame_system::pallet_prelude::*;
-use sp_runtime::DispatchResult;
+use primitives::evm::EvmAddress;
+use sp_runtime::{DispatchResult, RuntimeDebug};
 use sp_std::{prelude::*, vec::Vec};
+use support::EvmCallPaused;
 
 mod mock;
 mod tests;
@@ -38,6 +41,35 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The function name which pauses all the calls of a pallet.
+pub const ALL_FUNCTIONS: &[u8] = b"*";
+
+/// The selector of an EVM call, the first 4 bytes of the input.
+pub type Selector = [u8; 4];
+
+/// The calls to pause.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub enum PauseTarget {
+	/// The call of a pallet, `*` as the function name matches all the calls
+	/// of the pallet. \[pallet_name_bytes, function_name_bytes\]
+	Transaction(Vec<u8>, Vec<u8>),
+	/// The EVM calls to a contract, all the calls if the selector is none.
+	/// \[contract, selector\]
+	EvmCall(EvmAddress, Option<Selector>),
+}
+
+/// Get the target contract and the input of an EVM call.
+pub trait GetEvmCall<Call> {
+	/// Returns the target contract and the input if `call` is an EVM call.
+	fn evm_call(call: &Call) -> Option<(EvmAddress, &[u8])>;
+}
+
+impl<Call> GetEvmCall<Call> for () {
+	fn evm_call(_call: &Call) -> Option<(EvmAddress, &[u8])> {
+		None
+	}
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -49,6 +81,21 @@ pub mod module {
 		/// The origin which may set filter.
 		type UpdateOrigin: EnsureOrigin<Self::Origin>;
 
+		/// The governance pallets, whose calls can't be paused all at once so
+		/// that governance can always act and unpause.
+		type GovernancePallets: Contains<Vec<u8>>;
+
+		/// Get the EVM calls to filter by contract and selector.
+		type EvmCall: GetEvmCall<<Self as frame_system::Config>::Call>;
+
+		/// The maximum number of the targets paused or unpaused in a batch.
+		#[pallet::constant]
+		type MaxPauseBatch: Get<u32>;
+
+		/// The maximum number of the pauses expiring at a block.
+		#[pallet::constant]
+		type MaxExpiringPauses: Get<u32>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -59,6 +106,12 @@ pub mod module {
 		CannotPause,
 		/// invalid character encoding
 		InvalidCharacter,
+		/// Too many targets in a batch
+		TooManyTargets,
+		/// The expiry is not in the future
+		InvalidExpiry,
+		/// Too many pauses expire at the block
+		TooManyExpiringPauses,
 	}
 
 	#[pallet::event]
@@ -68,6 +121,13 @@ pub mod module {
 		TransactionPaused(Vec<u8>, Vec<u8>),
 		/// Unpaused transaction . \[pallet_name_bytes, function_name_bytes\]
 		TransactionUnpaused(Vec<u8>, Vec<u8>),
+		/// Paused EVM calls. \[contract, selector\]
+		EvmCallPaused(EvmAddress, Option<Selector>),
+		/// Unpaused EVM calls. \[contract, selector\]
+		EvmCallUnpaused(EvmAddress, Option<Selector>),
+		/// The pause expires at the block, and is then unpaused. \[target,
+		/// expiry\]
+		PauseExpirySet(PauseTarget, T::BlockNumber),
 	}
 
 	/// The paused transaction map
@@ -77,11 +137,56 @@ pub mod module {
 	#[pallet::getter(fn paused_transactions)]
 	pub type PausedTransactions<T: Config> = StorageMap<_, Twox64Concat, (Vec<u8>, Vec<u8>), (), OptionQuery>;
 
+	/// The paused EVM calls, all the calls to the contract if the selector is
+	/// none
+	///
+	/// PausedEvmCalls: map (EvmAddress, Option<Selector>) => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn paused_evm_calls)]
+	pub type PausedEvmCalls<T: Config> =
+		StorageMap<_, Twox64Concat, (EvmAddress, Option<Selector>), (), OptionQuery>;
+
+	/// The block at which a pause expires
+	///
+	/// PauseExpiries: map PauseTarget => Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn pause_expiries)]
+	pub type PauseExpiries<T: Config> = StorageMap<_, Twox64Concat, PauseTarget, T::BlockNumber, OptionQuery>;
+
+	/// The pauses expiring at a block. Stale entries, whose expiry is changed
+	/// later, are skipped.
+	///
+	/// ExpiringPauses: double_map BlockNumber, PauseTarget => Option<()>
+	#[pallet::storage]
+	pub type ExpiringPauses<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, PauseTarget, (), OptionQuery>;
+
+	/// The number of the pauses expiring at a block, stale entries included.
+	///
+	/// ExpiringPauseCount: map BlockNumber => u32
+	#[pallet::storage]
+	#[pallet::getter(fn expiring_pause_count)]
+	pub type ExpiringPauseCount<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, u32, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Unpause the pauses expiring at the block, at most
+		/// `MaxExpiringPauses`.
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			ExpiringPauseCount::<T>::remove(now);
+			let mut count: u32 = 0;
+			for (target, _) in ExpiringPauses::<T>::drain_prefix(now) {
+				count += 1;
+				if Self::pause_expiries(&target) == Some(now) {
+					Self::do_unpause(target);
+				}
+			}
+			T::WeightInfo::on_initialize(count)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -89,21 +194,7 @@ pub mod module {
 		#[transactional]
 		pub fn pause_transaction(origin: OriginFor<T>, pallet_name: Vec<u8>, function_name: Vec<u8>) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
-
-			// not allowed to pause calls of this pallet to ensure safe
-			let pallet_name_string = sp_std::str::from_utf8(&pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
-			ensure!(
-				pallet_name_string != <Self as PalletInfoAccess>::name(),
-				Error::<T>::CannotPause
-			);
-
-			PausedTransactions::<T>::mutate_exists((pallet_name.clone(), function_name.clone()), |maybe_paused| {
-				if maybe_paused.is_none() {
-					*maybe_paused = Some(());
-					Self::deposit_event(Event::TransactionPaused(pallet_name, function_name));
-				}
-			});
-			Ok(())
+			Self::do_pause(PauseTarget::Transaction(pallet_name, function_name), None)
 		}
 
 		#[pallet::weight(T::WeightInfo::unpause_transaction())]
@@ -114,14 +205,155 @@ pub mod module {
 			function_name: Vec<u8>,
 		) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
-			if PausedTransactions::<T>::take((&pallet_name, &function_name)).is_some() {
-				Self::deposit_event(Event::TransactionUnpaused(pallet_name, function_name));
-			};
+			Self::do_unpause(PauseTarget::Transaction(pallet_name, function_name));
+			Ok(())
+		}
+
+		/// Pause a batch of transactions and EVM calls, until manually
+		/// unpaused or until the expiry block.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `targets`: the calls to pause.
+		/// - `expiry`: the block at which the pauses are unpaused, `None` to
+		///   pause until manually unpaused. At most `MaxExpiringPauses` pauses
+		///   expire at a block.
+		#[pallet::weight(T::WeightInfo::pause_batch(targets.len() as u32))]
+		#[transactional]
+		pub fn pause_batch(
+			origin: OriginFor<T>,
+			targets: Vec<PauseTarget>,
+			expiry: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				targets.len() as u32 <= T::MaxPauseBatch::get(),
+				Error::<T>::TooManyTargets
+			);
+			if let Some(expiry) = expiry {
+				ensure!(
+					expiry > frame_system::Pallet::<T>::block_number(),
+					Error::<T>::InvalidExpiry
+				);
+			}
+
+			for target in targets {
+				Self::do_pause(target, expiry)?;
+			}
+			Ok(())
+		}
+
+		/// Unpause a batch of transactions and EVM calls.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `targets`: the calls to unpause.
+		#[pallet::weight(T::WeightInfo::unpause_batch(targets.len() as u32))]
+		#[transactional]
+		pub fn unpause_batch(origin: OriginFor<T>, targets: Vec<PauseTarget>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				targets.len() as u32 <= T::MaxPauseBatch::get(),
+				Error::<T>::TooManyTargets
+			);
+
+			for target in targets {
+				Self::do_unpause(target);
+			}
 			Ok(())
 		}
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// Pause `target`, until `expiry` if any. Pausing a paused target
+	/// replaces its expiry.
+	fn do_pause(target: PauseTarget, expiry: Option<T::BlockNumber>) -> DispatchResult {
+		match &target {
+			PauseTarget::Transaction(pallet_name, function_name) => {
+				// not allowed to pause calls of this pallet to ensure safe
+				let pallet_name_string =
+					sp_std::str::from_utf8(pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+				ensure!(
+					pallet_name_string != <Self as PalletInfoAccess>::name(),
+					Error::<T>::CannotPause
+				);
+				// nor all the calls of a governance pallet
+				ensure!(
+					!(function_name[..] == *ALL_FUNCTIONS && T::GovernancePallets::contains(pallet_name)),
+					Error::<T>::CannotPause
+				);
+
+				PausedTransactions::<T>::mutate_exists((pallet_name.clone(), function_name.clone()), |maybe_paused| {
+					if maybe_paused.is_none() {
+						*maybe_paused = Some(());
+						Self::deposit_event(Event::TransactionPaused(pallet_name.clone(), function_name.clone()));
+					}
+				});
+			}
+			PauseTarget::EvmCall(contract, selector) => {
+				PausedEvmCalls::<T>::mutate_exists((*contract, *selector), |maybe_paused| {
+					if maybe_paused.is_none() {
+						*maybe_paused = Some(());
+						Self::deposit_event(Event::EvmCallPaused(*contract, *selector));
+					}
+				});
+			}
+		}
+
+		match expiry {
+			Some(expiry) => {
+				if !ExpiringPauses::<T>::contains_key(expiry, &target) {
+					ExpiringPauseCount::<T>::try_mutate(expiry, |count| -> DispatchResult {
+						ensure!(
+							*count < T::MaxExpiringPauses::get(),
+							Error::<T>::TooManyExpiringPauses
+						);
+						*count += 1;
+						Ok(())
+					})?;
+					ExpiringPauses::<T>::insert(expiry, &target, ());
+				}
+				PauseExpiries::<T>::insert(&target, expiry);
+				Self::deposit_event(Event::PauseExpirySet(target, expiry));
+			}
+			None => PauseExpiries::<T>::remove(&target),
+		}
+		Ok(())
+	}
+
+	/// Whether calling `contract` with `input` is paused, either for the
+	/// whole contract or for the selector in the first 4 bytes of `input`.
+	pub fn is_evm_call_paused(contract: EvmAddress, input: &[u8]) -> bool {
+		if PausedEvmCalls::<T>::contains_key((contract, None::<Selector>)) {
+			return true;
+		}
+
+		input.get(0..4).map_or(false, |bytes| {
+			let mut selector = Selector::default();
+			selector.copy_from_slice(bytes);
+			PausedEvmCalls::<T>::contains_key((contract, Some(selector)))
+		})
+	}
+
+	/// Unpause `target`, and clear its expiry.
+	fn do_unpause(target: PauseTarget) {
+		PauseExpiries::<T>::remove(&target);
+		match target {
+			PauseTarget::Transaction(pallet_name, function_name) => {
+				if PausedTransactions::<T>::take((&pallet_name, &function_name)).is_some() {
+					Self::deposit_event(Event::TransactionUnpaused(pallet_name, function_name));
+				}
+			}
+			PauseTarget::EvmCall(contract, selector) => {
+				if PausedEvmCalls::<T>::take((contract, selector)).is_some() {
+					Self::deposit_event(Event::EvmCallUnpaused(contract, selector));
+				}
+			}
+		}
+	}
+}
+
 pub struct PausedTransactionFilter<T>(sp_std::marker::PhantomData<T>);
 impl<T: Config> Contains<T::Call> for PausedTransactionFilter<T>
 where
@@ -132,6 +364,23 @@ where
 			function_name,
 			pallet_name,
 		} = call.get_call_metadata();
-		PausedTransactions::<T>::contains_key((pallet_name.as_bytes(), function_name.as_bytes()))
+		if PausedTransactions::<T>::contains_key((pallet_name.as_bytes(), function_name.as_bytes()))
+			|| PausedTransactions::<T>::contains_key((pallet_name.as_bytes(), ALL_FUNCTIONS))
+		{
+			return true;
+		}
+
+		T::EvmCall::evm_call(call).map_or(false, |(contract, input)| {
+			Pallet::<T>::is_evm_call_paused(contract, input)
+		})
+	}
+}
+
+/// Checked by the EVM runner for every call, so that paused contracts and
+/// precompiles revert when reached from an internal call too.
+pub struct PausedEvmCallFilter<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> EvmCallPaused for PausedEvmCallFilter<T> {
+	fn is_paused(contract: EvmAddress, input: &[u8]) -> bool {
+		Pallet::<T>::is_evm_call_paused(contract, input)
 	}
 }
@@ -107,9 +107,40 @@ ord_parameter_types! {
 	pub const One: AccountId = 1;
 }
 
+/// Treats a remark as an EVM call, the remark is the target contract followed
+/// by the input.
+pub struct MockEvmCall;
+impl GetEvmCall<Call> for MockEvmCall {
+	fn evm_call(call: &Call) -> Option<(EvmAddress, &[u8])> {
+		match call {
+			Call::System(frame_system::Call::remark(remark)) if remark.len() >= 20 => {
+				Some((EvmAddress::from_slice(&remark[..20]), &remark[20..]))
+			}
+			_ => None,
+		}
+	}
+}
+
+/// The system pallet stands in for the governance pallets.
+pub struct MockGovernancePallets;
+impl Contains<Vec<u8>> for MockGovernancePallets {
+	fn contains(pallet_name: &Vec<u8>) -> bool {
+		pallet_name[..] == *b"System"
+	}
+}
+
+parameter_types! {
+	pub const MaxPauseBatch: u32 = 3;
+	pub const MaxExpiringPauses: u32 = 4;
+}
+
 impl Config for Runtime {
 	type Event = Event;
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+	type GovernancePallets = MockGovernancePallets;
+	type EvmCall = MockEvmCall;
+	type MaxPauseBatch = MaxPauseBatch;
+	type MaxExpiringPauses = MaxExpiringPauses;
 	type WeightInfo = ();
 }
 
@@ -151,3 +151,224 @@ fn paused_transaction_filter_work() {
 		assert!(!PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
 	});
 }
+
+fn evm_call(contract: EvmAddress, input: &[u8]) -> <Runtime as frame_system::Config>::Call {
+	mock::Call::System(frame_system::Call::remark([contract.as_bytes(), input].concat()))
+}
+
+#[test]
+fn pause_all_functions_of_pallet_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::signed(1),
+			b"Balances".to_vec(),
+			ALL_FUNCTIONS.to_vec()
+		));
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
+
+		assert_noop!(
+			TransactionPause::pause_transaction(
+				Origin::signed(1),
+				b"TransactionPause".to_vec(),
+				ALL_FUNCTIONS.to_vec()
+			),
+			Error::<Runtime>::CannotPause
+		);
+	});
+}
+
+#[test]
+fn pause_all_functions_of_governance_pallet_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			TransactionPause::pause_transaction(Origin::signed(1), b"System".to_vec(), ALL_FUNCTIONS.to_vec()),
+			Error::<Runtime>::CannotPause
+		);
+		assert_noop!(
+			TransactionPause::pause_batch(
+				Origin::signed(1),
+				vec![PauseTarget::Transaction(b"System".to_vec(), ALL_FUNCTIONS.to_vec())],
+				Some(10)
+			),
+			Error::<Runtime>::CannotPause
+		);
+
+		// a single call of a governance pallet can still be paused
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::signed(1),
+			b"System".to_vec(),
+			b"remark".to_vec()
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionPaused(
+			b"System".to_vec(),
+			b"remark".to_vec(),
+		)));
+	});
+}
+
+#[test]
+fn pause_evm_calls_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let contract = EvmAddress::from_low_u64_be(1);
+		let other_contract = EvmAddress::from_low_u64_be(2);
+		let transfer = evm_call(contract, &[0xa9, 0x05, 0x9c, 0xbb, 1, 2]);
+		let approve = evm_call(contract, &[0x09, 0x5e, 0xa7, 0xb3]);
+		let other_transfer = evm_call(other_contract, &[0xa9, 0x05, 0x9c, 0xbb]);
+
+		assert_ok!(TransactionPause::pause_batch(
+			Origin::signed(1),
+			vec![PauseTarget::EvmCall(contract, Some([0xa9, 0x05, 0x9c, 0xbb]))],
+			None
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::EvmCallPaused(
+			contract,
+			Some([0xa9, 0x05, 0x9c, 0xbb]),
+		)));
+		assert!(PausedTransactionFilter::<Runtime>::contains(&transfer));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(&approve));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(&other_transfer));
+		assert!(PausedEvmCallFilter::<Runtime>::is_paused(contract, &[0xa9, 0x05, 0x9c, 0xbb]));
+		assert!(!PausedEvmCallFilter::<Runtime>::is_paused(contract, &[0xa9, 0x05]));
+
+		assert_ok!(TransactionPause::pause_batch(
+			Origin::signed(1),
+			vec![PauseTarget::EvmCall(contract, None)],
+			None
+		));
+		assert!(PausedTransactionFilter::<Runtime>::contains(&approve));
+		assert!(PausedTransactionFilter::<Runtime>::contains(&evm_call(contract, &[])));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(&other_transfer));
+
+		assert_ok!(TransactionPause::unpause_batch(
+			Origin::signed(1),
+			vec![
+				PauseTarget::EvmCall(contract, None),
+				PauseTarget::EvmCall(contract, Some([0xa9, 0x05, 0x9c, 0xbb])),
+			]
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::EvmCallUnpaused(
+			contract,
+			Some([0xa9, 0x05, 0x9c, 0xbb]),
+		)));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(&transfer));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(&approve));
+		assert!(!PausedEvmCallFilter::<Runtime>::is_paused(contract, &[]));
+	});
+}
+
+#[test]
+fn pause_batch_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let targets = vec![
+			PauseTarget::Transaction(b"Balances".to_vec(), b"transfer".to_vec()),
+			PauseTarget::Transaction(b"Tokens".to_vec(), ALL_FUNCTIONS.to_vec()),
+		];
+
+		assert_noop!(
+			TransactionPause::pause_batch(Origin::signed(5), targets.clone(), None),
+			BadOrigin
+		);
+		assert_noop!(
+			TransactionPause::pause_batch(
+				Origin::signed(1),
+				vec![PauseTarget::EvmCall(Default::default(), None); 4],
+				None
+			),
+			Error::<Runtime>::TooManyTargets
+		);
+		assert_noop!(
+			TransactionPause::pause_batch(Origin::signed(1), targets.clone(), Some(1)),
+			Error::<Runtime>::InvalidExpiry
+		);
+		// the batch is reverted if any target cannot be paused
+		assert_noop!(
+			TransactionPause::pause_batch(
+				Origin::signed(1),
+				vec![
+					PauseTarget::Transaction(b"Balances".to_vec(), b"transfer".to_vec()),
+					PauseTarget::Transaction(b"TransactionPause".to_vec(), ALL_FUNCTIONS.to_vec()),
+				],
+				None
+			),
+			Error::<Runtime>::CannotPause
+		);
+
+		assert_ok!(TransactionPause::pause_batch(Origin::signed(1), targets.clone(), None));
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+		assert!(PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
+
+		assert_ok!(TransactionPause::unpause_batch(Origin::signed(1), targets));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
+	});
+}
+
+#[test]
+fn pause_expiry_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let target = PauseTarget::Transaction(b"Balances".to_vec(), b"transfer".to_vec());
+
+		assert_ok!(TransactionPause::pause_batch(Origin::signed(1), vec![target.clone()], Some(3)));
+		System::assert_last_event(Event::TransactionPause(crate::Event::PauseExpirySet(target.clone(), 3)));
+		assert_eq!(TransactionPause::pause_expiries(&target), Some(3));
+
+		TransactionPause::on_initialize(2);
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+
+		TransactionPause::on_initialize(3);
+		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionUnpaused(
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+		)));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+		assert_eq!(TransactionPause::pause_expiries(&target), None);
+
+		// pausing again without expiry keeps it paused after the former expiry
+		assert_ok!(TransactionPause::pause_batch(Origin::signed(1), vec![target.clone()], Some(5)));
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::signed(1),
+			b"Balances".to_vec(),
+			b"transfer".to_vec()
+		));
+		assert_eq!(TransactionPause::pause_expiries(&target), None);
+		TransactionPause::on_initialize(5);
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+	});
+}
+
+#[test]
+fn pause_expiry_is_bounded_per_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let targets: Vec<PauseTarget> = (0..5)
+			.map(|i| PauseTarget::EvmCall(EvmAddress::from_low_u64_be(i), None))
+			.collect();
+
+		assert_ok!(TransactionPause::pause_batch(Origin::signed(1), targets[0..3].to_vec(), Some(5)));
+		// pausing again at the same expiry is not counted twice
+		assert_ok!(TransactionPause::pause_batch(Origin::signed(1), targets[0..1].to_vec(), Some(5)));
+		assert_eq!(TransactionPause::expiring_pause_count(5), 3);
+		assert_noop!(
+			TransactionPause::pause_batch(Origin::signed(1), targets[3..5].to_vec(), Some(5)),
+			Error::<Runtime>::TooManyExpiringPauses
+		);
+		assert_ok!(TransactionPause::pause_batch(Origin::signed(1), targets[3..5].to_vec(), Some(6)));
+		assert_ok!(TransactionPause::pause_batch(Origin::signed(1), targets[3..4].to_vec(), Some(5)));
+		assert_eq!(TransactionPause::expiring_pause_count(5), 4);
+
+		TransactionPause::on_initialize(5);
+		assert_eq!(TransactionPause::expiring_pause_count(5), 0);
+		assert_eq!(TransactionPause::paused_evm_calls((EvmAddress::from_low_u64_be(0), None)), None);
+		assert_eq!(TransactionPause::paused_evm_calls((EvmAddress::from_low_u64_be(3), None)), None);
+		assert_eq!(
+			TransactionPause::paused_evm_calls((EvmAddress::from_low_u64_be(4), None)),
+			Some(())
+		);
+	});
+}
@@ -23,6 +23,10 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 3.0.0
 //! DATE: 2021-08-16, STEPS: `[50, ]`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
+//!
+//! `pause_batch`, `unpause_batch` and `on_initialize` are hand estimates
+//! until they are regenerated from the benchmarks in
+//! `runtime/src/benchmarking/transaction_pause.rs`.
 
 // Executed Command:
 // target/release/setheum-node
@@ -51,6 +55,9 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn pause_transaction() -> Weight;
 	fn unpause_transaction() -> Weight;
+	fn pause_batch(c: u32, ) -> Weight;
+	fn unpause_batch(c: u32, ) -> Weight;
+	fn on_initialize(c: u32, ) -> Weight;
 }
 
 /// Weights for module_transaction_pause using the Setheum node and recommended hardware.
@@ -66,6 +73,25 @@ impl<T: frame_system::Config> WeightInfo for SetheumWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn pause_batch(c: u32, ) -> Weight {
+		(9_871_000 as Weight)
+			.saturating_add((21_472_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(c as Weight)))
+	}
+	fn unpause_batch(c: u32, ) -> Weight {
+		(9_534_000 as Weight)
+			.saturating_add((20_816_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
+	fn on_initialize(c: u32, ) -> Weight {
+		(3_027_000 as Weight)
+			.saturating_add((18_935_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -80,4 +106,23 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn pause_batch(c: u32, ) -> Weight {
+		(9_871_000 as Weight)
+			.saturating_add((21_472_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(c as Weight)))
+	}
+	fn unpause_batch(c: u32, ) -> Weight {
+		(9_534_000 as Weight)
+			.saturating_add((20_816_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
+	fn on_initialize(c: u32, ) -> Weight {
+		(3_027_000 as Weight)
+			.saturating_add((18_935_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
+	}
 }
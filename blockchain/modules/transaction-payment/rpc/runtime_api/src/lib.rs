@@ -0,0 +1,125 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for quoting the transaction fee in the currency
+//! the fee is actually paid in.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::{Codec, Decode, Encode};
+use primitives::CurrencyId;
+use sp_runtime::{
+	traits::{MaybeDisplay, MaybeFromStr},
+	RuntimeDebug,
+};
+use sp_std::prelude::*;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// How the fee is paid.
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum FeePaymentMethod<AccountId> {
+	/// Paid in native currency.
+	Native,
+	/// Paid by the sponsorship of the sponsor.
+	Sponsored(AccountId),
+	/// Exchanged with the fee treasury at oracle price.
+	Oracle,
+	/// Swapped by DEX through the path.
+	Swap(Vec<CurrencyId>),
+}
+
+/// The quote of the fee of a transaction.
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(serialize = "AccountId: Serialize, Balance: std::fmt::Display"))
+)]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(deserialize = "AccountId: Deserialize<'de>, Balance: std::str::FromStr"))
+)]
+pub struct FeeQuote<AccountId, Balance> {
+	/// The fee in native currency.
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub fee: Balance,
+	/// How the fee is paid.
+	pub method: FeePaymentMethod<AccountId>,
+	/// The currency the fee is paid in.
+	pub supply_currency_id: CurrencyId,
+	/// The expected amount of `supply_currency_id` paid. Paying in other
+	/// currencies also tops up the native balance to the existential
+	/// deposit.
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub supply_amount: Balance,
+}
+
+/// The reason the fee cannot be paid. When several fee currencies fail,
+/// the furthest failure is reported.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum FeeQuoteError {
+	/// The native balance is not enough, and the account holds none of the
+	/// whitelisted fee currencies or supply currencies of the fee swap paths.
+	NoFeeCurrency,
+	/// No oracle price or fee treasury liquidity, and no DEX liquidity for
+	/// the fee currencies held.
+	NoPriceOrLiquidity,
+	/// The DEX swap exceeds the slippage limit compared to oracle price.
+	ExceedSwapSlippageLimit,
+	/// The balance of the fee currencies held is not enough.
+	InsufficientFeeCurrency,
+}
+
+#[cfg(feature = "std")]
+fn serialize_as_string<S: Serializer, T: std::fmt::Display>(t: &T, serializer: S) -> Result<S::Ok, S::Error> {
+	serializer.serialize_str(&t.to_string())
+}
+
+#[cfg(feature = "std")]
+fn deserialize_from_string<'de, D: Deserializer<'de>, T: std::str::FromStr>(deserializer: D) -> Result<T, D::Error> {
+	let s = String::deserialize(deserializer)?;
+	s.parse::<T>()
+		.map_err(|_| serde::de::Error::custom("Parse from string failed"))
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait FeeQuoteApi<AccountId, Balance> where
+		AccountId: Codec,
+		Balance: Codec + MaybeDisplay + MaybeFromStr,
+	{
+		/// Quote the fee of `uxt` paid by `who`, ignoring the tip.
+		fn query_fee_quote(
+			who: AccountId,
+			uxt: Block::Extrinsic,
+			len: u32,
+		) -> Result<FeeQuote<AccountId, Balance>, FeeQuoteError>;
+	}
+}
@@ -0,0 +1,123 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for quoting the transaction fee in the currency the fee is
+//! actually paid in.
+
+use codec::{Codec, Decode};
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
+};
+use std::sync::Arc;
+
+pub use module_transaction_payment_rpc_runtime_api::{
+	FeePaymentMethod, FeeQuote, FeeQuoteApi as FeeQuoteRuntimeApi, FeeQuoteError,
+};
+
+#[rpc]
+pub trait FeeQuoteApi<BlockHash, AccountId, ResponseType> {
+	/// Quote the fee of the encoded extrinsic paid by `who`, returns how the
+	/// fee is paid and the expected amount of the supply currency.
+	#[rpc(name = "transactionPayment_queryFeeQuote")]
+	fn query_fee_quote(&self, who: AccountId, encoded_xt: Bytes, at: Option<BlockHash>) -> Result<ResponseType>;
+}
+
+/// A struct that implements the [`FeeQuoteApi`].
+pub struct FeeQuoteRpc<C, P> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> FeeQuoteRpc<C, P> {
+	/// Create new `FeeQuoteRpc` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The transaction was not decodable.
+	DecodeError,
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The fee cannot be paid.
+	CannotPayFee,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::DecodeError => 2,
+			Error::CannotPayFee => 3,
+		}
+	}
+}
+
+impl<C, Block, AccountId, Balance> FeeQuoteApi<<Block as BlockT>::Hash, AccountId, FeeQuote<AccountId, Balance>>
+	for FeeQuoteRpc<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: FeeQuoteRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec,
+	Balance: Codec + MaybeDisplay + MaybeFromStr,
+{
+	fn query_fee_quote(
+		&self,
+		who: AccountId,
+		encoded_xt: Bytes,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<FeeQuote<AccountId, Balance>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		let encoded_len = encoded_xt.len() as u32;
+
+		let uxt: Block::Extrinsic = Decode::decode(&mut &*encoded_xt).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::DecodeError.into()),
+			message: "Unable to quote fee.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})?;
+		api.query_fee_quote(&at, who, uxt, encoded_len)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to quote fee.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})?
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::CannotPayFee.into()),
+				message: "Cannot pay fee.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+}
@@ -24,6 +24,16 @@
 //!
 //! Transaction payment module is responsible for charge fee and tip in
 //! different currencies
+//!
+//! When the native balance is not enough to pay the fee, the fee is first
+//! exchanged at oracle price from one of the governance whitelisted fee
+//! currencies with the fee treasury, and otherwise swapped by DEX through the
+//! fee swap paths.
+//!
+//! Dapps can sponsor the fees of their users: a sponsor funds a sponsorship
+//! pool and declares the sponsored calls, either by pallet and call index or
+//! by the contract address of `module_evm::call`, and the sponsored users,
+//! with per-user and per-period spending caps.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -34,26 +44,29 @@ use frame_support::{
 	traits::{
 		Currency, ExistenceRequirement, Imbalance, NamedReservableCurrency, OnUnbalanced, SameOrOther, WithdrawReasons,
 	},
+	transactional,
 	weights::{DispatchInfo, GetDispatchInfo, Pays, PostDispatchInfo, WeightToFeeCoefficient, WeightToFeePolynomial},
-	BoundedVec,
+	BoundedVec, PalletId,
 };
 use frame_system::pallet_prelude::*;
+pub use module_transaction_payment_rpc_runtime_api::{FeePaymentMethod, FeeQuote, FeeQuoteError};
 use orml_traits::MultiCurrency;
 use pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo;
 use pallet_transaction_payment_rpc_runtime_api::{FeeDetails, InclusionFee};
-use primitives::{Balance, CurrencyId, ReserveIdentifier};
+use primitives::{evm::EvmAddress, Balance, CurrencyId, ReserveIdentifier};
 use sp_runtime::{
 	traits::{
-		Bounded, CheckedSub, Convert, DispatchInfoOf, One, PostDispatchInfoOf, SaturatedConversion, Saturating,
-		SignedExtension, UniqueSaturatedInto, Zero,
+		AccountIdConversion, Bounded, CheckedAdd, CheckedSub, Convert, DispatchInfoOf, One, PostDispatchInfoOf,
+		SaturatedConversion, Saturating, SignedExtension, UniqueSaturatedInto, Zero,
 	},
 	transaction_validity::{
 		InvalidTransaction, TransactionPriority, TransactionValidity, TransactionValidityError, ValidTransaction,
 	},
-	FixedPointNumber, FixedPointOperand, FixedU128, Perquintill,
+	FixedPointNumber, FixedPointOperand, FixedU128, Perquintill, RuntimeDebug,
 };
+use sp_io::KillStorageResult::{AllRemoved, SomeRemaining};
 use sp_std::{convert::TryInto, prelude::*, vec};
-use support::{DEXManager, PriceProvider, Ratio, TransactionPayment};
+use support::{DEXManager, PriceProvider, Ratio, SwapLimit, TransactionPayment};
 
 mod mock;
 mod tests;
@@ -65,9 +78,43 @@ pub use weights::WeightInfo;
 /// Fee multiplier.
 pub type Multiplier = FixedU128;
 
+/// The calls a sponsor pays the fees for.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+pub enum SponsoredCall {
+	/// A runtime call, by pallet index and call index.
+	Call(u8, u8),
+	/// `module_evm::call` to the contract address.
+	EvmContract(EvmAddress),
+}
+
+/// The sponsorship settings of a sponsor.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct SponsorshipInfo<Balance, BlockNumber> {
+	/// Number of blocks of a spending period.
+	pub period: BlockNumber,
+	/// The maximum fees paid for a single user in a period.
+	pub user_cap: Balance,
+	/// The maximum fees paid for all users in a period.
+	pub period_cap: Balance,
+	/// Pay for all users, or only for the sponsored users.
+	pub all_users: bool,
+}
+
+/// Inspect the target contract of an EVM call.
+pub trait EvmCallContract<Call> {
+	fn contract(call: &Call) -> Option<EvmAddress>;
+}
+
+impl<Call> EvmCallContract<Call> for () {
+	fn contract(_call: &Call) -> Option<EvmAddress> {
+		None
+	}
+}
+
 type PalletBalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 type NegativeImbalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+type SponsorshipInfoOf<T> = SponsorshipInfo<PalletBalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
 
 /// A struct to update the weight multiplier per block. It implements
 /// `Convert<Multiplier, Multiplier>`, meaning that it can convert the
@@ -222,6 +269,8 @@ pub mod module {
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
 		/// Native currency id, the actual received currency type as fee for
 		/// treasury. Should be SEE
 		#[pallet::constant]
@@ -271,6 +320,28 @@ pub mod module {
 		/// The price source to provider external market price.
 		type PriceSource: PriceProvider<CurrencyId>;
 
+		/// The fee treasury, it holds native currency to exchange with the
+		/// whitelisted fee currencies at oracle price.
+		#[pallet::constant]
+		type FeeTreasuryAccount: Get<Self::AccountId>;
+
+		/// The origin which may update the whitelisted fee currencies.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The Sponsorship pallet id, keeps the sponsorship pools.
+		#[pallet::constant]
+		type SponsorshipPalletId: Get<PalletId>;
+
+		/// The maximum number of the sponsored users and user spendings
+		/// removed by `remove_sponsorship`, the rest are removed when blocks
+		/// are idle.
+		#[pallet::constant]
+		type MaxSponsorshipRemovals: Get<u32>;
+
+		/// Inspect the target contract of EVM calls to match sponsored
+		/// contracts.
+		type EvmCallContract: EvmCallContract<<Self as frame_system::Config>::Call>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -294,6 +365,40 @@ pub mod module {
 	pub enum Error<T> {
 		/// The swap path is invalid
 		InvalidSwapPath,
+		/// The fee currency is invalid
+		InvalidFeeCurrency,
+		/// The oracle price of the fee currency is not available
+		PriceNotAvailable,
+		/// None of the whitelisted fee currencies can pay the fee
+		CannotPayFeeByOracle,
+		/// The sponsorship period is zero
+		InvalidSponsorship,
+		/// The sponsorship does not exist
+		SponsorshipNotFound,
+		/// The call is already sponsored by another sponsor
+		CallAlreadySponsored,
+		/// The previous sponsorship of the sponsor is still being removed
+		SponsorshipBeingRemoved,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId", PalletBalanceOf<T> = "Balance", SponsorshipInfoOf<T> = "SponsorshipInfo")]
+	pub enum Event<T: Config> {
+		/// The sponsorship is set. \[sponsor, sponsorship\]
+		SponsorshipUpdated(T::AccountId, SponsorshipInfoOf<T>),
+		/// The sponsorship is removed. \[sponsor\]
+		SponsorshipRemoved(T::AccountId),
+		/// The sponsored calls are updated. \[sponsor\]
+		SponsoredCallsUpdated(T::AccountId),
+		/// The sponsored users are updated. \[sponsor\]
+		SponsoredUsersUpdated(T::AccountId),
+		/// The sponsorship pool is funded. \[sponsor, amount\]
+		SponsorshipPoolFunded(T::AccountId, PalletBalanceOf<T>),
+		/// Withdrawn from the sponsorship pool. \[sponsor, amount\]
+		SponsorshipPoolWithdrawn(T::AccountId, PalletBalanceOf<T>),
+		/// The fee of `who` is paid by the sponsor. \[sponsor, who, fee\]
+		FeeSponsored(T::AccountId, T::AccountId, PalletBalanceOf<T>),
 	}
 
 	/// The next fee multiplier.
@@ -309,11 +414,99 @@ pub mod module {
 	pub type AlternativeFeeSwapPath<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, BoundedVec<CurrencyId, T::TradingPathLimit>, OptionQuery>;
 
+	/// The whitelisted fee currencies, which pay the fee at oracle price
+	/// plus a premium.
+	///
+	/// FeeCurrencies: map CurrencyId => Option<Ratio>
+	#[pallet::storage]
+	#[pallet::getter(fn fee_currencies)]
+	pub type FeeCurrencies<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Ratio, OptionQuery>;
+
+	/// The sponsorships of sponsors.
+	///
+	/// Sponsorships: map AccountId => Option<SponsorshipInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn sponsorships)]
+	pub type Sponsorships<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, SponsorshipInfoOf<T>, OptionQuery>;
+
+	/// The sponsor of the sponsored calls.
+	///
+	/// SponsoredCalls: map SponsoredCall => Option<AccountId>
+	#[pallet::storage]
+	#[pallet::getter(fn sponsored_calls)]
+	pub type SponsoredCalls<T: Config> = StorageMap<_, Twox64Concat, SponsoredCall, T::AccountId, OptionQuery>;
+
+	/// The users sponsored by the sponsor.
+	///
+	/// SponsoredUsers: double_map (sponsor, user) => Option<()>
+	#[pallet::storage]
+	pub type SponsoredUsers<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, T::AccountId, (), OptionQuery>;
+
+	/// The fees paid by the sponsor for the user in the period.
+	///
+	/// UserSpending: double_map (sponsor, user) => (period_index, spent)
+	#[pallet::storage]
+	#[pallet::getter(fn user_spending)]
+	pub type UserSpending<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		(T::BlockNumber, PalletBalanceOf<T>),
+		ValueQuery,
+	>;
+
+	/// The fees paid by the sponsor for all users in the period.
+	///
+	/// PeriodSpending: map sponsor => (period_index, spent)
+	#[pallet::storage]
+	#[pallet::getter(fn period_spending)]
+	pub type PeriodSpending<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (T::BlockNumber, PalletBalanceOf<T>), ValueQuery>;
+
+	/// The sponsors of which the sponsored users and user spendings are still
+	/// being removed.
+	///
+	/// PendingSponsorshipRemovals: map AccountId => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn pending_sponsorship_removals)]
+	pub type PendingSponsorshipRemovals<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (), OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Remove the sponsored users and user spendings of a removed
+		/// sponsorship with the remaining weight.
+		fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let mut used_weight = T::DbWeight::get().reads(1);
+			let sponsor = match PendingSponsorshipRemovals::<T>::iter_keys().next() {
+				Some(sponsor) => sponsor,
+				None => return used_weight,
+			};
+
+			// keep the weight of removing the pending removal
+			let limit = remaining_weight
+				.saturating_sub(used_weight.saturating_add(T::DbWeight::get().writes(1)))
+				.checked_div(T::DbWeight::get().writes(1))
+				.unwrap_or(Weight::max_value())
+				.min(T::MaxSponsorshipRemovals::get().into()) as u32;
+			if limit.is_zero() {
+				return used_weight;
+			}
+
+			let (removed, all_removed) = Self::remove_sponsorship_entries(&sponsor, limit);
+			used_weight = used_weight.saturating_add(T::DbWeight::get().writes(removed.into()));
+			if all_removed {
+				PendingSponsorshipRemovals::<T>::remove(&sponsor);
+				used_weight = used_weight.saturating_add(T::DbWeight::get().writes(1));
+			}
+			used_weight
+		}
+
 		/// `on_initialize` to return the weight used in `on_finalize`.
 		fn on_initialize(_: T::BlockNumber) -> Weight {
 			<T as Config>::WeightInfo::on_finalize()
@@ -393,6 +586,197 @@ pub mod module {
 			}
 			Ok(())
 		}
+
+		/// Whitelist a fee currency with its premium, or remove it from the
+		/// whitelist if `premium` is `None`.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: the fee currency, cannot be the native currency.
+		/// - `premium`: the premium charged on top of the oracle price.
+		#[pallet::weight(<T as Config>::WeightInfo::set_fee_currency())]
+		pub fn set_fee_currency(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			premium: Option<Ratio>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				currency_id != T::NativeCurrencyId::get(),
+				Error::<T>::InvalidFeeCurrency
+			);
+
+			if let Some(premium) = premium {
+				FeeCurrencies::<T>::insert(currency_id, premium);
+			} else {
+				FeeCurrencies::<T>::remove(currency_id);
+			}
+			Ok(())
+		}
+
+		/// Set the sponsorship of the caller.
+		///
+		/// - `period`: number of blocks of a spending period.
+		/// - `user_cap`: the maximum fees paid for a single user in a period.
+		/// - `period_cap`: the maximum fees paid for all users in a period.
+		/// - `all_users`: pay for all users, or only for the sponsored users.
+		#[pallet::weight(<T as Config>::WeightInfo::set_sponsorship())]
+		pub fn set_sponsorship(
+			origin: OriginFor<T>,
+			period: T::BlockNumber,
+			user_cap: PalletBalanceOf<T>,
+			period_cap: PalletBalanceOf<T>,
+			all_users: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!period.is_zero(), Error::<T>::InvalidSponsorship);
+			ensure!(
+				!PendingSponsorshipRemovals::<T>::contains_key(&who),
+				Error::<T>::SponsorshipBeingRemoved
+			);
+
+			let info = SponsorshipInfo {
+				period,
+				user_cap,
+				period_cap,
+				all_users,
+			};
+			Sponsorships::<T>::insert(&who, &info);
+			Self::deposit_event(Event::SponsorshipUpdated(who, info));
+			Ok(())
+		}
+
+		/// Remove the sponsorship of the caller, and withdraw all from the
+		/// sponsorship pool. At most `MaxSponsorshipRemovals` sponsored users
+		/// and user spendings are removed at once, the rest are removed when
+		/// blocks are idle, and the caller can't sponsor again until then.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_sponsorship(T::MaxSponsorshipRemovals::get()))]
+		#[transactional]
+		pub fn remove_sponsorship(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				Sponsorships::<T>::contains_key(&who),
+				Error::<T>::SponsorshipNotFound
+			);
+
+			let pool = Self::sponsorship_pool_account(&who);
+			<T as Config>::Currency::transfer(
+				&pool,
+				&who,
+				<T as Config>::Currency::free_balance(&pool),
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			Sponsorships::<T>::remove(&who);
+			PeriodSpending::<T>::remove(&who);
+			let (_, all_removed) = Self::remove_sponsorship_entries(&who, T::MaxSponsorshipRemovals::get());
+			if !all_removed {
+				PendingSponsorshipRemovals::<T>::insert(&who, ());
+			}
+			Self::deposit_event(Event::SponsorshipRemoved(who));
+			Ok(())
+		}
+
+		/// Update the sponsored calls of the caller.
+		///
+		/// - `add`: the calls to sponsor.
+		/// - `remove`: the calls to stop sponsoring.
+		#[pallet::weight(<T as Config>::WeightInfo::update_sponsored_calls((add.len() + remove.len()) as u32))]
+		#[transactional]
+		pub fn update_sponsored_calls(
+			origin: OriginFor<T>,
+			add: Vec<SponsoredCall>,
+			remove: Vec<SponsoredCall>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				Sponsorships::<T>::contains_key(&who),
+				Error::<T>::SponsorshipNotFound
+			);
+
+			for call in remove.iter() {
+				if SponsoredCalls::<T>::get(call).as_ref() == Some(&who) {
+					SponsoredCalls::<T>::remove(call);
+				}
+			}
+			for call in add.iter() {
+				// calls of removed sponsorships can be taken over
+				if let Some(sponsor) = SponsoredCalls::<T>::get(call) {
+					ensure!(
+						sponsor == who || !Sponsorships::<T>::contains_key(&sponsor),
+						Error::<T>::CallAlreadySponsored
+					);
+				}
+				SponsoredCalls::<T>::insert(call, &who);
+			}
+			Self::deposit_event(Event::SponsoredCallsUpdated(who));
+			Ok(())
+		}
+
+		/// Update the sponsored users of the caller.
+		///
+		/// - `add`: the users to sponsor.
+		/// - `remove`: the users to stop sponsoring.
+		#[pallet::weight(<T as Config>::WeightInfo::update_sponsored_users((add.len() + remove.len()) as u32))]
+		pub fn update_sponsored_users(
+			origin: OriginFor<T>,
+			add: Vec<T::AccountId>,
+			remove: Vec<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				Sponsorships::<T>::contains_key(&who),
+				Error::<T>::SponsorshipNotFound
+			);
+
+			for user in remove.iter() {
+				SponsoredUsers::<T>::remove(&who, user);
+			}
+			for user in add.iter() {
+				SponsoredUsers::<T>::insert(&who, user, ());
+			}
+			Self::deposit_event(Event::SponsoredUsersUpdated(who));
+			Ok(())
+		}
+
+		/// Transfer native currency from the caller to its sponsorship pool.
+		///
+		/// - `amount`: the amount to fund.
+		#[pallet::weight(<T as Config>::WeightInfo::fund_sponsorship_pool())]
+		pub fn fund_sponsorship_pool(origin: OriginFor<T>, amount: PalletBalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				Sponsorships::<T>::contains_key(&who),
+				Error::<T>::SponsorshipNotFound
+			);
+
+			<T as Config>::Currency::transfer(
+				&who,
+				&Self::sponsorship_pool_account(&who),
+				amount,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			Self::deposit_event(Event::SponsorshipPoolFunded(who, amount));
+			Ok(())
+		}
+
+		/// Transfer native currency from the sponsorship pool of the caller
+		/// back to the caller.
+		///
+		/// - `amount`: the amount to withdraw.
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_sponsorship_pool())]
+		pub fn withdraw_sponsorship_pool(origin: OriginFor<T>, amount: PalletBalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<T as Config>::Currency::transfer(
+				&Self::sponsorship_pool_account(&who),
+				&who,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+			Self::deposit_event(Event::SponsorshipPoolWithdrawn(who, amount));
+			Ok(())
+		}
 	}
 }
 
@@ -577,44 +961,23 @@ where
 		let native_existential_deposit = <T as Config>::Currency::minimum_balance();
 		let total_native = <T as Config>::Currency::total_balance(who);
 
-		// check native balance if is enough
-		let native_is_enough = fee.saturating_add(native_existential_deposit) <= total_native
-			&& <T as Config>::Currency::free_balance(who)
-				.checked_sub(&fee)
-				.map_or(false, |new_free_balance| {
-					<T as Config>::Currency::ensure_can_withdraw(who, fee, reason, new_free_balance).is_ok()
-				});
-
 		// native is not enough, try swap native to pay fee and gap
-		if !native_is_enough {
+		if !Self::native_is_enough(who, fee, reason) {
 			// add extra gap to keep alive after swap
 			let amount = fee.saturating_add(native_existential_deposit.saturating_sub(total_native));
+
+			// try to exchange with the fee treasury at oracle price first,
+			// fallback to swap by DEX.
+			if Self::exchange_fee_by_oracle(who, amount).is_ok() {
+				return;
+			}
+
 			let native_currency_id = T::NativeCurrencyId::get();
-			let default_fee_swap_path_list = T::DefaultFeeSwapPathList::get();
-			let fee_swap_path_list: Vec<Vec<CurrencyId>> =
-				if let Some(trading_path) = AlternativeFeeSwapPath::<T>::get(who) {
-					vec![vec![trading_path.into_inner()], default_fee_swap_path_list].concat()
-				} else {
-					default_fee_swap_path_list
-				};
-
-			for trading_path in fee_swap_path_list {
+			for trading_path in Self::fee_swap_path_list(who) {
 				match trading_path.last() {
 					Some(target_currency_id) if *target_currency_id == native_currency_id => {
 						let supply_currency_id = *trading_path.first().expect("these's first guaranteed by match");
-						// calculate the supply limit according to oracle price and the slippage limit,
-						// if oracle price is not avalible, do not limit
-						let max_supply_limit = if let Some(target_price) =
-							T::PriceSource::get_relative_price(*target_currency_id, supply_currency_id)
-						{
-							Ratio::one()
-								.saturating_sub(T::MaxSwapSlippageCompareToOracle::get())
-								.reciprocal()
-								.unwrap_or_else(Ratio::max_value)
-								.saturating_mul_int(target_price.saturating_mul_int(amount))
-						} else {
-							PalletBalanceOf::<T>::max_value()
-						};
+						let max_supply_limit = Self::max_swap_supply_amount(supply_currency_id, amount);
 
 						if T::DEX::swap_with_exact_target(
 							who,
@@ -634,6 +997,295 @@ where
 			}
 		}
 	}
+
+	/// Quote the fee of `call` paid by `who`, ignoring the tip. Follows the
+	/// order of charging the fee: sponsorship, native currency, whitelisted
+	/// fee currencies at oracle price, then DEX swap through the fee swap
+	/// paths.
+	pub fn quote_fee(
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::Call,
+		len: u32,
+	) -> Result<FeeQuote<T::AccountId, PalletBalanceOf<T>>, FeeQuoteError>
+	where
+		<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo> + GetDispatchInfo + Encode,
+	{
+		let native_currency_id = T::NativeCurrencyId::get();
+		let fee = Self::compute_fee(len, &call.get_dispatch_info(), Zero::zero());
+		let quote = |method, supply_currency_id, supply_amount| FeeQuote {
+			fee,
+			method,
+			supply_currency_id,
+			supply_amount,
+		};
+
+		if fee.is_zero() {
+			return Ok(quote(FeePaymentMethod::Native, native_currency_id, Zero::zero()));
+		}
+
+		let native_existential_deposit = <T as Config>::Currency::minimum_balance();
+		if let Some((sponsor, ..)) = Self::sponsored_fee_spending(who, call, fee) {
+			let pool = Self::sponsorship_pool_account(&sponsor);
+			if <T as Config>::Currency::free_balance(&pool) >= fee.saturating_add(native_existential_deposit) {
+				return Ok(quote(
+					FeePaymentMethod::Sponsored(sponsor),
+					native_currency_id,
+					Zero::zero(),
+				));
+			}
+		}
+
+		if Self::native_is_enough(who, fee, WithdrawReasons::TRANSACTION_PAYMENT) {
+			return Ok(quote(FeePaymentMethod::Native, native_currency_id, fee));
+		}
+
+		// the fee and the gap to keep alive
+		let amount = fee.saturating_add(
+			native_existential_deposit.saturating_sub(<T as Config>::Currency::total_balance(who)),
+		);
+		let mut error = FeeQuoteError::NoFeeCurrency;
+
+		let fee_treasury_is_enough = <T as Config>::Currency::free_balance(&T::FeeTreasuryAccount::get())
+			>= amount.saturating_add(native_existential_deposit);
+		for (supply_currency_id, premium) in Self::whitelisted_fee_currencies(who) {
+			let free_balance = <T as Config>::MultiCurrency::free_balance(supply_currency_id, who);
+			if free_balance.is_zero() {
+				continue;
+			}
+
+			match Self::oracle_supply_amount(supply_currency_id, premium, amount) {
+				Some(supply_amount) if fee_treasury_is_enough => {
+					if supply_amount <= free_balance {
+						return Ok(quote(
+							FeePaymentMethod::Oracle,
+							supply_currency_id,
+							supply_amount.unique_saturated_into(),
+						));
+					}
+					error = error.max(FeeQuoteError::InsufficientFeeCurrency);
+				}
+				_ => error = error.max(FeeQuoteError::NoPriceOrLiquidity),
+			}
+		}
+
+		for trading_path in Self::fee_swap_path_list(who) {
+			if trading_path.last() != Some(&native_currency_id) {
+				continue;
+			}
+			let supply_currency_id = *trading_path.first().expect("these's last guaranteed by check");
+			let free_balance = <T as Config>::MultiCurrency::free_balance(supply_currency_id, who);
+			if free_balance.is_zero() {
+				continue;
+			}
+
+			let max_supply_limit: Balance =
+				Self::max_swap_supply_amount(supply_currency_id, amount).unique_saturated_into();
+			match T::DEX::get_swap_amount(
+				&trading_path,
+				SwapLimit::ExactTarget(Balance::max_value(), amount.unique_saturated_into()),
+			) {
+				Some((supply_amount, _)) if supply_amount > max_supply_limit => {
+					error = error.max(FeeQuoteError::ExceedSwapSlippageLimit)
+				}
+				Some((supply_amount, _)) if supply_amount > free_balance => {
+					error = error.max(FeeQuoteError::InsufficientFeeCurrency)
+				}
+				Some((supply_amount, _)) => {
+					return Ok(quote(
+						FeePaymentMethod::Swap(trading_path),
+						supply_currency_id,
+						supply_amount.unique_saturated_into(),
+					));
+				}
+				None => error = error.max(FeeQuoteError::NoPriceOrLiquidity),
+			}
+		}
+
+		Err(error)
+	}
+
+	/// Whether the native balance of `who` is enough to pay `fee` and keep
+	/// alive.
+	fn native_is_enough(who: &T::AccountId, fee: PalletBalanceOf<T>, reason: WithdrawReasons) -> bool {
+		fee.saturating_add(<T as Config>::Currency::minimum_balance()) <= <T as Config>::Currency::total_balance(who)
+			&& <T as Config>::Currency::free_balance(who)
+				.checked_sub(&fee)
+				.map_or(false, |new_free_balance| {
+					<T as Config>::Currency::ensure_can_withdraw(who, fee, reason, new_free_balance).is_ok()
+				})
+	}
+
+	/// The fee swap paths of `who`, the alternative fee swap path is tried
+	/// first.
+	fn fee_swap_path_list(who: &T::AccountId) -> Vec<Vec<CurrencyId>> {
+		let default_fee_swap_path_list = T::DefaultFeeSwapPathList::get();
+		if let Some(trading_path) = AlternativeFeeSwapPath::<T>::get(who) {
+			vec![vec![trading_path.into_inner()], default_fee_swap_path_list].concat()
+		} else {
+			default_fee_swap_path_list
+		}
+	}
+
+	/// The max supply amount to swap `amount` native currency, according to
+	/// oracle price and the slippage limit. If oracle price is not avalible,
+	/// do not limit.
+	fn max_swap_supply_amount(supply_currency_id: CurrencyId, amount: PalletBalanceOf<T>) -> PalletBalanceOf<T> {
+		if let Some(target_price) = T::PriceSource::get_relative_price(T::NativeCurrencyId::get(), supply_currency_id) {
+			Ratio::one()
+				.saturating_sub(T::MaxSwapSlippageCompareToOracle::get())
+				.reciprocal()
+				.unwrap_or_else(Ratio::max_value)
+				.saturating_mul_int(target_price.saturating_mul_int(amount))
+		} else {
+			PalletBalanceOf::<T>::max_value()
+		}
+	}
+
+	/// The whitelisted fee currencies and premiums, the alternative fee swap
+	/// path supply currency of `who` first.
+	fn whitelisted_fee_currencies(who: &T::AccountId) -> Vec<(CurrencyId, Ratio)> {
+		let alternative_fee_currency = AlternativeFeeSwapPath::<T>::get(who).and_then(|path| path.first().copied());
+		alternative_fee_currency
+			.into_iter()
+			.chain(FeeCurrencies::<T>::iter_keys().filter(|currency_id| Some(*currency_id) != alternative_fee_currency))
+			.filter_map(|currency_id| FeeCurrencies::<T>::get(currency_id).map(|premium| (currency_id, premium)))
+			.collect()
+	}
+
+	/// The supply amount to exchange `amount` native currency at oracle
+	/// price plus `premium`.
+	fn oracle_supply_amount(
+		supply_currency_id: CurrencyId,
+		premium: Ratio,
+		amount: PalletBalanceOf<T>,
+	) -> Option<Balance> {
+		let price = T::PriceSource::get_relative_price(T::NativeCurrencyId::get(), supply_currency_id)?;
+		Some(
+			Ratio::one()
+				.saturating_add(premium)
+				.saturating_mul_int(price.saturating_mul_int(amount))
+				.unique_saturated_into(),
+		)
+	}
+
+	/// Exchange `amount` native currency from the fee treasury with one of
+	/// the whitelisted fee currencies of `who`, the alternative fee swap path
+	/// supply currency is tried first.
+	fn exchange_fee_by_oracle(who: &T::AccountId, amount: PalletBalanceOf<T>) -> DispatchResult {
+		for (supply_currency_id, premium) in Self::whitelisted_fee_currencies(who) {
+			if Self::do_exchange_fee_by_oracle(who, supply_currency_id, premium, amount).is_ok() {
+				return Ok(());
+			}
+		}
+
+		Err(Error::<T>::CannotPayFeeByOracle.into())
+	}
+
+	#[transactional]
+	fn do_exchange_fee_by_oracle(
+		who: &T::AccountId,
+		supply_currency_id: CurrencyId,
+		premium: Ratio,
+		amount: PalletBalanceOf<T>,
+	) -> DispatchResult {
+		let fee_treasury = T::FeeTreasuryAccount::get();
+		let supply_amount =
+			Self::oracle_supply_amount(supply_currency_id, premium, amount).ok_or(Error::<T>::PriceNotAvailable)?;
+
+		<T as Config>::MultiCurrency::transfer(supply_currency_id, who, &fee_treasury, supply_amount)?;
+		<T as Config>::Currency::transfer(&fee_treasury, who, amount, ExistenceRequirement::KeepAlive)?;
+		Ok(())
+	}
+
+	/// The account of the sponsorship pool of `sponsor`.
+	pub fn sponsorship_pool_account(sponsor: &T::AccountId) -> T::AccountId {
+		T::SponsorshipPalletId::get().into_sub_account(sponsor)
+	}
+
+	/// Remove at most `limit` sponsored users and user spendings of
+	/// `sponsor`. Returns the number of the removed entries, and whether all
+	/// were removed.
+	fn remove_sponsorship_entries(sponsor: &T::AccountId, limit: u32) -> (u32, bool) {
+		let (users, users_removed) = match SponsoredUsers::<T>::remove_prefix(sponsor, Some(limit)) {
+			AllRemoved(count) => (count, true),
+			SomeRemaining(count) => (count, false),
+		};
+		let limit = limit.saturating_sub(users);
+		if limit.is_zero() {
+			return (users, false);
+		}
+		let (spendings, spendings_removed) = match UserSpending::<T>::remove_prefix(sponsor, Some(limit)) {
+			AllRemoved(count) => (count, true),
+			SomeRemaining(count) => (count, false),
+		};
+		(users.saturating_add(spendings), users_removed && spendings_removed)
+	}
+
+	/// The sponsor of `call` for `who` if the spending caps allow paying
+	/// `fee`. Returns the sponsor, the period index and the spending of the
+	/// period and of `who` after paying `fee`.
+	fn sponsored_fee_spending(
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::Call,
+		fee: PalletBalanceOf<T>,
+	) -> Option<(T::AccountId, T::BlockNumber, PalletBalanceOf<T>, PalletBalanceOf<T>)>
+	where
+		<T as frame_system::Config>::Call: Encode,
+	{
+		let sponsored_call = T::EvmCallContract::contract(call)
+			.map(SponsoredCall::EvmContract)
+			.filter(|sponsored_call| SponsoredCalls::<T>::contains_key(sponsored_call))
+			.unwrap_or_else(|| {
+				// the first two bytes of the encoded call are the pallet index and call index
+				let encoded = call.encode();
+				SponsoredCall::Call(encoded[0], encoded[1])
+			});
+		let sponsor = SponsoredCalls::<T>::get(&sponsored_call)?;
+		let info = Sponsorships::<T>::get(&sponsor)?;
+		if !info.all_users && !SponsoredUsers::<T>::contains_key(&sponsor, who) {
+			return None;
+		}
+
+		let period_index = <frame_system::Pallet<T>>::block_number() / info.period;
+		let spent_in_period = |(index, spent): (T::BlockNumber, PalletBalanceOf<T>)| {
+			if index == period_index {
+				spent
+			} else {
+				Zero::zero()
+			}
+		};
+		let period_spent = spent_in_period(PeriodSpending::<T>::get(&sponsor))
+			.checked_add(&fee)
+			.filter(|spent| *spent <= info.period_cap)?;
+		let user_spent = spent_in_period(UserSpending::<T>::get(&sponsor, who))
+			.checked_add(&fee)
+			.filter(|spent| *spent <= info.user_cap)?;
+
+		Some((sponsor, period_index, period_spent, user_spent))
+	}
+
+	/// Withdraw `fee` from the sponsorship pool if `call` is sponsored for
+	/// `who` and the spending caps allow it. Returns the pool account and the
+	/// withdrawn imbalance.
+	fn withdraw_sponsored_fee(
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::Call,
+		fee: PalletBalanceOf<T>,
+		reason: WithdrawReasons,
+	) -> Option<(T::AccountId, NegativeImbalanceOf<T>)>
+	where
+		<T as frame_system::Config>::Call: Encode,
+	{
+		let (sponsor, period_index, period_spent, user_spent) = Self::sponsored_fee_spending(who, call, fee)?;
+		let pool = Self::sponsorship_pool_account(&sponsor);
+		let imbalance =
+			<T as Config>::Currency::withdraw(&pool, fee, reason, ExistenceRequirement::KeepAlive).ok()?;
+
+		PeriodSpending::<T>::insert(&sponsor, (period_index, period_spent));
+		UserSpending::<T>::insert(&sponsor, who, (period_index, user_spent));
+		Self::deposit_event(Event::FeeSponsored(sponsor, who.clone(), fee));
+		Some((pool, imbalance))
+	}
 }
 
 impl<T> Convert<Weight, PalletBalanceOf<T>> for Pallet<T>
@@ -670,7 +1322,7 @@ impl<T: Config + Send + Sync> sp_std::fmt::Debug for ChargeTransactionPayment<T>
 
 impl<T: Config + Send + Sync> ChargeTransactionPayment<T>
 where
-	<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + Encode,
 	PalletBalanceOf<T>: Send + Sync + FixedPointOperand,
 {
 	/// utility constructor. Used only in client/factory code.
@@ -678,19 +1330,21 @@ where
 		Self(fee)
 	}
 
+	/// Withdraw the fee, returns the fee, the withdrawn imbalance and the
+	/// account that paid the fee.
 	fn withdraw_fee(
 		&self,
 		who: &T::AccountId,
-		_call: &<T as frame_system::Config>::Call,
+		call: &<T as frame_system::Config>::Call,
 		info: &DispatchInfoOf<<T as frame_system::Config>::Call>,
 		len: usize,
-	) -> Result<(PalletBalanceOf<T>, Option<NegativeImbalanceOf<T>>), TransactionValidityError> {
+	) -> Result<(PalletBalanceOf<T>, Option<NegativeImbalanceOf<T>>, T::AccountId), TransactionValidityError> {
 		let tip = self.0;
 		let fee = Pallet::<T>::compute_fee(len as u32, info, tip);
 
 		// Only mess with balances if fee is not zero.
 		if fee.is_zero() {
-			return Ok((fee, None));
+			return Ok((fee, None, who.clone()));
 		}
 
 		let reason = if tip.is_zero() {
@@ -699,11 +1353,18 @@ where
 			WithdrawReasons::TRANSACTION_PAYMENT | WithdrawReasons::TIP
 		};
 
+		// sponsors do not pay tips
+		if tip.is_zero() {
+			if let Some((pool, imbalance)) = Pallet::<T>::withdraw_sponsored_fee(who, call, fee, reason) {
+				return Ok((fee, Some(imbalance), pool));
+			}
+		}
+
 		Pallet::<T>::ensure_can_charge_fee(who, fee, reason);
 
 		// withdraw native currency as fee
 		match <T as Config>::Currency::withdraw(who, fee, reason, ExistenceRequirement::KeepAlive) {
-			Ok(imbalance) => Ok((fee, Some(imbalance))),
+			Ok(imbalance) => Ok((fee, Some(imbalance), who.clone())),
 			Err(_) => Err(InvalidTransaction::Payment.into()),
 		}
 	}
@@ -740,12 +1401,14 @@ where
 impl<T: Config + Send + Sync> SignedExtension for ChargeTransactionPayment<T>
 where
 	PalletBalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
-	<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + Encode,
 {
 	const IDENTIFIER: &'static str = "ChargeTransactionPayment";
 	type AccountId = T::AccountId;
 	type Call = <T as frame_system::Config>::Call;
 	type AdditionalSigned = ();
+	/// (tip, payer, imbalance, fee), the payer is either the signer or the
+	/// sponsorship pool.
 	type Pre = (
 		PalletBalanceOf<T>,
 		Self::AccountId,
@@ -764,7 +1427,7 @@ where
 		info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> TransactionValidity {
-		let (fee, _) = self.withdraw_fee(who, call, info, len)?;
+		let (fee, _, _) = self.withdraw_fee(who, call, info, len)?;
 		Ok(ValidTransaction {
 			priority: Self::get_priority(len, info, fee),
 			..Default::default()
@@ -778,8 +1441,8 @@ where
 		info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> Result<Self::Pre, TransactionValidityError> {
-		let (fee, imbalance) = self.withdraw_fee(who, call, info, len)?;
-		Ok((self.0, who.clone(), imbalance, fee))
+		let (fee, imbalance, payer) = self.withdraw_fee(who, call, info, len)?;
+		Ok((self.0, payer, imbalance, fee))
 	}
 
 	fn post_dispatch(
@@ -42,6 +42,7 @@ pub type BlockNumber = u64;
 pub const ALICE: AccountId = AccountId::new([1u8; 32]);
 pub const BOB: AccountId = AccountId::new([2u8; 32]);
 pub const CHARLIE: AccountId = AccountId::new([3u8; 32]);
+pub const FEE_TREASURY: AccountId = AccountId::new([4u8; 32]);
 pub const SEE: CurrencyId = CurrencyId::Token(TokenSymbol::SEE);
 pub const SETR: CurrencyId = CurrencyId::Token(TokenSymbol::SETR);
 pub const SETUSD: CurrencyId = CurrencyId::Token(TokenSymbol::SETUSD);
@@ -294,6 +295,13 @@ parameter_types! {
 	];
 }
 
+parameter_types! {
+	pub const MaxTransferBatch: u32 = 10;
+	pub const DustHistoryDepth: u32 = 84;
+	pub const Erc20TransferWeight: u64 = 1_000_000;
+	pub const MaxMemoLength: u32 = 32;
+}
+
 impl module_currencies::Config for Runtime {
 	type Event = Event;
 	type MultiCurrency = Tokens;
@@ -306,6 +314,12 @@ impl module_currencies::Config for Runtime {
 	type EVMBridge = ();
 	type SweepOrigin = EnsureSignedBy<Zero, AccountId>;
 	type OnDust = ();
+	type UpdateOrigin = EnsureSignedBy<Zero, AccountId>;
+	type CurrentEra = ();
+	type DustHistoryDepth = DustHistoryDepth;
+	type MaxTransferBatch = MaxTransferBatch;
+	type Erc20TransferWeight = Erc20TransferWeight;
+	type MaxMemoLength = MaxMemoLength;
 }
 
 thread_local! {
@@ -344,6 +358,9 @@ parameter_types! {
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(1, 2);
 	pub static TransactionByteFee: u128 = 1;
 	pub DefaultFeeSwapPathList: Vec<Vec<CurrencyId>> = vec![vec![SETUSD, SEE], vec![DNAR, SETUSD, SEE]];
+	pub const FeeTreasuryAccount: AccountId = FEE_TREASURY;
+	pub const SponsorshipPalletId: PalletId = PalletId(*b"set/spsr");
+	pub const MaxSponsorshipRemovals: u32 = 2;
 }
 
 thread_local! {
@@ -384,6 +401,7 @@ impl PriceProvider<CurrencyId> for MockPriceSource {
 }
 
 impl Config for Runtime {
+	type Event = Event;
 	type NativeCurrencyId = GetNativeCurrencyId;
 	type DefaultFeeSwapPathList = DefaultFeeSwapPathList;
 	type Currency = PalletBalances;
@@ -396,6 +414,11 @@ impl Config for Runtime {
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = MockPriceSource;
+	type FeeTreasuryAccount = FeeTreasuryAccount;
+	type UpdateOrigin = EnsureSignedBy<Zero, AccountId>;
+	type SponsorshipPalletId = SponsorshipPalletId;
+	type MaxSponsorshipRemovals = MaxSponsorshipRemovals;
+	type EvmCallContract = ();
 	type WeightInfo = ();
 }
 
@@ -427,7 +450,7 @@ construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		TransactionPayment: transaction_payment::{Pallet, Call, Storage},
+		TransactionPayment: transaction_payment::{Pallet, Call, Storage, Event<T>},
 		PalletBalances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
 		Currencies: module_currencies::{Pallet, Call, Event<T>},
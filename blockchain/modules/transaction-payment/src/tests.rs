@@ -25,14 +25,16 @@
 use super::*;
 use frame_support::{
 	assert_noop, assert_ok,
+	traits::Hooks,
 	weights::{DispatchClass, DispatchInfo, Pays},
 };
 use mock::{
-	AccountId, BlockWeights, Call, Currencies, DEXModule, ExtBuilder, MockPriceSource, Origin, Runtime,
-	TransactionPayment, SEE, ALICE, SETUSD, BOB, CHARLIE, DNAR, FEE_UNBALANCED_AMOUNT, TIP_UNBALANCED_AMOUNT,
+	AccountId, BlockWeights, Call, Currencies, DEXModule, ExtBuilder, MockPriceSource, Origin, Runtime, System,
+	TransactionPayment, SEE, ALICE, SETUSD, BOB, CHARLIE, DNAR, FEE_TREASURY, FEE_UNBALANCED_AMOUNT,
+	TIP_UNBALANCED_AMOUNT,
 };
 use orml_traits::MultiCurrency;
-use sp_runtime::{testing::TestXt, traits::One};
+use sp_runtime::{testing::TestXt, traits::{BadOrigin, One}};
 use support::Price;
 
 const CALL: &<Runtime as frame_system::Config>::Call =
@@ -376,6 +378,470 @@ fn charge_fee_by_default_swap_path() {
 		});
 }
 
+#[test]
+fn set_fee_currency_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TransactionPayment::set_fee_currency(Origin::signed(ALICE), SETUSD, Some(Ratio::zero())),
+			BadOrigin
+		);
+		assert_noop!(
+			TransactionPayment::set_fee_currency(Origin::signed(AccountId::new([0u8; 32])), SEE, Some(Ratio::zero())),
+			Error::<Runtime>::InvalidFeeCurrency
+		);
+
+		assert_eq!(TransactionPayment::fee_currencies(SETUSD), None);
+		assert_ok!(TransactionPayment::set_fee_currency(
+			Origin::signed(AccountId::new([0u8; 32])),
+			SETUSD,
+			Some(Ratio::saturating_from_rational(1, 10))
+		));
+		assert_eq!(
+			TransactionPayment::fee_currencies(SETUSD),
+			Some(Ratio::saturating_from_rational(1, 10))
+		);
+		assert_ok!(TransactionPayment::set_fee_currency(
+			Origin::signed(AccountId::new([0u8; 32])),
+			SETUSD,
+			None
+		));
+		assert_eq!(TransactionPayment::fee_currencies(SETUSD), None);
+	});
+}
+
+#[test]
+fn charge_fee_by_oracle_price_without_dex() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			assert_ok!(Currencies::update_balance(Origin::root(), FEE_TREASURY, SEE, 10000));
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(SETUSD, &ALICE, &BOB, 1000));
+			assert_ok!(TransactionPayment::set_fee_currency(
+				Origin::signed(AccountId::new([0u8; 32])),
+				SETUSD,
+				Some(Ratio::saturating_from_rational(1, 10))
+			));
+			MockPriceSource::set_relative_price(Some(Price::saturating_from_rational(1, 10)));
+
+			// no liquidity in DEX, total balance is lt ED, will exchange fee and ED
+			// with the fee treasury at oracle price plus premium
+			let fee = 500 * 2 + 1000; // len * byte + weight
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0)
+					.validate(&BOB, CALL2, &INFO, 500)
+					.unwrap()
+					.priority,
+				fee
+			);
+			assert_eq!(DEXModule::get_liquidity_pool(SEE, SETUSD), (0, 0));
+			assert_eq!(Currencies::free_balance(SEE, &BOB), 10);
+			// (2000 + 10) / 10 * 1.1
+			assert_eq!(Currencies::free_balance(SETUSD, &BOB), 1000 - 221);
+			assert_eq!(Currencies::free_balance(SETUSD, &FEE_TREASURY), 221);
+			assert_eq!(Currencies::free_balance(SEE, &FEE_TREASURY), 10000 - 2010);
+		});
+}
+
+#[test]
+fn charge_fee_by_oracle_price_fallback_to_swap() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				SEE,
+				SETUSD,
+				10000,
+				1000,
+				0
+			));
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(SETUSD, &ALICE, &BOB, 1000));
+			assert_ok!(TransactionPayment::set_fee_currency(
+				Origin::signed(AccountId::new([0u8; 32])),
+				SETUSD,
+				Some(Ratio::zero())
+			));
+
+			// the fee treasury has no native currency, fallback to swap by DEX
+			let fee = 500 * 2 + 1000; // len * byte + weight
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0)
+					.validate(&BOB, CALL2, &INFO, 500)
+					.unwrap()
+					.priority,
+				fee
+			);
+			assert_eq!(Currencies::free_balance(SEE, &BOB), 10);
+			assert_eq!(Currencies::free_balance(SETUSD, &BOB), 747);
+			assert_eq!(Currencies::free_balance(SETUSD, &FEE_TREASURY), 0);
+			assert_eq!(
+				DEXModule::get_liquidity_pool(SEE, SETUSD),
+				(10000 - 2000 - 10, 1000 + 255)
+			);
+		});
+}
+
+#[test]
+fn sponsorship_settings_work() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			let sponsored_call = SponsoredCall::Call(CALL2.encode()[0], CALL2.encode()[1]);
+
+			assert_noop!(
+				TransactionPayment::set_sponsorship(Origin::signed(ALICE), 0, 5000, 10000, false),
+				Error::<Runtime>::InvalidSponsorship
+			);
+			assert_noop!(
+				TransactionPayment::update_sponsored_calls(Origin::signed(ALICE), vec![sponsored_call], vec![]),
+				Error::<Runtime>::SponsorshipNotFound
+			);
+
+			assert_ok!(TransactionPayment::set_sponsorship(
+				Origin::signed(ALICE),
+				10,
+				5000,
+				10000,
+				false
+			));
+			assert_eq!(
+				TransactionPayment::sponsorships(ALICE),
+				Some(SponsorshipInfo {
+					period: 10,
+					user_cap: 5000,
+					period_cap: 10000,
+					all_users: false,
+				})
+			);
+			assert_ok!(TransactionPayment::update_sponsored_calls(
+				Origin::signed(ALICE),
+				vec![sponsored_call],
+				vec![]
+			));
+			assert_eq!(TransactionPayment::sponsored_calls(sponsored_call), Some(ALICE));
+
+			assert_ok!(TransactionPayment::set_sponsorship(
+				Origin::signed(CHARLIE),
+				10,
+				5000,
+				10000,
+				true
+			));
+			assert_noop!(
+				TransactionPayment::update_sponsored_calls(Origin::signed(CHARLIE), vec![sponsored_call], vec![]),
+				Error::<Runtime>::CallAlreadySponsored
+			);
+
+			assert_ok!(TransactionPayment::fund_sponsorship_pool(Origin::signed(ALICE), 10000));
+			let pool = TransactionPayment::sponsorship_pool_account(&ALICE);
+			assert_eq!(Currencies::free_balance(SEE, &pool), 10000);
+			assert_ok!(TransactionPayment::withdraw_sponsorship_pool(Origin::signed(ALICE), 4000));
+			assert_eq!(Currencies::free_balance(SEE, &pool), 6000);
+
+			assert_ok!(TransactionPayment::remove_sponsorship(Origin::signed(ALICE)));
+			assert_eq!(TransactionPayment::sponsorships(ALICE), None);
+			assert_eq!(Currencies::free_balance(SEE, &pool), 0);
+			assert_eq!(Currencies::free_balance(SEE, &ALICE), 100000);
+
+			// the call of the removed sponsorship can be taken over
+			assert_ok!(TransactionPayment::update_sponsored_calls(
+				Origin::signed(CHARLIE),
+				vec![sponsored_call],
+				vec![]
+			));
+			assert_eq!(TransactionPayment::sponsored_calls(sponsored_call), Some(CHARLIE));
+		});
+}
+
+#[test]
+fn remove_sponsorship_in_chunks() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			let dave = AccountId::new([5u8; 32]);
+			assert_ok!(TransactionPayment::set_sponsorship(
+				Origin::signed(ALICE),
+				10,
+				5000,
+				10000,
+				false
+			));
+			assert_ok!(TransactionPayment::update_sponsored_users(
+				Origin::signed(ALICE),
+				vec![BOB, CHARLIE, dave.clone()],
+				vec![]
+			));
+
+			// more entries than MaxSponsorshipRemovals, the rest is removed on idle
+			assert_ok!(TransactionPayment::remove_sponsorship(Origin::signed(ALICE)));
+			assert_eq!(TransactionPayment::sponsorships(ALICE), None);
+			assert_eq!(TransactionPayment::pending_sponsorship_removals(ALICE), Some(()));
+			assert_noop!(
+				TransactionPayment::set_sponsorship(Origin::signed(ALICE), 10, 5000, 10000, false),
+				Error::<Runtime>::SponsorshipBeingRemoved
+			);
+
+			TransactionPayment::on_idle(1, 1_000_000_000_000);
+			assert_eq!(TransactionPayment::pending_sponsorship_removals(ALICE), None);
+			assert!(!SponsoredUsers::<Runtime>::contains_key(ALICE, dave));
+
+			assert_ok!(TransactionPayment::set_sponsorship(
+				Origin::signed(ALICE),
+				10,
+				5000,
+				10000,
+				false
+			));
+		});
+}
+
+#[test]
+fn charge_fee_by_sponsor_within_caps() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			let sponsored_call = SponsoredCall::Call(CALL2.encode()[0], CALL2.encode()[1]);
+			let pool = TransactionPayment::sponsorship_pool_account(&ALICE);
+			let fee = 500 * 2 + 1000; // len * byte + weight
+
+			assert_ok!(TransactionPayment::set_sponsorship(
+				Origin::signed(ALICE),
+				10,
+				5000,
+				10000,
+				false
+			));
+			assert_ok!(TransactionPayment::update_sponsored_calls(
+				Origin::signed(ALICE),
+				vec![sponsored_call],
+				vec![]
+			));
+			assert_ok!(TransactionPayment::fund_sponsorship_pool(Origin::signed(ALICE), 10000));
+
+			// BOB is not sponsored and cannot pay the fee
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0).validate(&BOB, CALL2, &INFO, 500),
+				Err(InvalidTransaction::Payment.into())
+			);
+
+			assert_ok!(TransactionPayment::update_sponsored_users(
+				Origin::signed(ALICE),
+				vec![BOB],
+				vec![]
+			));
+			// the sponsor does not pay tips
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(10).validate(&BOB, CALL2, &INFO, 500),
+				Err(InvalidTransaction::Payment.into())
+			);
+			// the call is not sponsored
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0).validate(&BOB, CALL, &INFO, 500),
+				Err(InvalidTransaction::Payment.into())
+			);
+
+			for i in 1..=2 {
+				assert_eq!(
+					ChargeTransactionPayment::<Runtime>::from(0)
+						.validate(&BOB, CALL2, &INFO, 500)
+						.unwrap()
+						.priority,
+					fee
+				);
+				System::assert_last_event(mock::Event::TransactionPayment(crate::Event::FeeSponsored(
+					ALICE, BOB, fee,
+				)));
+				assert_eq!(Currencies::free_balance(SEE, &pool), 10000 - fee * i);
+				assert_eq!(TransactionPayment::user_spending(ALICE, BOB), (0, fee * i));
+				assert_eq!(TransactionPayment::period_spending(ALICE), (0, fee * i));
+			}
+			assert_eq!(Currencies::free_balance(SEE, &BOB), 0);
+
+			// exceeds the user cap
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0).validate(&BOB, CALL2, &INFO, 500),
+				Err(InvalidTransaction::Payment.into())
+			);
+			assert_eq!(Currencies::free_balance(SEE, &pool), 6000);
+
+			// the caps are reset in a new period
+			System::set_block_number(10);
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0)
+					.validate(&BOB, CALL2, &INFO, 500)
+					.unwrap()
+					.priority,
+				fee
+			);
+			assert_eq!(Currencies::free_balance(SEE, &pool), 4000);
+			assert_eq!(TransactionPayment::user_spending(ALICE, BOB), (1, fee));
+			assert_eq!(TransactionPayment::period_spending(ALICE), (1, fee));
+		});
+}
+
+#[test]
+fn refund_sponsored_fee_to_pool_when_post_dispatch() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			let sponsored_call = SponsoredCall::Call(CALL2.encode()[0], CALL2.encode()[1]);
+			let pool = TransactionPayment::sponsorship_pool_account(&ALICE);
+
+			assert_ok!(TransactionPayment::set_sponsorship(
+				Origin::signed(ALICE),
+				10,
+				5000,
+				10000,
+				true
+			));
+			assert_ok!(TransactionPayment::update_sponsored_calls(
+				Origin::signed(ALICE),
+				vec![sponsored_call],
+				vec![]
+			));
+			assert_ok!(TransactionPayment::fund_sponsorship_pool(Origin::signed(ALICE), 10000));
+
+			let pre = ChargeTransactionPayment::<Runtime>::from(0)
+				.pre_dispatch(&BOB, CALL2, &INFO, 500)
+				.unwrap();
+			assert_eq!(pre.1, pool);
+			assert_eq!(Currencies::free_balance(SEE, &pool), 10000 - 2000);
+
+			assert_ok!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+				pre,
+				&INFO,
+				&POST_INFO,
+				500,
+				&Ok(())
+			));
+			// refund the unused weight fee to the pool
+			assert_eq!(Currencies::free_balance(SEE, &pool), 10000 - 1800);
+			assert_eq!(Currencies::free_balance(SEE, &BOB), 0);
+		});
+}
+
+#[test]
+fn quote_fee_works() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			let fee = TransactionPayment::compute_fee(500, &CALL2.get_dispatch_info(), 0);
+			assert_eq!(
+				TransactionPayment::quote_fee(&ALICE, CALL2, 500),
+				Ok(FeeQuote {
+					fee,
+					method: FeePaymentMethod::Native,
+					supply_currency_id: SEE,
+					supply_amount: fee,
+				})
+			);
+			assert_eq!(
+				TransactionPayment::quote_fee(&BOB, CALL2, 500),
+				Err(FeeQuoteError::NoFeeCurrency)
+			);
+
+			// no liquidity in DEX
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(SETUSD, &ALICE, &BOB, 10));
+			assert_eq!(
+				TransactionPayment::quote_fee(&BOB, CALL2, 500),
+				Err(FeeQuoteError::NoPriceOrLiquidity)
+			);
+
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				SEE,
+				SETUSD,
+				10000,
+				1000,
+				0
+			));
+			assert_eq!(
+				TransactionPayment::quote_fee(&BOB, CALL2, 500),
+				Err(FeeQuoteError::InsufficientFeeCurrency)
+			);
+
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(SETUSD, &ALICE, &BOB, 990));
+			let quote = TransactionPayment::quote_fee(&BOB, CALL2, 500).unwrap();
+			assert_eq!(quote.method, FeePaymentMethod::Swap(vec![SETUSD, SEE]));
+			assert_eq!(quote.supply_currency_id, SETUSD);
+
+			// the quote matches charging the fee
+			TransactionPayment::ensure_can_charge_fee(&BOB, fee, WithdrawReasons::TRANSACTION_PAYMENT);
+			assert_eq!(Currencies::free_balance(SETUSD, &BOB), 1000 - quote.supply_amount);
+			assert_eq!(Currencies::free_balance(SEE, &BOB), fee + 10);
+		});
+}
+
+#[test]
+fn quote_fee_by_oracle_price_and_sponsor() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			let fee = TransactionPayment::compute_fee(500, &CALL2.get_dispatch_info(), 0);
+			assert_ok!(Currencies::update_balance(Origin::root(), FEE_TREASURY, SEE, 10000));
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(SETUSD, &ALICE, &BOB, 1000));
+			assert_ok!(TransactionPayment::set_fee_currency(
+				Origin::signed(AccountId::new([0u8; 32])),
+				SETUSD,
+				Some(Ratio::saturating_from_rational(1, 10))
+			));
+			MockPriceSource::set_relative_price(Some(Price::saturating_from_rational(1, 10)));
+
+			let quote = TransactionPayment::quote_fee(&BOB, CALL2, 500).unwrap();
+			assert_eq!(quote.method, FeePaymentMethod::Oracle);
+			assert_eq!(quote.supply_currency_id, SETUSD);
+			// (fee + 10) / 10 * 1.1
+			assert_eq!(
+				quote.supply_amount,
+				Ratio::saturating_from_rational(11, 100).saturating_mul_int(fee + 10)
+			);
+
+			// no oracle price
+			MockPriceSource::set_relative_price(None);
+			assert_eq!(
+				TransactionPayment::quote_fee(&BOB, CALL2, 500),
+				Err(FeeQuoteError::NoPriceOrLiquidity)
+			);
+
+			assert_ok!(TransactionPayment::set_sponsorship(
+				Origin::signed(ALICE),
+				10,
+				fee,
+				fee,
+				true
+			));
+			assert_ok!(TransactionPayment::update_sponsored_calls(
+				Origin::signed(ALICE),
+				vec![SponsoredCall::Call(CALL2.encode()[0], CALL2.encode()[1])],
+				vec![]
+			));
+			// the sponsorship pool is not funded
+			assert_eq!(
+				TransactionPayment::quote_fee(&BOB, CALL2, 500),
+				Err(FeeQuoteError::NoPriceOrLiquidity)
+			);
+
+			assert_ok!(TransactionPayment::fund_sponsorship_pool(Origin::signed(ALICE), 10000));
+			assert_eq!(
+				TransactionPayment::quote_fee(&BOB, CALL2, 500),
+				Ok(FeeQuote {
+					fee,
+					method: FeePaymentMethod::Sponsored(ALICE),
+					supply_currency_id: SEE,
+					supply_amount: 0,
+				})
+			);
+		});
+}
+
 #[test]
 fn query_info_works() {
 	ExtBuilder::default()
@@ -51,6 +51,13 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for module_transaction_payment.
 pub trait WeightInfo {
 	fn set_alternative_fee_swap_path() -> Weight;
+	fn set_fee_currency() -> Weight;
+	fn set_sponsorship() -> Weight;
+	fn remove_sponsorship(c: u32, ) -> Weight;
+	fn update_sponsored_calls(c: u32, ) -> Weight;
+	fn update_sponsored_users(c: u32, ) -> Weight;
+	fn fund_sponsorship_pool() -> Weight;
+	fn withdraw_sponsorship_pool() -> Weight;
 	fn on_finalize() -> Weight;
 }
 
@@ -61,6 +68,43 @@ impl<T: frame_system::Config> WeightInfo for SetheumWeight<T> {
 		(3_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_fee_currency() -> Weight {
+		(3_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_sponsorship() -> Weight {
+		(4_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn remove_sponsorship(c: u32, ) -> Weight {
+		(60_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_sponsored_calls(c: u32, ) -> Weight {
+		(5_000_000 as Weight)
+			.saturating_add((3_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_sponsored_users(c: u32, ) -> Weight {
+		(5_000_000 as Weight)
+			.saturating_add((1_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn fund_sponsorship_pool() -> Weight {
+		(55_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn withdraw_sponsorship_pool() -> Weight {
+		(55_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 	fn on_finalize() -> Weight {
 		(8_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
@@ -74,6 +118,43 @@ impl WeightInfo for () {
 		(3_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn set_fee_currency() -> Weight {
+		(3_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_sponsorship() -> Weight {
+		(4_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn remove_sponsorship(c: u32, ) -> Weight {
+		(60_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_sponsored_calls(c: u32, ) -> Weight {
+		(5_000_000 as Weight)
+			.saturating_add((3_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_sponsored_users(c: u32, ) -> Weight {
+		(5_000_000 as Weight)
+			.saturating_add((1_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn fund_sponsorship_pool() -> Weight {
+		(55_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn withdraw_sponsorship_pool() -> Weight {
+		(55_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
 	fn on_finalize() -> Weight {
 		(8_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
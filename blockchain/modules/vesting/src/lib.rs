@@ -67,6 +67,7 @@ use sp_std::{
 	LockIdentifier, MultiCurrency, MultiLockableCurrency,
 };
 use primitives::CurrencyId;
+use support::VestedTransfer;
 
 mod mock;
 mod tests;
@@ -227,6 +228,8 @@ pub mod module {
 		AmountLow,
 		/// Failed because the maximum vesting schedules was exceeded
 		MaxVestingSchedulesExceeded,
+		/// The currency does not support vesting
+		NotVestedCurrency,
 	}
 
 	#[pallet::event]
@@ -729,6 +732,8 @@ impl<T: Config> Pallet<T> {
 			T::MultiCurrency::transfer(currency_id, from, to, schedule_amount)?;
 			T::MultiCurrency::set_lock(VESTING_LOCK_ID, currency_id, to, total_amount)?;
 			<SetUSDVestingSchedules<T>>::try_append(to, schedule).map_err(|_| Error::<T>::MaxVestingSchedulesExceeded)?;
+		} else {
+			return Err(Error::<T>::NotVestedCurrency.into());
 		};
 		Ok(())
 	}
@@ -904,4 +909,27 @@ impl<T: Config> Pallet<T> {
 
 		Ok(total)
 	}
-}
\ No newline at end of file
+}
+
+impl<T: Config> VestedTransfer<T::AccountId, CurrencyIdOf<T>, T::BlockNumber, BalanceOf<T>> for Pallet<T> {
+	fn vested_transfer(
+		currency_id: CurrencyIdOf<T>,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		start: T::BlockNumber,
+		period: T::BlockNumber,
+		period_count: u32,
+		per_period: BalanceOf<T>,
+	) -> DispatchResult {
+		let schedule = VestingSchedule {
+			start,
+			period,
+			period_count,
+			per_period,
+		};
+		Self::do_vested_transfer(currency_id, from, to, schedule.clone())?;
+
+		Self::deposit_event(Event::VestingScheduleAdded(currency_id, from.clone(), to.clone(), schedule));
+		Ok(())
+	}
+}
@@ -355,4 +355,36 @@ fn exceeding_maximum_schedules_should_fail() {
 			Error::<Runtime>::MaxVestingSchedulesExceeded
 		);
 	});
-}
\ No newline at end of file
+}
+#[test]
+fn vested_transfer_trait_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(<Vesting as VestedTransfer<_, _, _, _>>::vested_transfer(
+			SEE, &TREASURY, &BOB, 0u64, 10u64, 2u32, 10u64
+		));
+		assert_eq!(
+			Vesting::native_vesting_schedules(&BOB),
+			vec![VestingSchedule {
+				start: 0u64,
+				period: 10u64,
+				period_count: 2u32,
+				per_period: 10u64,
+			}]
+		);
+		assert_eq!(Tokens::free_balance(SEE, &BOB), 20);
+		assert!(Tokens::ensure_can_withdraw(SEE, &BOB, 1).is_err());
+		System::assert_last_event(Event::Vesting(crate::Event::VestingScheduleAdded(
+			SEE,
+			TREASURY,
+			BOB,
+			VestingSchedule {
+				start: 0u64,
+				period: 10u64,
+				period_count: 2u32,
+				per_period: 10u64,
+			},
+		)));
+	});
+}
@@ -205,6 +205,8 @@ pub enum CurrencyId {
 	Token(TokenSymbol),
 	DexShare(DexShare, DexShare),
 	Erc20(EvmAddress),
+	ForeignAsset(ForeignAssetId),
+	NftShare(NftVaultId),
 }
 
 impl CurrencyId {
@@ -220,6 +222,14 @@ impl CurrencyId {
 		matches!(self, CurrencyId::Erc20(_))
 	}
 
+	pub fn is_foreign_asset_currency_id(&self) -> bool {
+		matches!(self, CurrencyId::ForeignAsset(_))
+	}
+
+	pub fn is_nft_share_currency_id(&self) -> bool {
+		matches!(self, CurrencyId::NftShare(_))
+	}
+
 	pub fn is_trading_pair_currency_id(&self) -> bool {
 		matches!(
 			self,
@@ -243,13 +253,13 @@ impl CurrencyId {
 			CurrencyId::Token(symbol) => DexShare::Token(symbol),
 			CurrencyId::Erc20(address) => DexShare::Erc20(address),
 			// Unsupported
-			CurrencyId::DexShare(..) => return None,
+			CurrencyId::DexShare(..) | CurrencyId::ForeignAsset(_) | CurrencyId::NftShare(_) => return None,
 		};
 		let dex_share_1 = match currency_id_1 {
 			CurrencyId::Token(symbol) => DexShare::Token(symbol),
 			CurrencyId::Erc20(address) => DexShare::Erc20(address),
 			// Unsupported
-			CurrencyId::DexShare(..) => return None,
+			CurrencyId::DexShare(..) | CurrencyId::ForeignAsset(_) | CurrencyId::NftShare(_) => return None,
 		};
 		Some(CurrencyId::DexShare(dex_share_0, dex_share_1))
 	}
@@ -336,6 +346,13 @@ impl TryFrom<CurrencyId> for EvmAddress {
 				Ok(prefix | EvmAddress::from_low_u64_be(u64::from(symbol_0) << 32 | u64::from(symbol_1)))
 			}
 			CurrencyId::Erc20(address) => Ok(address),
+			CurrencyId::ForeignAsset(foreign_asset_id) => {
+				let mut prefix = EvmAddress::default();
+				prefix[0..H160_PREFIX_FOREIGN_ASSET.len()].copy_from_slice(&H160_PREFIX_FOREIGN_ASSET);
+				Ok(prefix | EvmAddress::from_low_u64_be(u64::from(foreign_asset_id)))
+			}
+			// NFT shares are not mirrored
+			CurrencyId::NftShare(_) => Err(()),
 		}
 	}
 }
@@ -24,6 +24,7 @@
 
 pub mod currency;
 pub mod evm;
+pub mod prices;
 pub mod signature;
 pub mod task;
 
@@ -59,6 +60,9 @@ pub const MIRRORED_TOKENS_ADDRESS_START: u64 = 0x1000000;
 /// Mirrored NFT (ensure length <= 4 bytes, encode to u32 will take the first 4 non-zero bytes)
 /// 0x2000000
 pub const MIRRORED_NFT_ADDRESS_START: u64 = 0x2000000;
+/// Mirrored Foreign Assets
+/// 0x100000000
+pub const MIRRORED_FOREIGN_ASSETS_ADDRESS_START: u64 = 0x100000000;
 /// Mirrored LP Tokens
 /// 0x10000000000000000
 pub const MIRRORED_LP_TOKENS_ADDRESS_START: u128 = 0x10000000000000000;
@@ -81,14 +85,20 @@ pub const NETWORK_CONTRACT_START: u64 = 0x1000;
 ///
 /// Erc20
 /// - v[0..20] = evm address(20 bytes)
+///
+/// ForeignAsset
+/// v[15] = 1 // MIRRORED_FOREIGN_ASSETS_ADDRESS_START
+/// - v[16..20] = foreign asset id(4 bytes)
 pub const H160_TYPE_TOKEN: u8 = 1;
 pub const H160_TYPE_DEXSHARE: u8 = 1;
 pub const H160_POSITION_TOKEN: usize = 19;
 pub const H160_POSITION_DEXSHARE_LEFT: Range<usize> = 12..16;
 pub const H160_POSITION_DEXSHARE_RIGHT: Range<usize> = 16..20;
 pub const H160_POSITION_ERC20: Range<usize> = 0..20;
+pub const H160_POSITION_FOREIGN_ASSET: Range<usize> = 16..20;
 pub const H160_PREFIX_TOKEN: [u8; 19] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0];
 pub const H160_PREFIX_DEXSHARE: [u8; 12] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+pub const H160_PREFIX_FOREIGN_ASSET: [u8; 16] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
 
 /// NFT Balance type
 pub type NFTBalance = u128;
@@ -140,6 +150,12 @@ pub type AuctionId = u32;
 /// Launchpad Campaign ID
 pub type CampaignId = u32;
 
+/// Foreign asset ID
+pub type ForeignAssetId = u32;
+
+/// NFT vault ID
+pub type NftVaultId = u32;
+
 /// Share type
 pub type Share = u128;
 
@@ -0,0 +1,44 @@
+// بِسْمِ اللَّهِ الرَّحْمَنِ الرَّحِيم
+
+// This file is part of Setheum.
+
+// Copyright (C) 2019-Present Setheum Labs.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Moment;
+use codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{FixedU128, RuntimeDebug};
+
+/// The price of a currency at a block, as recorded in the price history.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct PriceRecord<BlockNumber> {
+	/// The block number at which the price is recorded.
+	pub block_number: BlockNumber,
+	/// The timestamp of the block, in milliseconds.
+	pub timestamp: Moment,
+	/// The raw oracle price for 1 unit of the currency, without pegs or
+	/// decimals adjustment.
+	pub oracle_price: Option<FixedU128>,
+	/// The price for 1 basic unit of the currency used by the system, the
+	/// locked price if any.
+	pub price: Option<FixedU128>,
+	/// Whether the price is locked.
+	pub locked: bool,
+}
@@ -35,6 +35,27 @@ pub struct TaskResult {
 	pub finished: bool,
 }
 
+/// The priority of a task in the idle scheduler. Higher priority tasks are
+/// dispatched first.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum TaskPriority {
+	High,
+	Normal,
+	Low,
+}
+
+impl TaskPriority {
+	/// All priorities, in dispatch order.
+	pub const ALL: [TaskPriority; 3] = [TaskPriority::High, TaskPriority::Normal, TaskPriority::Low];
+}
+
+impl Default for TaskPriority {
+	fn default() -> Self {
+		TaskPriority::Normal
+	}
+}
+
 #[macro_export]
 macro_rules! define_combined_task {
 	(
@@ -119,6 +119,13 @@ fn currency_id_try_into_evm_address_works() {
 
 	let erc20 = EvmAddress::from_str("0x1111111111111111111111111111111111111111").unwrap();
 	assert_eq!(EvmAddress::try_from(CurrencyId::Erc20(erc20)), Ok(erc20));
+
+	assert_eq!(
+		EvmAddress::try_from(CurrencyId::ForeignAsset(0x0102)),
+		Ok(EvmAddress::from_str("0x0000000000000000000000000000000100000102").unwrap())
+	);
+
+	assert_eq!(EvmAddress::try_from(CurrencyId::NftShare(0)), Err(()));
 }
 
 #[test]
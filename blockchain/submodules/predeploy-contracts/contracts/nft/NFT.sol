@@ -44,4 +44,19 @@ library NFT {
             }
         }
     }
+
+    function royaltyInfo(uint256 class_id, uint256 token_id, uint256 sale_price)
+        public
+        view
+        returns (address, uint256)
+    {
+        (bool success, bytes memory returnData) = precompile.staticcall(abi.encodeWithSignature("royaltyInfo(uint256,uint256,uint256)", class_id, token_id, sale_price));
+        assembly {
+            if eq(success, 0) {
+                revert(add(returnData, 0x20), returndatasize())
+            }
+        }
+
+        return abi.decode(returnData, (address, uint256));
+    }
 }
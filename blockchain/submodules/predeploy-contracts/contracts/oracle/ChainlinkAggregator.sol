@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+pragma solidity ^0.8.0;
+
+import "./AggregatorV3Interface.sol";
+
+/**
+ * @dev Chainlink `AggregatorV3Interface` feed of the price of one currency,
+ * backed by the Oracle precompile.
+ *
+ * The answer is the USD price of 1 unit of the currency with 18 decimals.
+ * Rounds are identified by the update time of the price in seconds, and only
+ * the latest round is available.
+ *
+ * One aggregator is predeployed for each token in `tokens.json`, at the
+ * `<SYMBOL>_AGGREGATOR` addresses of `utils/Address.sol`.
+ */
+contract ChainlinkAggregator is AggregatorV3Interface {
+    address constant private precompile = address(0x0000000000000000000000000000000000000403);
+
+    address public immutable token;
+    string private _description;
+
+    constructor(address token_, string memory description_) {
+        require(token_ != address(0), "ChainlinkAggregator: token is zero address");
+
+        token = token_;
+        _description = description_;
+    }
+
+    function decimals() public pure override returns (uint8) {
+        return 18;
+    }
+
+    function description() public view override returns (string memory) {
+        return _description;
+    }
+
+    function version() public pure override returns (uint256) {
+        return 1;
+    }
+
+    /**
+     * @dev Get the data of the round `_roundId`.
+     * Reverts with "No data present" if it is not the latest round.
+     */
+    function getRoundData(uint80 _roundId)
+    public
+    view
+    override
+    returns (uint80, int256, uint256, uint256, uint80)
+    {
+        (bool success, bytes memory returnData) = precompile.staticcall(abi.encodeWithSignature("getRoundData(address,uint80)", token, _roundId));
+        assembly {
+            if eq(success, 0) {
+                revert(add(returnData, 0x20), returndatasize())
+            }
+        }
+
+        return abi.decode(returnData, (uint80, int256, uint256, uint256, uint80));
+    }
+
+    /**
+     * @dev Get the data of the latest round.
+     * Reverts with "No data present" if there is no price.
+     */
+    function latestRoundData()
+    public
+    view
+    override
+    returns (uint80, int256, uint256, uint256, uint80)
+    {
+        (bool success, bytes memory returnData) = precompile.staticcall(abi.encodeWithSignature("latestRoundData(address)", token));
+        assembly {
+            if eq(success, 0) {
+                revert(add(returnData, 0x20), returndatasize())
+            }
+        }
+
+        return abi.decode(returnData, (uint80, int256, uint256, uint256, uint80));
+    }
+}
@@ -6,4 +6,16 @@ interface IOracle {
     // Get the price of the currency_id.
     // Returns the price.
     function getPrice(address token) external view returns (uint256);
+
+    // Get the oracle price of the currency_id and the time it was fed, in seconds.
+    // Returns (price, timestamp)
+    function getPriceWithTimestamp(address token) external view returns (uint256, uint256);
+
+    // Get the price of the base currency_id in the quote currency_id, with 18 decimals.
+    // Returns the relative price, 0 if any price is missing.
+    function getRelativePrice(address baseToken, address quoteToken) external view returns (uint256);
+
+    // Get the locked price of the currency_id.
+    // Returns the locked price, 0 if the price is not locked.
+    function getLockedPrice(address token) external view returns (uint256);
 }
@@ -9,7 +9,7 @@ contract Oracle is IOracle {
 
     /**
      * @dev Get the price of the currency_id.
-     * Returns the (price, timestamp)
+     * Returns the price.
      */
     function getPrice(address token)
     public
@@ -28,4 +28,71 @@ contract Oracle is IOracle {
 
         return abi.decode(returnData, (uint256));
     }
+
+    /**
+     * @dev Get the oracle price of the currency_id.
+     * Returns the (price, timestamp), the timestamp is in seconds.
+     */
+    function getPriceWithTimestamp(address token)
+    public
+    view
+    override
+    returns (uint256, uint256)
+    {
+        require(token != address(0), "Oracle: token is zero address");
+
+        (bool success, bytes memory returnData) = precompile.staticcall(abi.encodeWithSignature("getPriceWithTimestamp(address)", token));
+        assembly {
+            if eq(success, 0) {
+                revert(add(returnData, 0x20), returndatasize())
+            }
+        }
+
+        return abi.decode(returnData, (uint256, uint256));
+    }
+
+    /**
+     * @dev Get the price of the baseToken in the quoteToken.
+     * Returns the relative price with 18 decimals.
+     */
+    function getRelativePrice(address baseToken, address quoteToken)
+    public
+    view
+    override
+    returns (uint256)
+    {
+        require(baseToken != address(0), "Oracle: baseToken is zero address");
+        require(quoteToken != address(0), "Oracle: quoteToken is zero address");
+
+        (bool success, bytes memory returnData) = precompile.staticcall(abi.encodeWithSignature("getRelativePrice(address,address)", baseToken, quoteToken));
+        assembly {
+            if eq(success, 0) {
+                revert(add(returnData, 0x20), returndatasize())
+            }
+        }
+
+        return abi.decode(returnData, (uint256));
+    }
+
+    /**
+     * @dev Get the locked price of the currency_id.
+     * Returns the price.
+     */
+    function getLockedPrice(address token)
+    public
+    view
+    override
+    returns (uint256)
+    {
+        require(token != address(0), "Oracle: token is zero address");
+
+        (bool success, bytes memory returnData) = precompile.staticcall(abi.encodeWithSignature("getLockedPrice(address)", token));
+        assembly {
+            if eq(success, 0) {
+                revert(add(returnData, 0x20), returndatasize())
+            }
+        }
+
+        return abi.decode(returnData, (uint256));
+    }
 }
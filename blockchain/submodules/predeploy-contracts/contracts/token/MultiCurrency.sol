@@ -68,4 +68,42 @@ library MultiCurrency {
             }
         }
     }
+
+    function allowance(address owner, address spender) internal view returns (uint256) {
+        (bool success, bytes memory returnData) = precompile.staticcall(abi.encodeWithSignature("allowance(address,address)", owner, spender));
+        assembly {
+            if eq(success, 0) {
+                revert(add(returnData, 0x20), returndatasize())
+            }
+        }
+
+        return abi.decode(returnData, (uint256));
+    }
+
+    function approve(address owner, address spender, uint256 amount) internal {
+        (bool success, bytes memory returnData) = precompile.call(abi.encodeWithSignature("approve(address,address,uint256)", owner, spender, amount));
+        assembly {
+            if eq(success, 0) {
+                revert(add(returnData, 0x20), returndatasize())
+            }
+        }
+    }
+
+    function transferFrom(address spender, address sender, address recipient, uint256 amount) internal {
+        (bool success, bytes memory returnData) = precompile.call(abi.encodeWithSignature("transferFrom(address,address,address,uint256)", spender, sender, recipient, amount));
+        assembly {
+            if eq(success, 0) {
+                revert(add(returnData, 0x20), returndatasize())
+            }
+        }
+    }
+
+    function transferWithMemo(address sender, address recipient, uint256 amount, bytes memory memo) internal {
+        (bool success, bytes memory returnData) = precompile.call(abi.encodeWithSignature("transferWithMemo(address,address,uint256,bytes)", sender, recipient, amount, memo));
+        assembly {
+            if eq(success, 0) {
+                revert(add(returnData, 0x20), returndatasize())
+            }
+        }
+    }
 }
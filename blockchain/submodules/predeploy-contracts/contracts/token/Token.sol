@@ -13,8 +13,6 @@ import "./MultiCurrency.sol";
 contract Token is IERC20 {
     using SafeMath for uint256;
 
-    mapping (address => mapping (address => uint256)) private _allowances;
-
     /**
      * @dev Returns the name of the token.
      */
@@ -64,11 +62,32 @@ contract Token is IERC20 {
         return true;
     }
 
+    /**
+     * @dev Moves `amount` tokens from the caller to `to`, recording `memo`
+     * with the transfer, e.g. an exchange deposit reference.
+     *
+     * Emits a {Transfer} event.
+     *
+     * Requirements:
+     *
+     * - `to` cannot be the zero address.
+     * - the caller must have a balance of at least `amount`.
+     * - `amount` cannot be zero.
+     */
+    function transferWithMemo(address to, uint256 amount, bytes calldata memo) public returns (bool) {
+        require(to != address(0), "ERC20: transfer to the zero address");
+
+        MultiCurrency.transferWithMemo(msg.sender, to, amount, memo);
+
+        emit Transfer(msg.sender, to, amount);
+        return true;
+    }
+
     /**
      * @dev See {IERC20-allowance}.
      */
     function allowance(address owner, address spender) public view override returns (uint256) {
-        return _allowances[owner][spender];
+        return MultiCurrency.allowance(owner, spender);
     }
 
     /**
@@ -96,8 +115,15 @@ contract Token is IERC20 {
      * `amount`.
      */
     function transferFrom(address sender, address recipient, uint256 amount) public override returns (bool) {
-        _transfer(sender, recipient, amount);
-        _approve(sender, msg.sender, _allowances[sender][msg.sender].sub(amount, "ERC20: transfer amount exceeds allowance"));
+        require(sender != address(0), "ERC20: transfer from the zero address");
+        require(recipient != address(0), "ERC20: transfer to the zero address");
+        require(allowance(sender, msg.sender) >= amount, "ERC20: transfer amount exceeds allowance");
+
+        // the precompile moves the balance and spends the allowance atomically
+        MultiCurrency.transferFrom(msg.sender, sender, recipient, amount);
+
+        emit Transfer(sender, recipient, amount);
+        emit Approval(sender, msg.sender, allowance(sender, msg.sender));
         return true;
     }
 
@@ -114,7 +140,7 @@ contract Token is IERC20 {
      * - `spender` cannot be the zero address.
      */
     function increaseAllowance(address spender, uint256 addedValue) public returns (bool) {
-        _approve(msg.sender, spender, _allowances[msg.sender][spender].add(addedValue));
+        _approve(msg.sender, spender, allowance(msg.sender, spender).add(addedValue));
         return true;
     }
 
@@ -133,7 +159,7 @@ contract Token is IERC20 {
      * `subtractedValue`.
      */
     function decreaseAllowance(address spender, uint256 subtractedValue) public returns (bool) {
-        _approve(msg.sender, spender, _allowances[msg.sender][spender].sub(subtractedValue, "ERC20: decreased allowance below zero"));
+        _approve(msg.sender, spender, allowance(msg.sender, spender).sub(subtractedValue, "ERC20: decreased allowance below zero"));
         return true;
     }
 
@@ -177,7 +203,7 @@ contract Token is IERC20 {
         require(owner != address(0), "ERC20: approve from the zero address");
         require(spender != address(0), "ERC20: approve to the zero address");
 
-        _allowances[owner][spender] = amount;
+        MultiCurrency.approve(owner, spender, amount);
         emit Approval(owner, spender, amount);
     }
 }
@@ -18,3 +18,18 @@ export const StateRent: "0x0000000000000000000000000000000000000801";
 export const Oracle: "0x0000000000000000000000000000000000000802";
 export const Schedule: "0x0000000000000000000000000000000000000803";
 export const DEX: "0x0000000000000000000000000000000000000804";
+export const SETM_AGGREGATOR: "0x0000000000000000000000000000000000000900";
+export const SERP_AGGREGATOR: "0x0000000000000000000000000000000000000901";
+export const DNAR_AGGREGATOR: "0x0000000000000000000000000000000000000902";
+export const HELP_AGGREGATOR: "0x0000000000000000000000000000000000000903";
+export const SETR_AGGREGATOR: "0x0000000000000000000000000000000000000904";
+export const SETUSD_AGGREGATOR: "0x0000000000000000000000000000000000000905";
+export const LP_SETM_SETUSD_AGGREGATOR: "0x0000000000000000000000000000000000000906";
+export const LP_SERP_SETUSD_AGGREGATOR: "0x0000000000000000000000000000000000000907";
+export const LP_DNAR_SETUSD_AGGREGATOR: "0x0000000000000000000000000000000000000908";
+export const LP_HELP_SETUSD_AGGREGATOR: "0x0000000000000000000000000000000000000909";
+export const LP_SETR_SETUSD_AGGREGATOR: "0x000000000000000000000000000000000000090a";
+export const LP_SETM_SETR_AGGREGATOR: "0x000000000000000000000000000000000000090B";
+export const LP_SERP_SETR_AGGREGATOR: "0x000000000000000000000000000000000000090C";
+export const LP_DNAR_SETR_AGGREGATOR: "0x000000000000000000000000000000000000090D";
+export const LP_HELP_SETR_AGGREGATOR: "0x000000000000000000000000000000000000090e";
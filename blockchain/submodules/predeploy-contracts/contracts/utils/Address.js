@@ -18,6 +18,21 @@ const StateRent = '0x0000000000000000000000000000000000000801';
 const Oracle = '0x0000000000000000000000000000000000000802';
 const Schedule = '0x0000000000000000000000000000000000000803';
 const DEX = '0x0000000000000000000000000000000000000804';
+const SETM_AGGREGATOR = '0x0000000000000000000000000000000000000900';
+const SERP_AGGREGATOR = '0x0000000000000000000000000000000000000901';
+const DNAR_AGGREGATOR = '0x0000000000000000000000000000000000000902';
+const HELP_AGGREGATOR = '0x0000000000000000000000000000000000000903';
+const SETR_AGGREGATOR = '0x0000000000000000000000000000000000000904';
+const SETUSD_AGGREGATOR = '0x0000000000000000000000000000000000000905';
+const LP_SETM_SETUSD_AGGREGATOR = '0x0000000000000000000000000000000000000906';
+const LP_SERP_SETUSD_AGGREGATOR = '0x0000000000000000000000000000000000000907';
+const LP_DNAR_SETUSD_AGGREGATOR = '0x0000000000000000000000000000000000000908';
+const LP_HELP_SETUSD_AGGREGATOR = '0x0000000000000000000000000000000000000909';
+const LP_SETR_SETUSD_AGGREGATOR = '0x000000000000000000000000000000000000090a';
+const LP_SETM_SETR_AGGREGATOR = '0x000000000000000000000000000000000000090B';
+const LP_SERP_SETR_AGGREGATOR = '0x000000000000000000000000000000000000090C';
+const LP_DNAR_SETR_AGGREGATOR = '0x000000000000000000000000000000000000090D';
+const LP_HELP_SETR_AGGREGATOR = '0x000000000000000000000000000000000000090e';
 
 module.exports = {
   SETM,
@@ -40,4 +55,19 @@ module.exports = {
   Oracle,
   Schedule,
   DEX,
+  SETM_AGGREGATOR,
+  SERP_AGGREGATOR,
+  DNAR_AGGREGATOR,
+  HELP_AGGREGATOR,
+  SETR_AGGREGATOR,
+  SETUSD_AGGREGATOR,
+  LP_SETM_SETUSD_AGGREGATOR,
+  LP_SERP_SETUSD_AGGREGATOR,
+  LP_DNAR_SETUSD_AGGREGATOR,
+  LP_HELP_SETUSD_AGGREGATOR,
+  LP_SETR_SETUSD_AGGREGATOR,
+  LP_SETM_SETR_AGGREGATOR,
+  LP_SERP_SETR_AGGREGATOR,
+  LP_DNAR_SETR_AGGREGATOR,
+  LP_HELP_SETR_AGGREGATOR,
 }
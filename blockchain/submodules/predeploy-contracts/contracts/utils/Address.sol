@@ -23,4 +23,19 @@ contract ADDRESS {
 	address public constant Oracle = 0x0000000000000000000000000000000000000802;
 	address public constant Schedule = 0x0000000000000000000000000000000000000803;
 	address public constant DEX = 0x0000000000000000000000000000000000000804;
+	address public constant SETM_AGGREGATOR = 0x0000000000000000000000000000000000000900;
+	address public constant SERP_AGGREGATOR = 0x0000000000000000000000000000000000000901;
+	address public constant DNAR_AGGREGATOR = 0x0000000000000000000000000000000000000902;
+	address public constant HELP_AGGREGATOR = 0x0000000000000000000000000000000000000903;
+	address public constant SETR_AGGREGATOR = 0x0000000000000000000000000000000000000904;
+	address public constant SETUSD_AGGREGATOR = 0x0000000000000000000000000000000000000905;
+	address public constant LP_SETM_SETUSD_AGGREGATOR = 0x0000000000000000000000000000000000000906;
+	address public constant LP_SERP_SETUSD_AGGREGATOR = 0x0000000000000000000000000000000000000907;
+	address public constant LP_DNAR_SETUSD_AGGREGATOR = 0x0000000000000000000000000000000000000908;
+	address public constant LP_HELP_SETUSD_AGGREGATOR = 0x0000000000000000000000000000000000000909;
+	address public constant LP_SETR_SETUSD_AGGREGATOR = 0x000000000000000000000000000000000000090a;
+	address public constant LP_SETM_SETR_AGGREGATOR = 0x000000000000000000000000000000000000090B;
+	address public constant LP_SERP_SETR_AGGREGATOR = 0x000000000000000000000000000000000000090C;
+	address public constant LP_DNAR_SETR_AGGREGATOR = 0x000000000000000000000000000000000000090D;
+	address public constant LP_HELP_SETR_AGGREGATOR = 0x000000000000000000000000000000000000090e;
 }
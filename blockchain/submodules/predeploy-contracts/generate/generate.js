@@ -23,6 +23,9 @@ const exec = util.promisify(childProcess.exec);
 // Mirrored LP Tokens
 // 0x10000000000000000
 const PREDEPLOY_ADDRESS_START = 0x800;
+// Chainlink aggregators, one per token in `tokens.json`
+// 0x900 - 0x1000
+const AGGREGATOR_ADDRESS_START = 0x900;
 
 function address(start, offset) {
   const address = BigNumber.from(start).add(offset).toHexString().slice(2).padStart(40,0);
@@ -64,6 +67,13 @@ const generate = async () => {
   const { bytecode: dex } = require(`../build/contracts/DEX.json`);
   bytecodes.push(['DEX', address(PREDEPLOY_ADDRESS_START, 4), dex]);
 
+  // add a ChainlinkAggregator for each token, with the constructor arguments appended to the bytecode
+  const { bytecode: aggregator } = require(`../build/contracts/ChainlinkAggregator.json`);
+  tokens.forEach(({ symbol, address: tokenAddress }, index) => {
+    const args = ethers.utils.defaultAbiCoder.encode(['address', 'string'], [tokenAddress, `${symbol} / USD`]);
+    bytecodes.push([`${symbol}_AGGREGATOR`, address(AGGREGATOR_ADDRESS_START, index), aggregator + args.slice(2)]);
+  });
+
   // merge tokenList into bytecodes
   bytecodes = tokenList.concat(bytecodes);
 